@@ -6,6 +6,7 @@ use crate::messages::frontend::utility_types::ExportBounds;
 use crate::messages::frontend::utility_types::FileType;
 use crate::messages::input_mapper::utility_types::macros::action_keys;
 use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::portfolio::document::node_graph::upgrade_legacy_nodes;
 use crate::messages::portfolio::document::node_graph::NodeGraphHandlerData;
 use crate::messages::portfolio::document::properties_panel::utility_types::PropertiesPanelMessageHandlerData;
 use crate::messages::portfolio::document::utility_types::clipboards::Clipboard;
@@ -19,7 +20,7 @@ use crate::node_graph_executor::NodeGraphExecutor;
 
 use document_legacy::document::Document as DocumentLegacy;
 use document_legacy::document_metadata::LayerNodeIdentifier;
-use document_legacy::layers::layer_info::{LayerDataType, LayerDataTypeDiscriminant};
+use document_legacy::layers::layer_info::{Layer, LayerDataType, LayerDataTypeDiscriminant};
 use document_legacy::layers::style::{RenderData, ViewMode};
 use document_legacy::{DocumentError, DocumentResponse, LayerId, Operation as DocumentOperation};
 use graph_craft::document::value::TaggedValue;
@@ -993,8 +994,9 @@ impl DocumentMessageHandler {
 	pub fn deserialize_document(serialized_content: &str) -> Result<Self, DocumentError> {
 		let deserialized_result: Result<Self, DocumentError> = serde_json::from_str(serialized_content).map_err(|e| DocumentError::InvalidFile(e.to_string()));
 		match deserialized_result {
-			Ok(document) => {
+			Ok(mut document) => {
 				if document.version == GRAPHITE_DOCUMENT_VERSION {
+					document.upgrade_legacy_nodes();
 					Ok(document)
 				} else {
 					Err(DocumentError::InvalidFile("Graphite document version mismatch".to_string()))
@@ -1004,6 +1006,20 @@ impl DocumentMessageHandler {
 		}
 	}
 
+	/// Updates nodes saved by an older version of the editor in the document network and the network of each layer, so they still resolve to the current proto nodes.
+	fn upgrade_legacy_nodes(&mut self) {
+		fn upgrade_layer(layer: &mut Layer) {
+			match &mut layer.data {
+				LayerDataType::Folder(folder) => folder.layers_mut().iter_mut().for_each(upgrade_layer),
+				LayerDataType::Layer(layer_layer) => upgrade_legacy_nodes(&mut layer_layer.network),
+				LayerDataType::Shape(_) => {}
+			}
+		}
+
+		upgrade_legacy_nodes(&mut self.document_legacy.document_network);
+		upgrade_layer(&mut self.document_legacy.root);
+	}
+
 	pub fn with_name(name: String, ipp: &InputPreprocessorMessageHandler, responses: &mut VecDeque<Message>) -> Self {
 		let mut document = Self { name, ..Self::default() };
 		let transform = document.navigation_handler.calculate_offset_transform(ipp.viewport_bounds.size() / 2.);
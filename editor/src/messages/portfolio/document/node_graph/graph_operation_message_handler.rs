@@ -209,12 +209,12 @@ impl<'a> ModifyInputsContext<'a> {
 	}
 
 	fn insert_text(&mut self, text: String, font: Font, size: f64, layer: NodeId) {
-		let text = resolve_document_node_type("Text").expect("Text node does not exist").to_document_node(
+		let text = resolve_document_node_type("Text").expect("Text node does not exist").to_document_node_default_inputs(
 			[
-				NodeInput::Network(graph_craft::concrete!(graphene_std::wasm_application_io::WasmEditorApi)),
-				NodeInput::value(TaggedValue::String(text), false),
-				NodeInput::value(TaggedValue::Font(font), false),
-				NodeInput::value(TaggedValue::F64(size), false),
+				Some(NodeInput::Network(graph_craft::concrete!(graphene_std::wasm_application_io::WasmEditorApi))),
+				Some(NodeInput::value(TaggedValue::String(text), false)),
+				Some(NodeInput::value(TaggedValue::Font(font), false)),
+				Some(NodeInput::value(TaggedValue::F64(size), false)),
 			],
 			Default::default(),
 		);
@@ -11,8 +11,8 @@ use graph_craft::NodeIdentifier;
 #[cfg(feature = "gpu")]
 use graphene_core::application_io::SurfaceHandle;
 use graphene_core::raster::brush_cache::BrushCache;
-use graphene_core::raster::{BlendMode, Color, Image, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
-use graphene_core::text::Font;
+use graphene_core::raster::{BlendMode, CanvasAnchor, Color, Image, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, ResampleFilter, SelectiveColorChoice, StrokePosition};
+use graphene_core::text::{Font, TextPathAlignment};
 use graphene_core::transform::Footprint;
 use graphene_core::vector::VectorData;
 use graphene_core::*;
@@ -795,6 +795,226 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Gaussian Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::filters::GaussianBlurNode<_, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Box Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::filters::BoxBlurNode<_, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Motion Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::filters::MotionBlurNode<_, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Distance", TaggedValue::F64(20.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::motion_blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Radial Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::filters::RadialBlurNode<_, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Center", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::radial_blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Zoom Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::filters::ZoomBlurNode<_, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Center", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Amount", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::zoom_blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Unsharp Mask",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::filters::UnsharpMaskNode<_, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Amount", TaggedValue::F64(50.), false),
+				DocumentInputType::value("Radius", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Threshold", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::unsharp_mask_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Resize",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::raster::ResizeImageNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Mode", TaggedValue::RelativeAbsolute(RelativeAbsolute::Absolute), false),
+				DocumentInputType::value("Width", TaggedValue::U32(100), false),
+				DocumentInputType::value("Height", TaggedValue::U32(100), false),
+				DocumentInputType::value("Factor", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Filter", TaggedValue::ResampleFilter(ResampleFilter::Bicubic), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::resize_image_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Crop",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::raster::CropImageNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Offset", TaggedValue::IVec2(glam::IVec2::ZERO), false),
+				DocumentInputType::value("Size", TaggedValue::IVec2(glam::IVec2::new(100, 100)), false),
+				DocumentInputType::value("Content Bounds", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::crop_image_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Canvas Size",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_std::raster::CanvasSizeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Width", TaggedValue::U32(100), false),
+				DocumentInputType::value("Height", TaggedValue::U32(100), false),
+				DocumentInputType::value("Anchor", TaggedValue::CanvasAnchor(CanvasAnchor::Center), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::canvas_size_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Drop Shadow",
+			category: "Layer Styles",
+			identifier: NodeImplementation::proto("graphene_std::layer_styles::DropShadowNode<_, _, _, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::BLACK), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Multiply), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(75.), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(45.), false),
+				DocumentInputType::value("Distance", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Spread", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Size", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::layer_style_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Inner Shadow",
+			category: "Layer Styles",
+			identifier: NodeImplementation::proto("graphene_std::layer_styles::InnerShadowNode<_, _, _, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::BLACK), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Multiply), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(75.), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(45.), false),
+				DocumentInputType::value("Distance", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Spread", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Size", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::layer_style_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Outer Glow",
+			category: "Layer Styles",
+			identifier: NodeImplementation::proto("graphene_std::layer_styles::OuterGlowNode<_, _, _, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::from_rgb8_srgb(255, 255, 190)), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Screen), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(75.), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Distance", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Spread", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Size", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::layer_style_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Inner Glow",
+			category: "Layer Styles",
+			identifier: NodeImplementation::proto("graphene_std::layer_styles::InnerGlowNode<_, _, _, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::from_rgb8_srgb(255, 255, 190)), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Screen), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(75.), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Distance", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Spread", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Size", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::layer_style_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Raster Stroke",
+			category: "Layer Styles",
+			identifier: NodeImplementation::proto("graphene_std::layer_styles::RasterStrokeNode<_, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::GraphicGroup(GraphicGroup::EMPTY), true),
+				DocumentInputType::value("Color", TaggedValue::Color(Color::BLACK), false),
+				DocumentInputType::value("Blend Mode", TaggedValue::BlendMode(BlendMode::Normal), false),
+				DocumentInputType::value("Opacity", TaggedValue::F64(100.), false),
+				DocumentInputType::value("Size", TaggedValue::F64(3.), false),
+				DocumentInputType::value("Position", TaggedValue::StrokePosition(StrokePosition::Outside), false),
+			],
+			outputs: vec![DocumentOutputType::new("Out", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::raster_stroke_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Split Channels",
 			category: "Image Adjustments",
@@ -2111,12 +2331,18 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Text",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::text::TextGenerator<_, _, _>"),
+			identifier: NodeImplementation::proto("graphene_core::text::TextGenerator<_, _, _, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::none(),
 				DocumentInputType::value("Text", TaggedValue::String("hello world".to_string()), false),
 				DocumentInputType::value("Font", TaggedValue::Font(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into())), false),
 				DocumentInputType::value("Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Path", TaggedValue::VectorData(VectorData::empty()), true),
+				DocumentInputType::value("Start Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Alignment", TaggedValue::TextPathAlignment(TextPathAlignment::Start), false),
+				DocumentInputType::value("Letter Spacing", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Flip", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Rotate Glyphs", TaggedValue::Bool(true), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			properties: node_properties::node_section_font,
@@ -2214,6 +2440,20 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::stroke_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Set Attribute",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::SetAttributeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String(String::from("width")), false),
+				DocumentInputType::value("Per Subpath", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Values", TaggedValue::VecF32(vec![1.]), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::set_attribute_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Repeat",
 			category: "Vector",
@@ -2250,6 +2490,113 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::circular_repeat_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Grid Repeat",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::GridRepeatNode<_, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Columns", TaggedValue::U32(5), false),
+				DocumentInputType::value("Rows", TaggedValue::U32(5), false),
+				DocumentInputType::value("Spacing", TaggedValue::DVec2((100., 100.).into()), false),
+				DocumentInputType::value("Stagger", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Step Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Step Scale", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Opacity Falloff", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Instances", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::grid_repeat_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Symmetry",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::SymmetryNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Type", TaggedValue::SymmetryType(graphene_core::vector::SymmetryType::Horizontal), false),
+				DocumentInputType::value("Center", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Count", TaggedValue::U32(6), false),
+				DocumentInputType::value("Weld", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
+			properties: node_properties::symmetry_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Repeat Along Path",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::RepeatAlongPathNode<_, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Path", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Count", TaggedValue::U32(10), false),
+				DocumentInputType::value("Align to Path", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Step Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Step Scale", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Opacity Falloff", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Instances", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::repeat_along_path_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Scatter Points",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::ScatterPointsNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Along Outline", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Spacing", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Count", TaggedValue::U32(100), false),
+				DocumentInputType::value("Fill Rule", TaggedValue::FillRule(graphene_core::vector::style::FillRule::NonZero), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Points", FrontendGraphDataType::Subpath)],
+			properties: node_properties::scatter_points_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Delaunay Triangulation",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::DelaunayTriangulationNode"),
+			inputs: vec![DocumentInputType::value("Points", TaggedValue::VecDVec2(Vec::new()), true)],
+			outputs: vec![DocumentOutputType::new("Triangles", FrontendGraphDataType::Subpath)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Voronoi Cells",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::VoronoiCellsNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Points", TaggedValue::VecDVec2(Vec::new()), true),
+				DocumentInputType::value("Boundary", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+			],
+			outputs: vec![DocumentOutputType::new("Cells", FrontendGraphDataType::Subpath)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Copy to Points",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::CopyToPointsNode<_, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Points", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Instance", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Align to Tangent", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Random Scale Min", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Scale Max", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Index Scale", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Index Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Instances", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::copy_to_points_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Resample Points",
 			category: "Vector",
@@ -2262,6 +2609,111 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::resample_points_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Dash",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::DashNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Dash Lengths", TaggedValue::VecF32(vec![10., 5.]), false),
+				DocumentInputType::value("Dash Offset", TaggedValue::F32(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::dash_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Outline Stroke",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::OutlineStrokeNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Variable Width Stroke",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::VariableWidthStrokeNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Width Profile", TaggedValue::Curve(Default::default()), false),
+				DocumentInputType::value("Use Width Attribute", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::variable_width_stroke_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Round Corners",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::RoundCornersNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Chamfer", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Selection", TaggedValue::ManipulatorGroupIds(vec![]), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::round_corners_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Offset Path",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::OffsetPathNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Distance", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Line Join", TaggedValue::LineJoin(graphene_core::vector::style::LineJoin::Miter), false),
+				DocumentInputType::value("Miter Limit", TaggedValue::F64(4.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::offset_path_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Roughen",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::RoughenNode<_, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Size", TaggedValue::F64(20.), false),
+				DocumentInputType::value("Amount", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Smooth", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Time", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::roughen_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Morph",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::MorphNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Target", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Time", TaggedValue::F64(0.5), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::morph_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Blend Steps",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::BlendStepsNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Target", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Steps", TaggedValue::U32(5), false),
+			],
+			outputs: vec![DocumentOutputType::new("Blend", FrontendGraphDataType::GraphicGroup)],
+			properties: node_properties::blend_steps_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Spline from Points",
 			category: "Vector",
@@ -2403,6 +2855,23 @@ pub fn resolve_document_node_type(name: &str) -> Option<&DocumentNodeBlueprint>
 	DOCUMENT_NODE_TYPES.iter().find(|node| node.name == name)
 }
 
+/// Brings the nodes of a network saved by an older version of the editor up to date with their blueprints, including those within nested networks.
+/// Text nodes saved before text could follow a path are given the inputs added since, with their default values.
+pub fn upgrade_legacy_nodes(network: &mut NodeNetwork) {
+	for node in network.nodes.values_mut() {
+		match &mut node.implementation {
+			DocumentNodeImplementation::Network(nested_network) => upgrade_legacy_nodes(nested_network),
+			DocumentNodeImplementation::Unresolved(identifier) if identifier.name == "graphene_core::text::TextGenerator<_, _, _>" => {
+				let text = resolve_document_node_type("Text").expect("Text node does not exist");
+				let added_inputs = text.inputs.iter().skip(node.inputs.len()).map(|input| input.default.clone()).collect::<Vec<_>>();
+				node.inputs.extend(added_inputs);
+				node.implementation = text.generate_implementation();
+			}
+			_ => {}
+		}
+	}
+}
+
 pub fn collect_node_types() -> Vec<FrontendNodeType> {
 	DOCUMENT_NODE_TYPES
 		.iter()
@@ -2569,12 +3038,12 @@ pub fn new_text_network(text: String, font: Font, size: f64) -> NodeNetwork {
 		inputs: vec![0],
 		..Default::default()
 	};
-	network.push_node(text_generator.to_document_node(
+	network.push_node(text_generator.to_document_node_default_inputs(
 		[
-			NodeInput::Network(concrete!(WasmEditorApi)),
-			NodeInput::value(TaggedValue::String(text), false),
-			NodeInput::value(TaggedValue::Font(font), false),
-			NodeInput::value(TaggedValue::F64(size), false),
+			Some(NodeInput::Network(concrete!(WasmEditorApi))),
+			Some(NodeInput::value(TaggedValue::String(text), false)),
+			Some(NodeInput::value(TaggedValue::Font(font), false)),
+			Some(NodeInput::value(TaggedValue::F64(size), false)),
 		],
 		DocumentNodeMetadata::position((0, 4)),
 	));
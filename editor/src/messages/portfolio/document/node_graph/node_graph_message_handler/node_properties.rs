@@ -10,9 +10,11 @@ use graph_craft::concrete;
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNode, NodeId, NodeInput};
 use graph_craft::imaginate_input::{ImaginateMaskStartingFill, ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus};
-use graphene_core::raster::{BlendMode, Color, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
-use graphene_core::text::Font;
-use graphene_core::vector::style::{FillType, GradientType, LineCap, LineJoin};
+use graphene_core::consts::IMAGE_SIZE_MAX;
+use graphene_core::raster::{BlendMode, CanvasAnchor, Color, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, ResampleFilter, SelectiveColorChoice, StrokePosition};
+use graphene_core::text::{Font, TextPathAlignment};
+use graphene_core::vector::style::{FillRule, FillType, GradientType, LineCap, LineJoin};
+use graphene_core::vector::SymmetryType;
 
 use glam::{DVec2, IVec2};
 
@@ -368,6 +370,48 @@ fn noise_type(document_node: &DocumentNode, node_id: u64, index: usize, name: &s
 	LayoutGroup::Row { widgets }.with_tooltip("Type of Noise")
 }
 
+//TODO Use generalized Version of this as soon as it's available
+fn resample_filter(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::ResampleFilter(filter),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = ResampleFilter::list()
+			.into_iter()
+			.map(|filter| MenuListEntry::new(filter.to_string()).on_update(update_value(move |_| TaggedValue::ResampleFilter(filter), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(filter as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Interpolation between pixels when the image is resampled")
+}
+
+//TODO Use generalized Version of this as soon as it's available
+fn canvas_anchor(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::CanvasAnchor(anchor),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = CanvasAnchor::list()
+			.into_iter()
+			.map(|anchor| MenuListEntry::new(anchor.to_string()).on_update(update_value(move |_| TaggedValue::CanvasAnchor(anchor), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(anchor as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Side or corner of the canvas which the image stays attached to")
+}
+
 //TODO Use generalized Version of this as soon as it's available
 fn blend_mode(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
@@ -457,6 +501,91 @@ fn line_join_widget(document_node: &DocumentNode, node_id: u64, index: usize, na
 	LayoutGroup::Row { widgets }
 }
 
+fn text_path_alignment_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TextPathAlignment(alignment),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Start", TextPathAlignment::Start), ("Center", TextPathAlignment::Center), ("End", TextPathAlignment::End)]
+			.into_iter()
+			.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::TextPathAlignment(val), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(alignment as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+fn stroke_position_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::StrokePosition(position),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = StrokePosition::list()
+			.into_iter()
+			.map(|position| RadioEntryData::new(position.to_string()).on_update(update_value(move |_| TaggedValue::StrokePosition(position), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(position as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Whether the stroke lies inside the edges of the layer, is centered on them, or lies outside them")
+}
+
+fn symmetry_type_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::SymmetryType(symmetry_type),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("Horizontal", SymmetryType::Horizontal),
+			("Vertical", SymmetryType::Vertical),
+			("Both", SymmetryType::Both),
+			("Radial", SymmetryType::Radial),
+		]
+		.into_iter()
+		.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::SymmetryType(val), node_id, index)))
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(symmetry_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+fn fill_rule_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::FillRule(fill_rule),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Non-Zero", FillRule::NonZero), ("Even-Odd", FillRule::EvenOdd)]
+			.into_iter()
+			.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::FillRule(val), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(fill_rule as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
 fn fill_type_widget(document_node: &DocumentNode, node_id: u64, index: usize) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, "Fill Type", FrontendGraphDataType::General, true);
 	if let &NodeInput::Value {
@@ -849,11 +978,142 @@ pub fn curves_properties(document_node: &DocumentNode, node_id: NodeId, _context
 	vec![curves]
 }
 
-pub fn _blur_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).max(20.).int(), true);
-	let sigma = number_widget(document_node, node_id, 2, "Sigma", NumberInput::default().min(0.).max(10000.), true);
+pub fn blur_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).max(250.).unit(" px"), true);
+
+	vec![LayoutGroup::Row { widgets: radius }]
+}
+
+pub fn motion_blur_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let angle = number_widget(document_node, node_id, 1, "Angle", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let distance = number_widget(document_node, node_id, 2, "Distance", NumberInput::default().min(0.).unit(" px"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Direction of the motion"),
+		LayoutGroup::Row { widgets: distance }.with_tooltip("Length of the streak left by each point of the image"),
+	]
+}
+
+pub fn radial_blur_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let center = vec2_widget(document_node, node_id, 1, "Center", "X", "Y", " px", add_blank_assist);
+	let angle = number_widget(document_node, node_id, 2, "Angle", NumberInput::default().min(0.).max(360.).unit("°"), true);
 
-	vec![LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: sigma }]
+	vec![
+		center.with_tooltip("Offset of the point the image spins about from the middle of the image"),
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Arc swept by each point of the image"),
+	]
+}
+
+pub fn zoom_blur_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let center = vec2_widget(document_node, node_id, 1, "Center", "X", "Y", " px", add_blank_assist);
+	let amount = number_widget(document_node, node_id, 2, "Amount", NumberInput::default().min(0.).max(100.).unit("%"), true);
+
+	vec![
+		center.with_tooltip("Offset of the point the image streaks away from, from the middle of the image"),
+		LayoutGroup::Row { widgets: amount }.with_tooltip("Length of each streak as a fraction of its distance from the center"),
+	]
+}
+
+pub fn unsharp_mask_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let amount = number_widget(document_node, node_id, 1, "Amount", NumberInput::default().min(0.).max(500.).unit("%"), true);
+	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default().min(0.).max(250.).unit(" px"), true);
+	let threshold = number_widget(document_node, node_id, 3, "Threshold", NumberInput::default().min(0.).max(100.).unit("%"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: amount },
+		LayoutGroup::Row { widgets: radius }.with_tooltip("Size of the details which are sharpened"),
+		LayoutGroup::Row { widgets: threshold }.with_tooltip("Difference in luminance from the surroundings below which pixels are left unsharpened"),
+	]
+}
+
+pub fn resize_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mode_index = 1;
+	let mut mode = start_widgets(document_node, node_id, mode_index, "Mode", FrontendGraphDataType::General, true);
+	let mut relative = false;
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::RelativeAbsolute(relative_or_absolute),
+		exposed: false,
+	} = &document_node.inputs[mode_index]
+	{
+		relative = relative_or_absolute == RelativeAbsolute::Relative;
+		let entries = vec![
+			RadioEntryData::new("Relative").on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Relative), node_id, mode_index)),
+			RadioEntryData::new("Absolute").on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Absolute), node_id, mode_index)),
+		];
+		mode.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(relative_or_absolute as u32)).widget_holder(),
+		]);
+	}
+	let filter = resample_filter(document_node, node_id, 5, "Filter", true);
+
+	let mut layout = vec![LayoutGroup::Row { widgets: mode }.with_tooltip("Resize by a factor of the current size, or to a size in pixels")];
+	if relative {
+		let factor = number_widget(document_node, node_id, 4, "Factor", NumberInput::default().min(0.).max(100.).unit("x"), true);
+		layout.push(LayoutGroup::Row { widgets: factor });
+	} else {
+		let width = number_widget(document_node, node_id, 2, "Width", NumberInput::default().int().min(0.).max(IMAGE_SIZE_MAX as f64).unit(" px"), true);
+		let height = number_widget(document_node, node_id, 3, "Height", NumberInput::default().int().min(0.).max(IMAGE_SIZE_MAX as f64).unit(" px"), true);
+		layout.extend([LayoutGroup::Row { widgets: width }, LayoutGroup::Row { widgets: height }]);
+	}
+	layout.push(filter);
+	layout
+}
+
+pub fn crop_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let offset = vec2_widget(document_node, node_id, 1, "Offset", "X", "Y", " px", add_blank_assist);
+	let size = vec2_widget(document_node, node_id, 2, "Size", "W", "H", " px", add_blank_assist);
+	let content_bounds = bool_widget(document_node, node_id, 3, "Content Bounds", true);
+
+	vec![
+		offset.with_tooltip("Top left corner of the cropped area, from the top left corner of the image"),
+		size,
+		LayoutGroup::Row { widgets: content_bounds }.with_tooltip("Crop to the bounds of the visible pixels instead of the area above"),
+	]
+}
+
+pub fn canvas_size_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let width = number_widget(document_node, node_id, 1, "Width", NumberInput::default().int().min(0.).max(IMAGE_SIZE_MAX as f64).unit(" px"), true);
+	let height = number_widget(document_node, node_id, 2, "Height", NumberInput::default().int().min(0.).max(IMAGE_SIZE_MAX as f64).unit(" px"), true);
+	let anchor = canvas_anchor(document_node, node_id, 3, "Anchor", true);
+
+	vec![LayoutGroup::Row { widgets: width }, LayoutGroup::Row { widgets: height }, anchor]
+}
+
+pub fn layer_style_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color = color_widget(document_node, node_id, 1, "Color", ColorButton::default().allow_none(false), true);
+	let blend_mode = blend_mode(document_node, node_id, 2, "Blend Mode", true);
+	let opacity = number_widget(document_node, node_id, 3, "Opacity", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let angle = number_widget(document_node, node_id, 4, "Angle", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let distance = number_widget(document_node, node_id, 5, "Distance", NumberInput::default().min(0.).unit(" px"), true);
+	let spread = number_widget(document_node, node_id, 6, "Spread", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let size = number_widget(document_node, node_id, 7, "Size", NumberInput::default().min(0.).unit(" px"), true);
+
+	vec![
+		color,
+		blend_mode,
+		LayoutGroup::Row { widgets: opacity },
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Direction in which the effect is moved away from the layer"),
+		LayoutGroup::Row { widgets: distance }.with_tooltip("How far the effect is moved away from the layer"),
+		LayoutGroup::Row { widgets: spread }.with_tooltip("Fraction of the size across which the effect stays solid before it fades"),
+		LayoutGroup::Row { widgets: size }.with_tooltip("How far the effect reaches from the edges of the layer"),
+	]
+}
+
+pub fn raster_stroke_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let color = color_widget(document_node, node_id, 1, "Color", ColorButton::default().allow_none(false), true);
+	let blend_mode = blend_mode(document_node, node_id, 2, "Blend Mode", true);
+	let opacity = number_widget(document_node, node_id, 3, "Opacity", NumberInput::default().min(0.).max(100.).unit("%"), true);
+	let size = number_widget(document_node, node_id, 4, "Size", NumberInput::default().min(0.).unit(" px"), true);
+	let position = stroke_position_widget(document_node, node_id, 5, "Position", true);
+
+	vec![
+		color,
+		blend_mode,
+		LayoutGroup::Row { widgets: opacity },
+		LayoutGroup::Row { widgets: size }.with_tooltip("Width of the stroke"),
+		position,
+	]
 }
 
 pub fn adjust_threshold_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -1237,6 +1497,20 @@ pub fn node_section_font(document_node: &DocumentNode, node_id: NodeId, _context
 		result.push(LayoutGroup::Row { widgets: style });
 	}
 	result.push(LayoutGroup::Row { widgets: size });
+
+	// Laying the text out along a path, which only applies while a path is connected
+	let start_offset = number_widget(document_node, node_id, 5, "Start Offset", NumberInput::default().unit(" px"), true);
+	let alignment = text_path_alignment_widget(document_node, node_id, 6, "Alignment", true);
+	let letter_spacing = number_widget(document_node, node_id, 7, "Letter Spacing", NumberInput::default().unit(" px"), true);
+	let flip = bool_widget(document_node, node_id, 8, "Flip", true);
+	let rotate_glyphs = bool_widget(document_node, node_id, 9, "Rotate Glyphs", true);
+	result.extend([
+		LayoutGroup::Row { widgets: start_offset }.with_tooltip("Distance along the path from where the text is placed according to the alignment"),
+		alignment.with_tooltip("Place the text from the start, around the middle, or back from the end of the path"),
+		LayoutGroup::Row { widgets: letter_spacing }.with_tooltip("Extra space between each glyph and the next along the path"),
+		LayoutGroup::Row { widgets: flip }.with_tooltip("Follow the path in reverse, placing the text on its other side"),
+		LayoutGroup::Row { widgets: rotate_glyphs }.with_tooltip("Turn each glyph to follow the direction of the path, instead of keeping it upright"),
+	]);
 	result
 }
 
@@ -1831,12 +2105,179 @@ pub fn circular_repeat_properties(document_node: &DocumentNode, node_id: NodeId,
 	vec![LayoutGroup::Row { widgets: angle_offset }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: count }]
 }
 
+pub fn grid_repeat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let columns = number_widget(document_node, node_id, 1, "Columns", NumberInput::default().min(1.).int(), true);
+	let rows = number_widget(document_node, node_id, 2, "Rows", NumberInput::default().min(1.).int(), true);
+	let spacing = vec2_widget(document_node, node_id, 3, "Spacing", "X", "Y", " px", add_blank_assist);
+	let stagger = number_widget(document_node, node_id, 4, "Stagger", NumberInput::default(), true);
+	let rotation = number_widget(document_node, node_id, 5, "Step Rotation", NumberInput::default().unit("°"), true);
+	let scale = number_widget(document_node, node_id, 6, "Step Scale", NumberInput::default().min(0.), true);
+	let opacity_falloff = number_widget(document_node, node_id, 7, "Opacity Falloff", NumberInput::default().min(0.).max(1.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: columns },
+		LayoutGroup::Row { widgets: rows },
+		spacing,
+		LayoutGroup::Row { widgets: stagger }.with_tooltip("Shifts every other row along by this fraction of the column spacing"),
+		LayoutGroup::Row { widgets: rotation },
+		LayoutGroup::Row { widgets: scale },
+		LayoutGroup::Row { widgets: opacity_falloff }.with_tooltip("Fraction of the full opacity lost by each copy after the first"),
+	]
+}
+
+pub fn symmetry_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let symmetry_type = symmetry_type_widget(document_node, node_id, 1, "Type", true);
+	let center = vec2_widget(document_node, node_id, 2, "Center", "X", "Y", " px", add_blank_assist);
+	let angle = number_widget(document_node, node_id, 3, "Angle", NumberInput::default().unit("°"), true);
+	let count = number_widget(document_node, node_id, 4, "Count", NumberInput::default().min(1.).int(), true);
+	let weld = bool_widget(document_node, node_id, 5, "Weld", true);
+
+	vec![
+		symmetry_type,
+		center.with_tooltip("Point which the mirror axes pass through, or which radial copies turn about"),
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Rotation of the mirror axes"),
+		LayoutGroup::Row { widgets: count }.with_tooltip("Number of copies around the center for radial symmetry"),
+		LayoutGroup::Row { widgets: weld }.with_tooltip("Join paths which end on a mirror axis to their reflections, closing those with both ends on it"),
+	]
+}
+
+pub fn repeat_along_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let count = number_widget(document_node, node_id, 2, "Count", NumberInput::default().min(1.).int(), true);
+	let align_to_path = bool_widget(document_node, node_id, 3, "Align to Path", true);
+	let rotation = number_widget(document_node, node_id, 4, "Step Rotation", NumberInput::default().unit("°"), true);
+	let scale = number_widget(document_node, node_id, 5, "Step Scale", NumberInput::default().min(0.), true);
+	let opacity_falloff = number_widget(document_node, node_id, 6, "Opacity Falloff", NumberInput::default().min(0.).max(1.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: count },
+		LayoutGroup::Row { widgets: align_to_path },
+		LayoutGroup::Row { widgets: rotation },
+		LayoutGroup::Row { widgets: scale },
+		LayoutGroup::Row { widgets: opacity_falloff }.with_tooltip("Fraction of the full opacity lost by each copy after the first"),
+	]
+}
+
+pub fn scatter_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let along_outline = bool_widget(document_node, node_id, 1, "Along Outline", true);
+	let spacing = number_widget(document_node, node_id, 2, "Spacing", NumberInput::default().unit(" px").min(0.), true);
+	let count = number_widget(document_node, node_id, 3, "Count", NumberInput::default().min(0.).int(), true);
+	let fill_rule = fill_rule_widget(document_node, node_id, 4, "Fill Rule", true);
+	let seed = number_widget(document_node, node_id, 5, "Seed", NumberInput::default().min(0.).int(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: along_outline }.with_tooltip("Scatter the points along the outline of the shape instead of over its filled area"),
+		LayoutGroup::Row { widgets: spacing }.with_tooltip("Minimum distance kept between the points, or 0 to place them independently of each other"),
+		LayoutGroup::Row { widgets: count }.with_tooltip("Maximum number of points, which may not all fit when the spacing is large"),
+		fill_rule,
+		LayoutGroup::Row { widgets: seed }.with_tooltip("Seed determines the random outcome, enabling limitless unique variations"),
+	]
+}
+
+pub fn copy_to_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let align_to_tangent = bool_widget(document_node, node_id, 2, "Align to Tangent", true);
+	let random_scale_min = number_widget(document_node, node_id, 3, "Random Scale Min", NumberInput::default().min(0.), true);
+	let random_scale_max = number_widget(document_node, node_id, 4, "Random Scale Max", NumberInput::default().min(0.), true);
+	let random_rotation = number_widget(document_node, node_id, 5, "Random Rotation", NumberInput::default().min(0.).max(180.).unit("°"), true);
+	let index_scale = number_widget(document_node, node_id, 6, "Index Scale", NumberInput::default(), true);
+	let index_rotation = number_widget(document_node, node_id, 7, "Index Rotation", NumberInput::default().unit("°"), true);
+	let seed = number_widget(document_node, node_id, 8, "Seed", NumberInput::default().min(0.).int(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: align_to_tangent },
+		LayoutGroup::Row { widgets: random_scale_min },
+		LayoutGroup::Row { widgets: random_scale_max },
+		LayoutGroup::Row { widgets: random_rotation },
+		LayoutGroup::Row { widgets: index_scale },
+		LayoutGroup::Row { widgets: index_rotation },
+		LayoutGroup::Row { widgets: seed }.with_tooltip("Seed determines the random outcome, enabling limitless unique variations"),
+	]
+}
+
 pub fn resample_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let spacing = number_widget(document_node, node_id, 1, "Spacing", NumberInput::default().min(1.), true);
 
 	vec![LayoutGroup::Row { widgets: spacing }]
 }
 
+pub fn round_corners_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().unit(" px").min(0.), true);
+	let chamfer = bool_widget(document_node, node_id, 2, "Chamfer", true);
+
+	vec![LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: chamfer }]
+}
+
+pub fn offset_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let distance = number_widget(document_node, node_id, 1, "Distance", NumberInput::default().unit(" px"), true);
+	let line_join = line_join_widget(document_node, node_id, 2, "Line Join", true);
+	let miter_limit = number_widget(document_node, node_id, 3, "Miter Limit", NumberInput::default().min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: distance }, line_join, LayoutGroup::Row { widgets: miter_limit }]
+}
+
+pub fn roughen_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let size = number_widget(document_node, node_id, 1, "Size", NumberInput::default().unit(" px").min(1.), true);
+	let amount = number_widget(document_node, node_id, 2, "Amount", NumberInput::default().unit(" px").min(0.), true);
+	let smooth = bool_widget(document_node, node_id, 3, "Smooth", true);
+	let time = number_widget(document_node, node_id, 4, "Time", NumberInput::default(), true);
+	let seed = number_widget(document_node, node_id, 5, "Seed", NumberInput::default().min(0.).int(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: size },
+		LayoutGroup::Row { widgets: amount },
+		LayoutGroup::Row { widgets: smooth }.with_tooltip("Smooth noise moves neighbouring points together, while uniform noise moves each point independently"),
+		LayoutGroup::Row { widgets: time }.with_tooltip("Animating the time makes the roughness wiggle, jumping at each whole number with uniform noise or flowing with smooth noise"),
+		LayoutGroup::Row { widgets: seed }.with_tooltip("Seed determines the random outcome, enabling limitless unique variations"),
+	]
+}
+
+pub fn morph_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let time = number_widget(document_node, node_id, 2, "Time", NumberInput::default().min(0.).max(1.), true);
+
+	vec![LayoutGroup::Row { widgets: time }.with_tooltip("How far the shape and style have changed from the source (0) to the target (1)")]
+}
+
+pub fn blend_steps_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let steps = number_widget(
+		document_node,
+		node_id,
+		2,
+		"Steps",
+		NumberInput::default().min(0.).max(graphene_core::consts::BLEND_STEPS_MAX as f64).int(),
+		true,
+	);
+
+	vec![LayoutGroup::Row { widgets: steps }.with_tooltip("Number of intermediate shapes placed between the source and the target")]
+}
+
+pub fn set_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let per_subpath = bool_widget(document_node, node_id, 2, "Per Subpath", true);
+	let values = vec_f32_input(document_node, node_id, 3, "Values", TextInput::default().centered(true), true);
+
+	vec![
+		LayoutGroup::Row { widgets: name }.with_tooltip("Name which later nodes look the attribute up by, such as \"width\""),
+		LayoutGroup::Row { widgets: per_subpath }.with_tooltip("Give a value to each subpath instead of each point"),
+		LayoutGroup::Row { widgets: values }.with_tooltip("Values given to the points or subpaths in order, repeating from the start if there are more of them than values"),
+	]
+}
+
+pub fn dash_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let dash_lengths = vec_f32_input(document_node, node_id, 1, "Dash Lengths", TextInput::default().centered(true), true);
+	let dash_offset = number_widget(document_node, node_id, 2, "Dash Offset", NumberInput::default().unit(" px"), true);
+
+	vec![LayoutGroup::Row { widgets: dash_lengths }, LayoutGroup::Row { widgets: dash_offset }]
+}
+
+pub fn variable_width_stroke_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let profile = curves_widget(document_node, node_id, 1, "Width Profile", true);
+	let use_width_attribute = bool_widget(document_node, node_id, 2, "Use Width Attribute", true);
+
+	vec![
+		profile.with_tooltip("Fraction of the stroke weight (up) at each fraction of the length along the subpath (across)"),
+		LayoutGroup::Row { widgets: use_width_attribute }.with_tooltip("Scale the stroke weight by the \"width\" point attribute instead of the profile, where the attribute is present"),
+	]
+}
+
 /// Fill Node Widgets LayoutGroup
 pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let fill_type_index = 1;
@@ -172,6 +172,13 @@ pub fn get_text(layer: LayerNodeIdentifier, document: &Document) -> Option<(&Str
 	Some((text, font, font_size))
 }
 
+/// Does the text of the layer follow a path connected to its text node?
+pub fn text_follows_path(layer: LayerNodeIdentifier, document: &Document) -> bool {
+	NodeGraphLayer::new(layer, document)
+		.and_then(|layer| layer.find_node_inputs("Text"))
+		.is_some_and(|inputs| matches!(inputs.get(4), Some(NodeInput::Node { .. })))
+}
+
 /// Is a specified layer an artboard?
 pub fn is_artboard(layer: LayerNodeIdentifier, document: &Document) -> bool {
 	NodeGraphLayer::new(layer, document).is_some_and(|layer| layer.uses_node("Artboard"))
@@ -323,12 +323,17 @@ impl TextToolData {
 		resize_overlays(&mut self.overlays, responses, 1);
 
 		let editing_text = self.editing_text.as_ref()?;
-		let buzz_face = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data));
-		let far = graphene_core::text::bounding_box(&self.new_text, buzz_face, editing_text.font_size, None);
-		let quad = Quad::from_box([DVec2::ZERO, far]);
+		// Text following a path isn't laid out in a box, so its bounds are only known once it has been rendered
+		let bounds = if graph_modification_utils::text_follows_path(self.layer, &document.document_legacy) {
+			document.metadata().bounding_box_viewport(self.layer)?
+		} else {
+			let buzz_face = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data));
+			let far = graphene_core::text::bounding_box(&self.new_text, buzz_face, editing_text.font_size, None);
+			let quad = Quad::from_box([DVec2::ZERO, far]);
 
-		let transformed_quad = document.metadata().transform_to_viewport(self.layer) * quad;
-		let bounds = transformed_quad.bounding_box();
+			let transformed_quad = document.metadata().transform_to_viewport(self.layer) * quad;
+			transformed_quad.bounding_box()
+		};
 
 		let operation = Operation::SetLayerTransformInViewport {
 			path: self.overlays[0].clone(),
@@ -347,7 +352,12 @@ impl TextToolData {
 		Some(combined_bounds)
 	}
 
-	fn fix_text_bounds(&self, new_text: &str, _document: &DocumentMessageHandler, render_data: &RenderData, responses: &mut VecDeque<Message>) -> Option<()> {
+	fn fix_text_bounds(&self, new_text: &str, document: &DocumentMessageHandler, render_data: &RenderData, responses: &mut VecDeque<Message>) -> Option<()> {
+		// Text following a path stays in place along it, rather than growing from its corner
+		if graph_modification_utils::text_follows_path(self.layer, &document.document_legacy) {
+			return None;
+		}
+
 		let layer = self.layer.to_path();
 		let old_bounds = self.get_bounds(&self.editing_text.as_ref()?.text, render_data)?;
 		let new_bounds = self.get_bounds(new_text, render_data)?;
@@ -383,6 +393,9 @@ fn resize_overlays(overlays: &mut Vec<Vec<LayerId>>, responses: &mut VecDeque<Me
 fn update_overlays(document: &DocumentMessageHandler, tool_data: &mut TextToolData, responses: &mut VecDeque<Message>, render_data: &RenderData) {
 	let get_bounds = |layer: LayerNodeIdentifier, document: &DocumentMessageHandler, render_data: &RenderData| {
 		let (text, font, font_size) = graph_modification_utils::get_text(layer, &document.document_legacy)?;
+		if graph_modification_utils::text_follows_path(layer, &document.document_legacy) {
+			return document.metadata().bounding_box_viewport(layer);
+		}
 		let buzz_face = render_data.font_cache.get(font).map(|data| load_face(data));
 		let far = graphene_core::text::bounding_box(text, buzz_face, font_size, None);
 		let quad = Quad::from_box([DVec2::ZERO, far]);
@@ -123,6 +123,59 @@ impl Bezier {
 		}
 	}
 
+	/// Returns the coefficients of the polynomial form of the curve, starting with the constant term.
+	pub(crate) fn polynomial_coefficients(&self) -> Vec<DVec2> {
+		match self.handles {
+			BezierHandles::Linear => vec![self.start, self.end - self.start],
+			BezierHandles::Quadratic { handle } => vec![self.start, 2. * (handle - self.start), self.start - 2. * handle + self.end],
+			BezierHandles::Cubic { handle_start, handle_end } => vec![
+				self.start,
+				3. * (handle_start - self.start),
+				3. * (self.start - 2. * handle_start + handle_end),
+				self.end - 3. * handle_end + 3. * handle_start - self.start,
+			],
+		}
+	}
+
+	/// Returns the signed area swept by the line from `origin` to a point moving along the curve, along with the first moments of that area about `origin`.
+	/// These are the exact line integrals of Green's theorem, so summing them over the segments of a closed path gives the area and moments of the path.
+	pub(crate) fn area_and_moments(&self, origin: DVec2) -> (f64, DVec2) {
+		let mut coefficients = self.polynomial_coefficients();
+		coefficients[0] -= origin;
+		let x = coefficients.iter().map(|coefficient| coefficient.x).collect::<Vec<f64>>();
+		let y = coefficients.iter().map(|coefficient| coefficient.y).collect::<Vec<f64>>();
+		let dx = utils::polynomial_derivative(&x);
+		let dy = utils::polynomial_derivative(&y);
+
+		// A = ∮ (x dy - y dx) / 2, ∬ x dA = ∮ x² dy / 2, ∬ y dA = -∮ y² dx / 2
+		let area = (utils::polynomial_unit_integral(&utils::polynomial_product(&x, &dy)) - utils::polynomial_unit_integral(&utils::polynomial_product(&y, &dx))) / 2.;
+		let moment_x = utils::polynomial_unit_integral(&utils::polynomial_product(&utils::polynomial_product(&x, &x), &dy)) / 2.;
+		let moment_y = -utils::polynomial_unit_integral(&utils::polynomial_product(&utils::polynomial_product(&y, &y), &dx)) / 2.;
+		(area, DVec2::new(moment_x, moment_y))
+	}
+
+	/// Returns the signed area of the region enclosed by the curve and the straight line from its end back to its start.
+	/// The area is positive when this region is traversed counterclockwise with +y pointing up, as for [Bezier::winding].
+	/// If the curve intersects itself or the line, regions traversed in opposite directions cancel out.
+	pub fn signed_area(&self) -> f64 {
+		self.area_and_moments(self.start).0 + Bezier::from_linear_dvec2(self.end, self.start).area_and_moments(self.start).0
+	}
+
+	/// Returns the area of the region enclosed by the curve and the straight line from its end back to its start.
+	pub fn area(&self) -> f64 {
+		self.signed_area().abs()
+	}
+
+	/// Returns the centroid of the region enclosed by the curve and the straight line from its end back to its start,
+	/// or `None` if the region has no area, as is the case for a linear segment.
+	pub fn centroid(&self) -> Option<DVec2> {
+		let (area, moments) = [*self, Bezier::from_linear_dvec2(self.end, self.start)]
+			.iter()
+			.map(|bezier| bezier.area_and_moments(self.start))
+			.fold((0., DVec2::ZERO), |(area, moments), (bezier_area, bezier_moments)| (area + bezier_area, moments + bezier_moments));
+		(area.abs() > STRICT_MAX_ABSOLUTE_DIFFERENCE).then(|| self.start + moments / area)
+	}
+
 	/// Returns the parametric `t`-value that corresponds to the closest point on the curve to the provided point.
 	/// Uses a searching algorithm akin to binary search that can be customized using the optional [ProjectionOptions] struct.
 	/// <iframe frameBorder="0" width="100%" height="300px" src="https://graphite.rs/libraries/bezier-rs#bezier/project/solo" title="Project Demo"></iframe>
@@ -273,6 +326,25 @@ mod tests {
 		assert!(utils::f64_compare(bezier_cubic.length(None), 199., 1e-2));
 	}
 
+	#[test]
+	fn test_area_and_centroid() {
+		let linear = Bezier::from_linear_coordinates(10., 20., 40., 70.);
+		assert_eq!(linear.signed_area(), 0.);
+		assert!(linear.centroid().is_none());
+
+		// The parabolic segment under y = x(2 - x) has an area of 4/3 and its centroid at (1, 2/5)
+		let quadratic = Bezier::from_quadratic_coordinates(0., 0., 1., 2., 2., 0.);
+		assert!(utils::f64_compare(quadratic.signed_area(), -4. / 3., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(utils::f64_compare(quadratic.reverse().signed_area(), 4. / 3., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(utils::f64_compare(quadratic.area(), 4. / 3., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(quadratic.centroid().unwrap().abs_diff_eq(DVec2::new(1., 0.4), MAX_ABSOLUTE_DIFFERENCE));
+
+		// Elevating the degree of the curve does not change the region it encloses
+		let cubic = Bezier::from_cubic_coordinates(0., 0., 2. / 3., 4. / 3., 4. / 3., 4. / 3., 2., 0.);
+		assert!(utils::f64_compare(cubic.signed_area(), -4. / 3., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(cubic.centroid().unwrap().abs_diff_eq(DVec2::new(1., 0.4), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
 	#[test]
 	fn test_project() {
 		let bezier1 = Bezier::from_cubic_coordinates(4., 4., 23., 45., 10., 30., 56., 90.);
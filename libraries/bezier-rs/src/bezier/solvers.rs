@@ -1,5 +1,5 @@
 use super::*;
-use crate::utils::{solve_cubic, solve_quadratic, TValue};
+use crate::utils::TValue;
 
 use glam::DMat2;
 use std::ops::Range;
@@ -457,23 +457,7 @@ impl Bezier {
 				if target_point.x >= self.start.x.max(self.end.x).max(p1.x) {
 					return resulting_sign;
 				}
-				let a = self.end.y - 2. * p1.y + self.start.y;
-				let b = 2. * (p1.y - self.start.y);
-				let c = self.start.y - target_point.y;
-
-				let discriminant = b * b - 4. * a * c;
-				let two_times_a = 2. * a;
-				for t in solve_quadratic(discriminant, two_times_a, b, c) {
-					if (0.0..=1.).contains(&t) {
-						let x = self.evaluate(TValue::Parametric(t)).x;
-						if target_point.x >= x {
-							return resulting_sign;
-						} else {
-							return 0;
-						}
-					}
-				}
-				0
+				self.crossing_winding(target_point, resulting_sign)
 			}
 			BezierHandles::Cubic { handle_start: p1, handle_end: p2 } => {
 				if target_point.x < self.start.x.min(self.end.x).min(p1.x).min(p2.x) {
@@ -482,34 +466,49 @@ impl Bezier {
 				if target_point.x >= self.start.x.max(self.end.x).max(p1.x).max(p2.x) {
 					return resulting_sign;
 				}
-				let a = self.end.y - 3. * p2.y + 3. * p1.y - self.start.y;
-				let b = 3. * (p2.y - 2. * p1.y + self.start.y);
-				let c = 3. * (p1.y - self.start.y);
-				let d = self.start.y - target_point.y;
-				for t in solve_cubic(a, b, c, d) {
-					if (0.0..=1.).contains(&t) {
-						let x = self.evaluate(TValue::Parametric(t)).x;
-						if target_point.x >= x {
-							return resulting_sign;
-						} else {
-							return 0;
-						}
-					}
-				}
-				0
+				self.crossing_winding(target_point, resulting_sign)
 			}
 		}
 	}
 
+	/// Returns the winding contribution of a segment which is monotonic in `y` and spans the height of the target point.
+	/// The crossing is found by bisection rather than by solving for the roots, which loses precision when the segment is nearly flat.
+	fn crossing_winding(&self, target_point: DVec2, resulting_sign: i32) -> i32 {
+		let increasing = self.end.y > self.start.y;
+		let (mut low, mut high) = (0., 1.);
+		while high - low > f64::EPSILON {
+			let middle = (low + high) / 2.;
+			if (self.evaluate(TValue::Parametric(middle)).y < target_point.y) == increasing {
+				low = middle;
+			} else {
+				high = middle;
+			}
+		}
+		if target_point.x >= self.evaluate(TValue::Parametric(low)).x {
+			resulting_sign
+		} else {
+			0
+		}
+	}
+
 	/// Compute the winding number contribution of a single segment.
 	///
 	/// Cast a ray to the left and count intersections.
 	pub fn winding(&self, target_point: DVec2) -> i32 {
-		let extrema = self.get_extrema_t_list();
-		extrema
-			.windows(2)
-			.map(|t| self.trim(TValue::Parametric(t[0]), TValue::Parametric(t[1])).pre_split_winding_number(target_point))
-			.sum()
+		// Extrema lying numerically at the endpoints are skipped, since the subcurve before them would not end exactly at the endpoint
+		let extrema = self.get_extrema_t_list().into_iter().filter(|&t| t > f64::EPSILON.sqrt() && t < 1. - f64::EPSILON.sqrt());
+
+		// Split sequentially rather than trimming so consecutive subcurves share their endpoints exactly
+		let mut winding = 0;
+		let mut remaining = *self;
+		let mut previous_t = 0.;
+		for t in extrema {
+			let [subcurve, rest] = remaining.split(TValue::Parametric((t - previous_t) / (1. - previous_t)));
+			winding += subcurve.pre_split_winding_number(target_point);
+			remaining = rest;
+			previous_t = t;
+		}
+		winding + remaining.pre_split_winding_number(target_point)
 	}
 }
 
@@ -910,4 +909,27 @@ mod tests {
 		assert!(Bezier::from_linear_coordinates(160., 180., 170., 10.).self_intersections(None).is_empty());
 		assert!(Bezier::from_quadratic_coordinates(160., 180., 170., 10., 30., 90.).self_intersections(None).is_empty());
 	}
+
+	#[test]
+	fn test_winding() {
+		// Quarter circle arc ending at the height of the target point, whose extremum lies numerically just before the end
+		let arc = Bezier::from_cubic_coordinates(5., 10., 2.2410761111049298, 10., 0., 7.758923888895071, 0., 5.);
+		assert_eq!(arc.winding(DVec2::new(5., 5.)), 1);
+		assert_eq!(arc.winding(DVec2::new(5., 7.5)), 1);
+		assert_eq!(arc.winding(DVec2::new(-1., 7.5)), 0);
+
+		// Nearly flat curve, whose crossing would be misplaced by solving for the roots
+		let flat = Bezier::from_cubic_coordinates(
+			7.8897662682801775,
+			7.039833907252881,
+			7.896305579137072,
+			7.039730495363684,
+			7.902851600733563,
+			7.039678487926722,
+			7.909403785597533,
+			7.039678487926722,
+		);
+		assert_eq!(flat.winding(DVec2::new(7.8995802784040094, 7.039727417817574)), 1);
+		assert_eq!(flat.winding(DVec2::new(7.8995, 7.03971)), 0);
+	}
 }
@@ -499,6 +499,43 @@ impl Bezier {
 		}
 	}
 
+	/// Returns the circular arc followed by the bezier to within `max_error`, or `None` if it strays further from every circle, such as for a straight line.
+	/// This recognizes the segments built by [Subpath::new_circular_arc] and other circular segments which span less than a full turn.
+	/// As for [Bezier::arcs], the arc travels from its start angle to its end angle in the direction of increasing angles, whichever direction the bezier travels.
+	pub fn to_circle_arc(&self, max_error: f64) -> Option<CircleArc> {
+		let center = utils::compute_circle_center_from_points(self.start, self.evaluate(TValue::Parametric(0.5)), self.end)?;
+		let radius = center.distance(self.start);
+
+		// The bezier must stay near the circle and turn steadily around it in one direction
+		let mut previous = self.start - center;
+		let mut sweep_angle = 0.;
+		for index in 1..=CIRCLE_ARC_SAMPLES {
+			let offset = self.evaluate(TValue::Parametric(index as f64 / CIRCLE_ARC_SAMPLES as f64)) - center;
+			let turn = previous.angle_between(offset);
+			if (offset.length() - radius).abs() > max_error || turn * sweep_angle < 0. {
+				return None;
+			}
+			sweep_angle += turn;
+			previous = offset;
+		}
+		if sweep_angle.abs() >= 2. * PI {
+			return None;
+		}
+
+		let angle_of = |point: DVec2| DVec2::X.angle_between(point - center);
+		let (start_angle, end_angle) = if sweep_angle >= 0. {
+			(angle_of(self.start), angle_of(self.end))
+		} else {
+			(angle_of(self.end), angle_of(self.start))
+		};
+		Some(CircleArc {
+			center,
+			radius,
+			start_angle,
+			end_angle,
+		})
+	}
+
 	/// Implements an algorithm that approximates a bezier curve with circular arcs.
 	/// This algorithm uses a method akin to binary search to find an arc that approximates a maximal segment of the curve.
 	/// Once a maximal arc has been found for a sub-segment of the curve, the algorithm continues by starting again at the end of the previous approximation.
@@ -622,6 +659,8 @@ mod tests {
 	use crate::utils::{Cap, TValue};
 	use crate::EmptyId;
 
+	use std::f64::consts::FRAC_PI_2;
+
 	#[test]
 	fn test_split() {
 		let line = Bezier::from_linear_coordinates(25., 25., 75., 75.);
@@ -982,6 +1021,30 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_to_circle_arc() {
+		let center = DVec2::new(20., 30.);
+		let arc = Subpath::<EmptyId>::new_circular_arc(center, 50., -FRAC_PI_2, PI, Some(1e-3));
+		for bezier in arc.iter() {
+			let circle_arc = bezier.to_circle_arc(1e-3).unwrap();
+			assert!(circle_arc.center.abs_diff_eq(center, 1e-3));
+			assert!(f64_compare(circle_arc.radius, 50., 1e-3));
+
+			// The arc travels in the direction of increasing angles, whichever direction the bezier travels
+			let expected_start = DVec2::X.angle_between(bezier.start() - center);
+			let expected_end = DVec2::X.angle_between(bezier.end() - center);
+			assert!(f64_compare(circle_arc.start_angle, expected_start, 1e-6) && f64_compare(circle_arc.end_angle, expected_end, 1e-6));
+			assert!(compare_arcs(bezier.reverse().to_circle_arc(1e-3).unwrap(), circle_arc));
+		}
+
+		// Lines, curves which bend both ways, and curves of changing curvature are not arcs
+		assert!(Bezier::from_linear_coordinates(0., 0., 100., 50.).to_circle_arc(1e-3).is_none());
+		assert!(Bezier::from_cubic_coordinates(0., 0., 50., 100., 50., -100., 100., 0.).to_circle_arc(1e-3).is_none());
+		let parabola = Bezier::from_quadratic_coordinates(0., 0., 50., 100., 100., 0.);
+		assert!(parabola.to_circle_arc(1e-3).is_none());
+		assert!(parabola.to_circle_arc(10.).is_some());
+	}
+
 	#[test]
 	fn test_arcs_linear() {
 		let bezier = Bezier::from_linear_coordinates(30., 60., 140., 120.);
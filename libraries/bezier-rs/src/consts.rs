@@ -12,6 +12,38 @@ pub const SCALABLE_CURVE_MAX_ENDPOINT_NORMAL_ANGLE: f64 = std::f64::consts::PI /
 pub const MIN_SEPARATION_VALUE: f64 = 5. * 1e-3;
 /// Default error bound for `t_value_to_parametric` function when TValue argument is Euclidean
 pub const DEFAULT_EUCLIDEAN_ERROR_BOUND: f64 = 0.001;
+/// Size of the bounding boxes at which candidate intersections between the segments of a boolean operation are refined numerically.
+pub const BOOLEAN_INTERSECTION_ERROR: f64 = 1e-2;
+/// Distance from the midpoint of an edge at which the regions on either side of it are sampled during a boolean operation.
+pub const BOOLEAN_SAMPLE_OFFSET: f64 = 1e-5;
+/// Largest adjustment, in radians, that curvature makes to the angle of an edge leaving a vertex, used to order edges with the same tangent during a boolean operation.
+pub const BOOLEAN_TANGENT_TIE_BREAK_ANGLE: f64 = 1e-6;
+/// Factor of the tolerance below which the fit of a curve to sampled points is improved by reparameterizing the points instead of splitting the curve.
+pub const FIT_REPARAMETERIZATION_ERROR_FACTOR: f64 = 4.;
+/// Maximum number of reparameterization passes attempted when fitting a curve to sampled points.
+pub const FIT_MAX_REPARAMETERIZATION_ITERATIONS: usize = 4;
+/// Number of points sampled along each segment when checking whether consecutive segments can be merged by `simplify`.
+pub const SIMPLIFY_SAMPLES_PER_SEGMENT: usize = 16;
+/// Maximum number of reparameterization passes attempted when fitting a single curve to consecutive segments in `simplify`.
+pub const SIMPLIFY_MAX_REPARAMETERIZATION_ITERATIONS: usize = 50;
+/// Number of points sampled along each side of a piece of a curve when finding the area covered by its stroke in `outline_stroke`.
+pub const STROKE_SAMPLES_PER_SEGMENT: usize = 32;
+/// Maximum distance allowed between the sides of the area covered by a stroke, as found by `outline_stroke`, and the points sampled along them.
+pub const STROKE_TOLERANCE: f64 = 1e-2;
+/// Number of points sampled along a bezier when checking whether it follows a circular arc in `to_circle_arc`.
+pub const CIRCLE_ARC_SAMPLES: usize = 16;
+/// Number of points sampled along each segment when searching for the point farthest from another subpath in `hausdorff_distance`.
+pub const DISTANCE_SAMPLES_PER_SEGMENT: usize = 32;
+/// Smallest precision used by the distance queries, such as `hausdorff_distance`, so that their searches still end when given an `error` of zero or less.
+pub const MIN_DISTANCE_ERROR: f64 = 1e-6;
+/// Smallest angle, in radians, spanned by each of the segments of an arc built by `new_elliptical_arc`, no matter how small its maximum error.
+pub const MIN_ARC_SEGMENT_ANGLE: f64 = 1e-3;
+/// Smallest angle, in radians, by which a path must turn at an anchor for it to be treated as a corner by `round_corners`.
+pub const CORNER_MIN_ANGLE: f64 = 1e-3;
+/// Size of the bounding boxes at which the points where an offset crosses itself are found by `offset_path`.
+pub const OFFSET_INTERSECTION_ERROR: f64 = 1e-4;
+/// Fraction of the offset distance which a piece of an offset must stay away from the original subpath to be kept by `offset_path`, allowing for the error in approximating the offset.
+pub const OFFSET_MIN_DISTANCE_FACTOR: f64 = 0.9;
 
 // Method argument defaults
 
@@ -23,6 +55,8 @@ pub const DEFAULT_LUT_STEP_SIZE: usize = 10;
 pub const DEFAULT_LENGTH_SUBDIVISIONS: usize = 1000;
 /// Default step size for `reduce` function.
 pub const DEFAULT_REDUCE_STEP_SIZE: f64 = 0.01;
+/// Default angle by which sampled points must turn to form a corner in the `fit_points` function.
+pub const DEFAULT_FIT_CORNER_ANGLE: f64 = std::f64::consts::PI / 3.;
 
 // SVG constants
 pub const SVG_ARG_CUBIC: &str = "C";
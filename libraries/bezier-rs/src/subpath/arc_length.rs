@@ -0,0 +1,271 @@
+use super::*;
+use crate::consts::DEFAULT_LENGTH_SUBDIVISIONS;
+use crate::utils::{SubpathTValue, TValue};
+
+use glam::DVec2;
+
+/// Table of the distance travelled along a [Subpath] at many points, used to look up positions by their distance along the subpath without measuring it again each time.
+/// Build it once with [Subpath::arc_length_table] and reuse it for every lookup, such as when sampling the subpath at equal intervals.
+#[derive(Clone)]
+pub struct ArcLengthTable<'a, ManipulatorGroupId: crate::Identifier> {
+	subpath: &'a Subpath<ManipulatorGroupId>,
+	/// The distance from the start of the subpath at each sample, along with the segment index and parametric `t`-value of the sample.
+	samples: Vec<(f64, usize, f64)>,
+}
+
+/// The position and orientation of a [Subpath] at some distance along it, as yielded by [ArcLengthTable::evenly_spaced_frames].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ArcLengthFrame {
+	pub distance: f64,
+	pub position: DVec2,
+	pub tangent: DVec2,
+	pub normal: DVec2,
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns an [ArcLengthTable] for looking up points on the subpath by their distance along it.
+	/// - `num_subdivisions` - Number of subdivisions used to approximate the length of each curved segment. Straight segments are measured exactly. The default value is `1000`.
+	pub fn arc_length_table(&self, num_subdivisions: Option<usize>) -> ArcLengthTable<'_, ManipulatorGroupId> {
+		let num_subdivisions = num_subdivisions.unwrap_or(DEFAULT_LENGTH_SUBDIVISIONS).max(1);
+
+		let mut samples = vec![(0., 0, 0.)];
+		let mut distance = 0.;
+		for (segment_index, bezier) in self.iter().enumerate() {
+			let steps = if bezier.handle_start().is_none() { 1 } else { num_subdivisions };
+			let mut previous = bezier.start();
+			for step in 1..=steps {
+				let t = step as f64 / steps as f64;
+				let point = bezier.evaluate(TValue::Parametric(t));
+				distance += previous.distance(point);
+				samples.push((distance, segment_index, t));
+				previous = point;
+			}
+		}
+
+		ArcLengthTable { subpath: self, samples }
+	}
+}
+
+impl<'a, ManipulatorGroupId: crate::Identifier> ArcLengthTable<'a, ManipulatorGroupId> {
+	/// Returns the total length of the subpath.
+	pub fn length(&self) -> f64 {
+		self.samples.last().map_or(0., |&(distance, ..)| distance)
+	}
+
+	/// Converts a distance along the subpath into a [SubpathTValue::Parametric] value which can be passed to the other functions of the subpath.
+	/// Distances beyond either end of an open subpath are clamped to its ends, while those of a closed subpath wrap around.
+	/// A subpath without segments gives `t = 0` on segment 0 for every distance.
+	pub fn t_value_at_distance(&self, distance: f64) -> SubpathTValue {
+		let length = self.length();
+		let distance = if self.subpath.closed && length > 0. {
+			distance.rem_euclid(length)
+		} else {
+			distance.clamp(0., length)
+		};
+
+		let (segment_index, t) = self.parametric_at_distance(distance);
+		SubpathTValue::Parametric { segment_index, t }
+	}
+
+	/// Returns the segment index and parametric `t`-value at a distance within the length of the subpath.
+	/// A distance at the end of a segment is given as `t = 1` on that segment, rather than `t = 0` on the next.
+	fn parametric_at_distance(&self, distance: f64) -> (usize, f64) {
+		if self.samples.len() < 2 {
+			return (0, 0.);
+		}
+
+		let index = self.samples.partition_point(|&(sample_distance, ..)| sample_distance < distance).clamp(1, self.samples.len() - 1);
+		let (start_distance, start_segment_index, start_t) = self.samples[index - 1];
+		let (end_distance, segment_index, end_t) = self.samples[index];
+
+		// The last sample of the previous segment is also the start of this segment
+		let start_t = if start_segment_index == segment_index { start_t } else { 0. };
+		let fraction = if end_distance > start_distance {
+			(distance - start_distance) / (end_distance - start_distance)
+		} else {
+			0.
+		};
+
+		(segment_index, (start_t + (end_t - start_t) * fraction).clamp(0., 1.))
+	}
+
+	/// Returns the pieces of the segments between two distances along the subpath, where `start_distance <= end_distance`, both lying within its length.
+	/// Segments are split in turn rather than trimmed, so short pieces are kept even where their `t`-values are very close together.
+	pub(crate) fn beziers_between_distances(&self, start_distance: f64, end_distance: f64) -> Vec<Bezier> {
+		let (start_index, start_t) = self.parametric_at_distance(start_distance);
+		let (end_index, end_t) = self.parametric_at_distance(end_distance);
+
+		(start_index..=end_index)
+			.filter_map(|segment_index| {
+				let t1 = if segment_index == start_index { start_t } else { 0. };
+				let t2 = if segment_index == end_index { end_t } else { 1. };
+				if t2 <= t1 {
+					return None;
+				}
+
+				let bezier = self.subpath.get_segment(segment_index).unwrap();
+				let bezier = if t2 < 1. { bezier.split(TValue::Parametric(t2))[0] } else { bezier };
+				Some(if t1 > 0. { bezier.split(TValue::Parametric(t1 / t2))[1] } else { bezier })
+			})
+			.collect()
+	}
+
+	/// Returns the only anchor of a subpath without segments, or `None` if the subpath has segments.
+	fn lone_anchor(&self) -> Option<DVec2> {
+		(self.subpath.len_segments() == 0).then(|| self.subpath.manipulator_groups.first().map_or(DVec2::ZERO, |group| group.anchor))
+	}
+
+	/// Returns the point at the given distance along the subpath.
+	/// A subpath without segments gives its lone anchor.
+	pub fn evaluate_at_distance(&self, distance: f64) -> DVec2 {
+		if let Some(anchor) = self.lone_anchor() {
+			return anchor;
+		}
+		self.subpath.evaluate(self.t_value_at_distance(distance))
+	}
+
+	/// Returns a normalized unit vector representing the tangent at the given distance along the subpath.
+	/// A subpath without segments has no direction, so this gives the zero vector.
+	pub fn tangent_at_distance(&self, distance: f64) -> DVec2 {
+		if self.lone_anchor().is_some() {
+			return DVec2::ZERO;
+		}
+		self.subpath.tangent(self.t_value_at_distance(distance))
+	}
+
+	/// Returns a normalized unit vector representing the normal at the given distance along the subpath.
+	/// A subpath without segments has no direction, so this gives the zero vector.
+	pub fn normal_at_distance(&self, distance: f64) -> DVec2 {
+		if self.lone_anchor().is_some() {
+			return DVec2::ZERO;
+		}
+		self.subpath.normal(self.t_value_at_distance(distance))
+	}
+
+	/// Splits the subpath at the given distance along it, in the same way as [Subpath::split].
+	/// A subpath without segments is returned whole.
+	pub fn split_at_distance(&self, distance: f64) -> (Subpath<ManipulatorGroupId>, Option<Subpath<ManipulatorGroupId>>) {
+		if self.lone_anchor().is_some() {
+			return (self.subpath.clone(), None);
+		}
+		self.subpath.split(self.t_value_at_distance(distance))
+	}
+
+	/// Returns the [ArcLengthFrame] at the given distance along the subpath.
+	/// A subpath without segments gives a frame at its lone anchor with zero tangent and normal vectors.
+	pub fn frame_at_distance(&self, distance: f64) -> ArcLengthFrame {
+		if let Some(anchor) = self.lone_anchor() {
+			return ArcLengthFrame {
+				distance,
+				position: anchor,
+				tangent: DVec2::ZERO,
+				normal: DVec2::ZERO,
+			};
+		}
+
+		let t = self.t_value_at_distance(distance);
+		let tangent = self.subpath.tangent(t);
+		ArcLengthFrame {
+			distance,
+			position: self.subpath.evaluate(t),
+			tangent,
+			normal: tangent.perp(),
+		}
+	}
+
+	/// Returns an iterator over `count` frames spaced at equal distances along the subpath.
+	/// The frames of an open subpath include both of its ends, while those of a closed subpath start at its first anchor and stop one spacing short of returning to it.
+	pub fn evenly_spaced_frames(&self, count: usize) -> impl Iterator<Item = ArcLengthFrame> + '_ {
+		let intervals = if self.subpath.closed { count } else { count.saturating_sub(1) };
+		let spacing = if intervals == 0 { 0. } else { self.length() / intervals as f64 };
+		(0..count).map(move |index| self.frame_at_distance(index as f64 * spacing))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
+	use crate::utils::f64_compare;
+
+	#[test]
+	fn straight_segments() {
+		let subpath = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::new(40., 20.));
+		let table = subpath.arc_length_table(None);
+		assert!(f64_compare(table.length(), 120., MAX_ABSOLUTE_DIFFERENCE));
+
+		assert!(table.evaluate_at_distance(0.).abs_diff_eq(DVec2::ZERO, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.evaluate_at_distance(10.).abs_diff_eq(DVec2::new(10., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.evaluate_at_distance(50.).abs_diff_eq(DVec2::new(40., 10.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.evaluate_at_distance(100.).abs_diff_eq(DVec2::new(0., 20.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.tangent_at_distance(50.).abs_diff_eq(DVec2::new(0., 1.), MAX_ABSOLUTE_DIFFERENCE));
+
+		// Distances wrap around a closed subpath
+		assert!(table.evaluate_at_distance(130.).abs_diff_eq(DVec2::new(10., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.evaluate_at_distance(-10.).abs_diff_eq(DVec2::new(0., 10.), MAX_ABSOLUTE_DIFFERENCE));
+
+		let open = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(30., 40.)], false);
+		let table = open.arc_length_table(None);
+		assert!(table.evaluate_at_distance(-5.).abs_diff_eq(DVec2::ZERO, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.evaluate_at_distance(25.).abs_diff_eq(DVec2::new(15., 20.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(table.evaluate_at_distance(60.).abs_diff_eq(DVec2::new(30., 40.), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn curves_are_measured_by_distance() {
+		let radius = 50.;
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-radius), DVec2::splat(radius));
+		let table = circle.arc_length_table(None);
+		assert!(f64_compare(table.length(), circle.length(None), MAX_ABSOLUTE_DIFFERENCE));
+
+		// Equal distances along the circle correspond to equal angles around it
+		let start = circle.evaluate(SubpathTValue::GlobalParametric(0.));
+		let start_angle = start.y.atan2(start.x);
+		for frame in table.evenly_spaced_frames(12) {
+			let expected_angle = start_angle + frame.distance / radius;
+			assert!(frame.position.abs_diff_eq(DVec2::from_angle(expected_angle) * radius, 0.1));
+			assert!(frame.tangent.abs_diff_eq(DVec2::from_angle(expected_angle).perp(), 1e-2));
+			assert_eq!(frame.normal, frame.tangent.perp());
+		}
+	}
+
+	#[test]
+	fn evenly_spaced_frames() {
+		let positions = |subpath: &Subpath<EmptyId>, count| subpath.arc_length_table(None).evenly_spaced_frames(count).map(|frame| frame.position).collect::<Vec<_>>();
+		let all_near = |positions: Vec<DVec2>, expected: &[DVec2]| positions.len() == expected.len() && positions.iter().zip(expected).all(|(a, b)| a.abs_diff_eq(*b, MAX_ABSOLUTE_DIFFERENCE));
+
+		let open = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 20.)], false);
+		assert!(all_near(positions(&open, 4), &[DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 10.), DVec2::new(10., 20.)]));
+		assert!(all_near(positions(&open, 1), &[DVec2::ZERO]));
+		assert!(positions(&open, 0).is_empty());
+
+		let closed = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(10.));
+		assert!(all_near(positions(&closed, 4), &[DVec2::ZERO, DVec2::new(10., 0.), DVec2::splat(10.), DVec2::new(0., 10.)]));
+	}
+
+	#[test]
+	fn single_anchor() {
+		let subpath = Subpath::<EmptyId>::from_anchors([DVec2::new(3., 4.)], false);
+		let table = subpath.arc_length_table(None);
+		assert_eq!(table.length(), 0.);
+		assert!(table.t_value_at_distance(5.) == SubpathTValue::Parametric { segment_index: 0, t: 0. });
+		assert_eq!(table.evaluate_at_distance(5.), DVec2::new(3., 4.));
+		assert_eq!(table.tangent_at_distance(5.), DVec2::ZERO);
+		assert_eq!(table.split_at_distance(5.), (subpath.clone(), None));
+
+		let frames = table.evenly_spaced_frames(3).collect::<Vec<_>>();
+		assert_eq!(frames.len(), 3);
+		assert!(frames.iter().all(|frame| frame.position == DVec2::new(3., 4.) && frame.tangent == DVec2::ZERO));
+	}
+
+	#[test]
+	fn split_at_distance() {
+		let mut subpath = Subpath::<EmptyId>::new_ellipse(DVec2::ZERO, DVec2::new(100., 60.));
+		subpath.closed = false;
+		let table = subpath.arc_length_table(None);
+
+		let (first, second) = table.split_at_distance(table.length() / 3.);
+		assert!(f64_compare(first.length(None), table.length() / 3., 1e-2));
+		assert!(f64_compare(second.unwrap().length(None), table.length() * 2. / 3., 1e-2));
+	}
+}
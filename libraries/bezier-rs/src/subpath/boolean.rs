@@ -0,0 +1,640 @@
+use super::*;
+use crate::consts::{BOOLEAN_INTERSECTION_ERROR, BOOLEAN_SAMPLE_OFFSET, BOOLEAN_TANGENT_TIE_BREAK_ANGLE, MAX_ABSOLUTE_DIFFERENCE};
+use crate::utils::{do_rectangles_overlap, BooleanOperation, FillRule, TValue};
+use crate::BezierHandles;
+
+use glam::DVec2;
+use std::f64::consts::TAU;
+
+/// Index of the first operand (`A`) of a boolean operation.
+const OPERAND_A: usize = 0;
+/// Index of the second operand (`B`) of a boolean operation.
+const OPERAND_B: usize = 1;
+
+/// Parametric `t`-values, paired with the position they should be pinned to, at which a segment is split.
+type SplitPoints = Vec<(f64, DVec2)>;
+
+/// A segment of one of the operands of a boolean operation, along with the points at which it must be split.
+struct InputSegment {
+	bezier: Bezier,
+	operand: usize,
+	splits: SplitPoints,
+}
+
+/// An edge of the planar graph formed by splitting the segments of both operands at all their intersections.
+struct Edge {
+	bezier: Bezier,
+	/// Index of the [InputSegment] this edge was split from.
+	segment: usize,
+	/// Range of `t`-values of the [InputSegment] covered by this edge.
+	t_range: [f64; 2],
+	/// Indices of the start and end vertices of the edge.
+	vertices: [usize; 2],
+	/// Winding contributed by this edge to each operand. Coincident edges are merged by adding their contributions.
+	winding: [i32; 2],
+}
+
+/// A directed edge of the planar graph which borders the result of the boolean operation on its left side.
+struct HalfEdge {
+	edge: usize,
+	forward: bool,
+	start: usize,
+	end: usize,
+	/// Angle at which the half-edge leaves its start vertex.
+	departure_angle: f64,
+	/// Angle at which the reversed half-edge leaves the end vertex.
+	return_angle: f64,
+}
+
+/// A piece of a traced boundary, used to join consecutive edges which originate from the same segment.
+struct BoundaryPiece {
+	bezier: Bezier,
+	segment: usize,
+	t_range: [f64; 2],
+}
+
+/// Returns the portion of `bezier` between `t1` and `t2`, reversed if `t1 > t2`.
+/// Unlike [Bezier::trim], this preserves the curve even when the `t`-values are very close to each other.
+fn sub_curve(bezier: &Bezier, t1: f64, t2: f64) -> Bezier {
+	let (low, high) = (t1.min(t2), t1.max(t2));
+	let ending_at_high = if high < 1. { bezier.split(TValue::Parametric(high))[0] } else { *bezier };
+	let result = if low > 0. { ending_at_high.split(TValue::Parametric(low / high))[1] } else { ending_at_high };
+	if t1 > t2 {
+		result.reverse()
+	} else {
+		result
+	}
+}
+
+/// Returns the parametric `t`-value of the point on `bezier` closest to `point`, refining the result of [Bezier::project] with a few Newton iterations.
+fn closest_t_value(bezier: &Bezier, point: DVec2) -> f64 {
+	let mut t = bezier.project(point, None);
+	for _ in 0..4 {
+		let derivative = bezier.non_normalized_tangent(t);
+		let length_squared = derivative.length_squared();
+		if length_squared < f64::EPSILON {
+			break;
+		}
+		let offset = bezier.evaluate(TValue::Parametric(t)) - point;
+		t = (t - offset.dot(derivative) / length_squared).clamp(0., 1.);
+	}
+	t
+}
+
+/// Returns the distance between `point` and the closest point on `bezier`.
+fn distance_to_curve(bezier: &Bezier, point: DVec2) -> f64 {
+	bezier.evaluate(TValue::Parametric(closest_t_value(bezier, point))).distance(point)
+}
+
+/// Returns whether the two curves trace over the same points, in either direction.
+fn are_curves_coincident(bezier1: &Bezier, bezier2: &Bezier) -> bool {
+	[0.25, 0.5, 0.75]
+		.into_iter()
+		.all(|t| distance_to_curve(bezier2, bezier1.evaluate(TValue::Parametric(t))) < MAX_ABSOLUTE_DIFFERENCE)
+}
+
+/// Returns the bounding box of the bezier, grown on every side by the vertex tolerance.
+fn expanded_bounding_box(bezier: &Bezier) -> [DVec2; 2] {
+	let [min, max] = bezier.bounding_box();
+	[min - DVec2::splat(MAX_ABSOLUTE_DIFFERENCE), max + DVec2::splat(MAX_ABSOLUTE_DIFFERENCE)]
+}
+
+/// Returns the endpoints of either bezier which lie on the other one, as `(t on bezier1, t on bezier2, position)` tuples.
+fn touching_points(bezier1: &Bezier, bezier2: &Bezier) -> Vec<(f64, f64, DVec2)> {
+	let mut touches: Vec<(f64, f64, DVec2)> = Vec::new();
+	let mut add_touch = |touch: (f64, f64, DVec2)| {
+		if !touches.iter().any(|existing| existing.2.abs_diff_eq(touch.2, MAX_ABSOLUTE_DIFFERENCE)) {
+			touches.push(touch);
+		}
+	};
+
+	for (t2, point) in [(0., bezier2.start), (1., bezier2.end)] {
+		let t1 = closest_t_value(bezier1, point);
+		if bezier1.evaluate(TValue::Parametric(t1)).distance(point) < MAX_ABSOLUTE_DIFFERENCE {
+			add_touch((t1, t2, point));
+		}
+	}
+	for (t1, point) in [(0., bezier1.start), (1., bezier1.end)] {
+		let t2 = closest_t_value(bezier2, point);
+		if bezier2.evaluate(TValue::Parametric(t2)).distance(point) < MAX_ABSOLUTE_DIFFERENCE {
+			add_touch((t1, t2, point));
+		}
+	}
+
+	touches
+}
+
+/// Returns the points at which each of the two beziers must be split, as lists of `(t, position)` pairs.
+/// When the curves partially overlap, they are only split at the ends of the overlap, so the overlapping parts become coincident edges.
+fn split_points_between(bezier1: &Bezier, bezier2: &Bezier) -> (SplitPoints, SplitPoints) {
+	let mut touches = touching_points(bezier1, bezier2);
+	touches.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+	let mut splits1: SplitPoints = touches.iter().map(|&(t1, _, point)| (t1, point)).collect();
+	let mut splits2: SplitPoints = touches.iter().map(|&(_, t2, point)| (t2, point)).collect();
+
+	// Overlapping curves have infinitely many intersections, so only the ends of the overlap are used to split them
+	let overlapping = touches
+		.windows(2)
+		.any(|pair| distance_to_curve(bezier2, bezier1.evaluate(TValue::Parametric((pair[0].0 + pair[1].0) / 2.))) < MAX_ABSOLUTE_DIFFERENCE);
+	if !overlapping {
+		for [t1, t2] in bezier1.intersections_between_subcurves(0. ..1., bezier2, 0. ..1., BOOLEAN_INTERSECTION_ERROR) {
+			let Some([t1, t2]) = refine_intersection(bezier1, bezier2, t1, t2) else { continue };
+			let point = bezier1.evaluate(TValue::Parametric(t1));
+			// Several candidates usually converge to the same intersection
+			if splits1.iter().any(|&(_, existing)| existing.abs_diff_eq(point, MAX_ABSOLUTE_DIFFERENCE)) {
+				continue;
+			}
+			splits1.push((t1, point));
+			splits2.push((t2, point));
+		}
+	}
+
+	(splits1, splits2)
+}
+
+/// Refines an approximate intersection between two curves, returning the `t`-values of the intersection on each curve if the curves meet there.
+/// Newton's method is used for curves that cross, with alternating projections onto each curve as a fallback for curves that touch tangentially.
+fn refine_intersection(bezier1: &Bezier, bezier2: &Bezier, t1: f64, t2: f64) -> Option<[f64; 2]> {
+	let distance = |[t1, t2]: [f64; 2]| bezier1.evaluate(TValue::Parametric(t1)).distance(bezier2.evaluate(TValue::Parametric(t2)));
+
+	let mut newton = [t1, t2];
+	for _ in 0..16 {
+		let difference = bezier1.evaluate(TValue::Parametric(newton[0])) - bezier2.evaluate(TValue::Parametric(newton[1]));
+		let derivative1 = bezier1.non_normalized_tangent(newton[0]);
+		let derivative2 = bezier2.non_normalized_tangent(newton[1]);
+		let determinant = derivative1.perp_dot(derivative2);
+		if determinant.abs() < f64::EPSILON {
+			break;
+		}
+		newton = [
+			(newton[0] - difference.perp_dot(derivative2) / determinant).clamp(0., 1.),
+			(newton[1] + derivative1.perp_dot(difference) / determinant).clamp(0., 1.),
+		];
+	}
+	if distance(newton) < MAX_ABSOLUTE_DIFFERENCE {
+		return Some(newton);
+	}
+
+	let mut projected = [t1, t2];
+	for _ in 0..8 {
+		projected[1] = closest_t_value(bezier2, bezier1.evaluate(TValue::Parametric(projected[0])));
+		projected[0] = closest_t_value(bezier1, bezier2.evaluate(TValue::Parametric(projected[1])));
+	}
+	(distance(projected) < MAX_ABSOLUTE_DIFFERENCE).then_some(projected)
+}
+
+/// Returns the angle at which the bezier leaves its start point.
+/// Curves leaving in the same direction are ordered by their curvature, so the one bending counterclockwise has a slightly larger angle.
+fn departure_angle(bezier: &Bezier) -> f64 {
+	let (control_points, second_derivative_factor) = match bezier.handles {
+		BezierHandles::Linear => (vec![bezier.end], 0.),
+		BezierHandles::Quadratic { handle } => (vec![handle, bezier.end], 2.),
+		BezierHandles::Cubic { handle_start, handle_end } => (vec![handle_start, handle_end, bezier.end], 6.),
+	};
+
+	// The first control point that differs from the start point gives the direction of the curve
+	let direction = control_points
+		.iter()
+		.map(|&point| point - bezier.start)
+		.find(|direction| direction.length_squared() > f64::EPSILON)
+		.unwrap_or(DVec2::X);
+	let angle = direction.y.atan2(direction.x);
+
+	let first_derivative = bezier.non_normalized_tangent(0.);
+	if control_points.len() < 2 || first_derivative.length_squared() <= f64::EPSILON {
+		return angle;
+	}
+	let second_derivative = second_derivative_factor * (bezier.start - 2. * control_points[0] + control_points[1]);
+	let curvature = first_derivative.perp_dot(second_derivative) / first_derivative.length().powi(3);
+
+	angle + curvature.atan() * BOOLEAN_TANGENT_TIE_BREAK_ANGLE
+}
+
+/// Returns a point slightly to the left and slightly to the right of the middle of the bezier.
+fn side_sample_points(bezier: &Bezier) -> (DVec2, DVec2) {
+	let t = if bezier.non_normalized_tangent(0.5).length_squared() > f64::EPSILON { 0.5 } else { 0.45 };
+	let middle = bezier.evaluate(TValue::Parametric(t));
+	let normal = bezier.tangent(TValue::Parametric(t)).perp() * BOOLEAN_SAMPLE_OFFSET;
+	(middle + normal, middle - normal)
+}
+
+/// Returns the index of the vertex at the given position, adding a new vertex if none is within the tolerance.
+fn vertex_index(vertices: &mut Vec<DVec2>, point: DVec2) -> usize {
+	match vertices.iter().position(|vertex| vertex.abs_diff_eq(point, MAX_ABSOLUTE_DIFFERENCE)) {
+		Some(index) => index,
+		None => {
+			vertices.push(point);
+			vertices.len() - 1
+		}
+	}
+}
+
+/// Functionality for combining the areas enclosed by sets of subpaths.
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Computes a boolean operation between the areas enclosed by two sets of subpaths, `subpaths_a` and `subpaths_b`, which are both filled using `fill_rule`.
+	/// Open subpaths are treated as if they were closed by a straight segment, as they are when filled.
+	///
+	/// Returns a list of closed subpaths tracing the boundary of the resulting area. Each subpath winds such that the filled area is on its left
+	/// (the side `perp()` of its tangent points to), so outer boundaries and holes wind in opposite directions and the result may be filled with either fill rule.
+	/// For [BooleanOperation::Division], each piece of `A` is traced separately, so pieces sharing an edge are returned as distinct subpaths.
+	///
+	/// Intersections are computed on the full set of segments, so self-intersecting operands, edges shared by both operands and curves touching at a tangent are all supported.
+	pub fn boolean_operation(
+		subpaths_a: &[Subpath<ManipulatorGroupId>],
+		subpaths_b: &[Subpath<ManipulatorGroupId>],
+		operation: BooleanOperation,
+		fill_rule: FillRule,
+	) -> Vec<Subpath<ManipulatorGroupId>> {
+		let mut segments = Vec::new();
+		Self::collect_boolean_segments(subpaths_a, OPERAND_A, &mut segments);
+		Self::collect_boolean_segments(subpaths_b, OPERAND_B, &mut segments);
+
+		// Find all the points at which segments intersect themselves or each other
+		for i in 0..segments.len() {
+			let bezier = segments[i].bezier;
+			let self_splits = bezier
+				.self_intersections(Some(BOOLEAN_INTERSECTION_ERROR))
+				.into_iter()
+				.filter_map(|[t1, t2]| refine_intersection(&bezier, &bezier, t1, t2))
+				.filter(|[t1, t2]| (t1 - t2).abs() > MAX_ABSOLUTE_DIFFERENCE)
+				.flat_map(|[t1, t2]| {
+					let point = bezier.evaluate(TValue::Parametric(t1));
+					[(t1, point), (t2, point)]
+				})
+				.collect::<Vec<_>>();
+			segments[i].splits.extend(self_splits);
+
+			for j in (i + 1)..segments.len() {
+				let other = segments[j].bezier;
+				if !do_rectangles_overlap(expanded_bounding_box(&bezier), expanded_bounding_box(&other)) {
+					continue;
+				}
+				let (splits1, splits2) = split_points_between(&bezier, &other);
+				segments[i].splits.extend(splits1);
+				segments[j].splits.extend(splits2);
+			}
+		}
+
+		let (vertices, edges) = Self::build_boolean_graph(&mut segments);
+
+		// Determine which edges lie on the boundary of the result, and on which side the result is
+		let is_inside = |point: DVec2| {
+			let winding = |operand: usize| {
+				edges
+					.iter()
+					.filter(|edge| edge.winding[operand] != 0)
+					.map(|edge| edge.winding[operand] * edge.bezier.winding(point))
+					.sum::<i32>()
+			};
+			(fill_rule.is_inside(winding(OPERAND_A)), fill_rule.is_inside(winding(OPERAND_B)))
+		};
+		let borders_result = |side: (bool, bool), other_side: (bool, bool)| match operation {
+			// The edges of `B` which cut through `A` also separate pieces of the result
+			BooleanOperation::Division => side.0 && side != other_side,
+			_ => operation.is_inside(side.0, side.1) && !operation.is_inside(other_side.0, other_side.1),
+		};
+
+		let mut half_edges = Vec::new();
+		for (index, edge) in edges.iter().enumerate() {
+			let (left, right) = side_sample_points(&edge.bezier);
+			let (left, right) = (is_inside(left), is_inside(right));
+
+			for (forward, side, other_side) in [(true, left, right), (false, right, left)] {
+				if !borders_result(side, other_side) {
+					continue;
+				}
+				let bezier = if forward { edge.bezier } else { edge.bezier.reverse() };
+				let [start, end] = if forward { edge.vertices } else { [edge.vertices[1], edge.vertices[0]] };
+				half_edges.push(HalfEdge {
+					edge: index,
+					forward,
+					start,
+					end,
+					departure_angle: departure_angle(&bezier),
+					return_angle: departure_angle(&bezier.reverse()),
+				});
+			}
+		}
+
+		Self::trace_boolean_result(&segments, &edges, &vertices, &half_edges)
+	}
+
+	/// Adds the segments of the given subpaths to the list of segments used by a boolean operation, closing any open subpath with a straight segment.
+	fn collect_boolean_segments(subpaths: &[Subpath<ManipulatorGroupId>], operand: usize, segments: &mut Vec<InputSegment>) {
+		for subpath in subpaths.iter().filter(|subpath| !subpath.is_empty()) {
+			let closing_segment = (!subpath.closed).then(|| Bezier::from_linear_dvec2(subpath[subpath.len() - 1].anchor, subpath[0].anchor));
+			segments.extend(
+				subpath
+					.iter()
+					.chain(closing_segment)
+					.filter(|bezier| !bezier.is_point())
+					.map(|bezier| InputSegment { bezier, operand, splits: Vec::new() }),
+			);
+		}
+	}
+
+	/// Splits the segments at their split points into the edges of a planar graph, merging the vertices and edges which coincide.
+	fn build_boolean_graph(segments: &mut [InputSegment]) -> (Vec<DVec2>, Vec<Edge>) {
+		let mut vertices = Vec::new();
+		let mut edges: Vec<Edge> = Vec::new();
+
+		for (segment_index, segment) in segments.iter_mut().enumerate() {
+			let bezier = segment.bezier;
+			segment
+				.splits
+				.retain(|&(_, point)| !point.abs_diff_eq(bezier.start, MAX_ABSOLUTE_DIFFERENCE) && !point.abs_diff_eq(bezier.end, MAX_ABSOLUTE_DIFFERENCE));
+			segment.splits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+			let mut previous = (0., bezier.start);
+			for &(t, point) in segment.splits.iter().chain(std::iter::once(&(1., bezier.end))) {
+				if t <= previous.0 {
+					continue;
+				}
+
+				let start = vertex_index(&mut vertices, previous.1);
+				let end = vertex_index(&mut vertices, point);
+				let mut piece = sub_curve(&bezier, previous.0, t);
+				piece.start = vertices[start];
+				piece.end = vertices[end];
+				let t_range = [previous.0, t];
+				previous = (t, point);
+
+				// Drop pieces that collapsed to a single point after their endpoints were merged
+				if start == end && piece.is_point() {
+					continue;
+				}
+
+				let mut winding = [0; 2];
+				winding[segment.operand] = 1;
+				let new_edge = Edge {
+					bezier: piece,
+					segment: segment_index,
+					t_range,
+					vertices: [start, end],
+					winding,
+				};
+
+				// Merge the new edge into an existing one if they trace the same curve
+				let coincident = edges.iter_mut().find_map(|edge| {
+					let same_direction = if start == end {
+						edge.vertices == [start, end] && departure_angle(&edge.bezier).sin_cos().0 * departure_angle(&piece).sin_cos().0 >= 0.
+					} else {
+						edge.vertices == [start, end]
+					};
+					let matching_vertices = same_direction || edge.vertices == [end, start];
+					(matching_vertices && are_curves_coincident(&piece, &edge.bezier)).then_some((edge, same_direction))
+				});
+				match coincident {
+					Some((edge, same_direction)) => {
+						let sign = if same_direction { 1 } else { -1 };
+						edge.winding[OPERAND_A] += sign * new_edge.winding[OPERAND_A];
+						edge.winding[OPERAND_B] += sign * new_edge.winding[OPERAND_B];
+					}
+					None => edges.push(new_edge),
+				}
+			}
+		}
+
+		(vertices, edges)
+	}
+
+	/// Follows the half-edges bordering the result into closed loops, turning as sharply clockwise as possible at each vertex so each loop encloses a single face.
+	fn trace_boolean_result(segments: &[InputSegment], edges: &[Edge], vertices: &[DVec2], half_edges: &[HalfEdge]) -> Vec<Subpath<ManipulatorGroupId>> {
+		let mut outgoing = vec![Vec::new(); vertices.len()];
+		for (index, half_edge) in half_edges.iter().enumerate() {
+			outgoing[half_edge.start].push(index);
+		}
+
+		let mut visited = vec![false; half_edges.len()];
+		let mut result = Vec::new();
+		for first in 0..half_edges.len() {
+			if visited[first] {
+				continue;
+			}
+
+			let mut loop_half_edges = Vec::new();
+			let mut current = first;
+			let closed = loop {
+				visited[current] = true;
+				loop_half_edges.push(current);
+				let incoming = &half_edges[current];
+
+				// The next half-edge is the first one found when rotating clockwise from the direction we arrived from
+				let next = outgoing[incoming.end].iter().copied().min_by(|&a, &b| {
+					let turn = |candidate: usize| {
+						let candidate = &half_edges[candidate];
+						if candidate.edge == incoming.edge && candidate.forward != incoming.forward {
+							TAU
+						} else {
+							(incoming.return_angle - candidate.departure_angle).rem_euclid(TAU)
+						}
+					};
+					turn(a).total_cmp(&turn(b))
+				});
+				match next {
+					Some(next) if next == first => break true,
+					Some(next) if !visited[next] => current = next,
+					_ => break false,
+				}
+			};
+			if !closed {
+				continue;
+			}
+
+			let pieces = loop_half_edges
+				.iter()
+				.map(|&index| {
+					let half_edge = &half_edges[index];
+					let edge = &edges[half_edge.edge];
+					if half_edge.forward {
+						BoundaryPiece {
+							bezier: edge.bezier,
+							segment: edge.segment,
+							t_range: edge.t_range,
+						}
+					} else {
+						BoundaryPiece {
+							bezier: edge.bezier.reverse(),
+							segment: edge.segment,
+							t_range: [edge.t_range[1], edge.t_range[0]],
+						}
+					}
+				})
+				.collect::<Vec<_>>();
+			result.push(Self::subpath_from_boundary_pieces(segments, pieces));
+		}
+
+		result
+	}
+
+	/// Builds a closed subpath from a loop of boundary pieces, rejoining consecutive pieces which were split from the same segment.
+	fn subpath_from_boundary_pieces(segments: &[InputSegment], pieces: Vec<BoundaryPiece>) -> Subpath<ManipulatorGroupId> {
+		let mut joined: Vec<BoundaryPiece> = Vec::with_capacity(pieces.len());
+		for piece in pieces {
+			if let Some(previous) = joined.last_mut() {
+				let continues_previous = previous.segment == piece.segment
+					&& previous.t_range[1] == piece.t_range[0]
+					&& (previous.t_range[1] - previous.t_range[0]).signum() == (piece.t_range[1] - piece.t_range[0]).signum();
+				if continues_previous {
+					let start = previous.bezier.start;
+					previous.t_range[1] = piece.t_range[1];
+					previous.bezier = sub_curve(&segments[piece.segment].bezier, previous.t_range[0], previous.t_range[1]);
+					previous.bezier.start = start;
+					previous.bezier.end = piece.bezier.end;
+					continue;
+				}
+			}
+			joined.push(piece);
+		}
+
+		let mut beziers = joined.into_iter().map(|piece| piece.bezier).collect::<Vec<_>>();
+		// A closed subpath needs at least two segments, so a loop made of a single curve is split in half
+		if beziers.len() == 1 {
+			beziers = beziers[0].split(TValue::Parametric(0.5)).to_vec();
+		}
+		Subpath::from_beziers(&beziers, true)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::f64_compare;
+
+	type Shape = Vec<Subpath<EmptyId>>;
+
+	fn square(corner: DVec2, size: f64) -> Shape {
+		vec![Subpath::new_rect(corner, corner + DVec2::splat(size))]
+	}
+
+	/// Signed area of shapes consisting only of linear segments.
+	fn polygon_area(shape: &[Subpath<EmptyId>]) -> f64 {
+		shape
+			.iter()
+			.map(|subpath| {
+				let anchors = subpath.anchors();
+				(0..anchors.len()).map(|i| anchors[i].perp_dot(anchors[(i + 1) % anchors.len()])).sum::<f64>() / 2.
+			})
+			.sum()
+	}
+
+	fn contains(shape: &[Subpath<EmptyId>], point: DVec2) -> bool {
+		shape.iter().flat_map(|subpath| subpath.iter()).map(|bezier| bezier.winding(point)).sum::<i32>() != 0
+	}
+
+	#[test]
+	fn overlapping_squares() {
+		let a = square(DVec2::ZERO, 10.);
+		let b = square(DVec2::splat(5.), 10.);
+		let area = |operation| polygon_area(&Subpath::boolean_operation(&a, &b, operation, FillRule::NonZero));
+
+		assert!(f64_compare(area(BooleanOperation::Union), 175., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(area(BooleanOperation::Difference), 75., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(area(BooleanOperation::Intersection), 25., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(area(BooleanOperation::Exclusion), 150., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(area(BooleanOperation::Division), 100., MAX_ABSOLUTE_DIFFERENCE));
+
+		assert_eq!(Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero).len(), 1);
+		assert_eq!(Subpath::boolean_operation(&a, &b, BooleanOperation::Exclusion, FillRule::NonZero).len(), 2);
+		assert_eq!(Subpath::boolean_operation(&a, &b, BooleanOperation::Division, FillRule::NonZero).len(), 2);
+	}
+
+	#[test]
+	fn squares_sharing_an_edge() {
+		let a = square(DVec2::ZERO, 10.);
+		let b = square(DVec2::new(10., 0.), 10.);
+
+		let union = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+		assert_eq!(union.len(), 1);
+		assert!(f64_compare(polygon_area(&union), 200., MAX_ABSOLUTE_DIFFERENCE));
+
+		assert!(Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero).is_empty());
+		assert!(f64_compare(
+			polygon_area(&Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero)),
+			100.,
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+	}
+
+	#[test]
+	fn identical_operands() {
+		let a = square(DVec2::ZERO, 10.);
+		let b = a.clone();
+
+		assert!(f64_compare(
+			polygon_area(&Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero)),
+			100.,
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+		assert!(f64_compare(
+			polygon_area(&Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero)),
+			100.,
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+		assert!(Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero).is_empty());
+	}
+
+	#[test]
+	fn hole_inside_shape() {
+		let a = square(DVec2::ZERO, 10.);
+		let b = square(DVec2::splat(3.), 4.);
+
+		let difference = Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero);
+		assert_eq!(difference.len(), 2);
+		assert!(f64_compare(polygon_area(&difference), 84., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(contains(&difference, DVec2::splat(1.)));
+		assert!(!contains(&difference, DVec2::splat(5.)));
+	}
+
+	#[test]
+	fn self_intersecting_star_fill_rules() {
+		let star: Shape = vec![Subpath::from_anchors(
+			(0..5).map(|i| {
+				let angle = (i * 2) as f64 * TAU / 5.;
+				DVec2::new(angle.cos(), angle.sin()) * 10.
+			}),
+			true,
+		)];
+
+		let nonzero = Subpath::boolean_operation(&star, &[], BooleanOperation::Union, FillRule::NonZero);
+		let even_odd = Subpath::boolean_operation(&star, &[], BooleanOperation::Union, FillRule::EvenOdd);
+
+		assert_eq!(nonzero.len(), 1);
+		assert_eq!(nonzero[0].len(), 10);
+		assert_eq!(even_odd.len(), 5);
+		assert!(contains(&nonzero, DVec2::ZERO));
+		assert!(!contains(&even_odd, DVec2::ZERO));
+		assert!(polygon_area(&nonzero) > polygon_area(&even_odd));
+	}
+
+	#[test]
+	fn tangent_circles() {
+		let a = vec![Subpath::<EmptyId>::new_ellipse(DVec2::ZERO, DVec2::splat(10.))];
+		let b = vec![Subpath::<EmptyId>::new_ellipse(DVec2::new(10., 0.), DVec2::new(20., 10.))];
+
+		let union = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+		assert_eq!(union.len(), 2);
+		assert!(contains(&union, DVec2::splat(5.)));
+		assert!(contains(&union, DVec2::new(15., 5.)));
+		assert!(Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero).is_empty());
+	}
+
+	#[test]
+	fn overlapping_circles() {
+		let a = vec![Subpath::<EmptyId>::new_ellipse(DVec2::ZERO, DVec2::splat(10.))];
+		let b = vec![Subpath::<EmptyId>::new_ellipse(DVec2::new(5., 0.), DVec2::new(15., 10.))];
+
+		let difference = Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero);
+		assert_eq!(difference.len(), 1);
+		assert!(difference[0].closed());
+		assert!(contains(&difference, DVec2::new(1., 5.)));
+		assert!(!contains(&difference, DVec2::new(7.5, 5.)));
+		assert!(!contains(&difference, DVec2::new(12., 5.)));
+
+		let intersection = Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero);
+		assert_eq!(intersection.len(), 1);
+		assert!(contains(&intersection, DVec2::new(7.5, 5.)));
+		assert!(!contains(&intersection, DVec2::new(1., 5.)));
+	}
+}
@@ -1,7 +1,9 @@
 use super::*;
 use crate::consts::*;
+use crate::utils;
 
-use glam::DVec2;
+use glam::{DAffine2, DVec2};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 use std::fmt::Write;
 
 /// Functionality relating to core `Subpath` operations, such as constructors and `iter`.
@@ -233,6 +235,96 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		Self::new(manipulator_groups, true)
 	}
 
+	/// Constructs an open subpath along an arc of the ellipse around `center` with the given `radii`, rotated by `rotation` radians, using as few cubic segments as possible.
+	/// The arc starts at `start_angle` and turns by `sweep_angle`, both in radians and measured around the ellipse before it is rotated, so a negative sweep travels in the direction of decreasing angles.
+	/// - `max_error` - The maximum distance allowed between the segments and the ellipse. By default, each segment spans at most a quarter turn, as in [Subpath::new_ellipse].
+	pub fn new_elliptical_arc(center: DVec2, radii: DVec2, rotation: f64, start_angle: f64, sweep_angle: f64, max_error: Option<f64>) -> Self {
+		// The segments approximate an arc of the unit circle, which is then stretched and rotated onto the ellipse
+		let to_ellipse = DAffine2::from_translation(center) * DAffine2::from_angle(rotation) * DAffine2::from_scale(radii);
+		let max_radius = radii.x.abs().max(radii.y.abs());
+
+		let max_segment_angle = match max_error {
+			// Find the largest angle a segment can span within the error, which grows steadily with the angle
+			Some(max_error) if max_radius * utils::unit_arc_approximation_error(PI) > max_error => {
+				let (mut low, mut high) = (MIN_ARC_SEGMENT_ANGLE, PI);
+				for _ in 0..50 {
+					let middle = (low + high) / 2.;
+					if max_radius * utils::unit_arc_approximation_error(middle) > max_error {
+						high = middle;
+					} else {
+						low = middle;
+					}
+				}
+				low
+			}
+			Some(_) => PI,
+			None => FRAC_PI_2,
+		};
+		let count = (sweep_angle.abs() / max_segment_angle - STRICT_MAX_ABSOLUTE_DIFFERENCE).ceil().max(1.) as usize;
+		let step = sweep_angle / count as f64;
+		let handle_length = 4. / 3. * (step / 4.).tan();
+
+		let manipulator_groups = (0..=count)
+			.map(|index| {
+				let point = DVec2::from_angle(start_angle + step * index as f64);
+				let in_handle = (index > 0).then(|| to_ellipse.transform_point2(point - point.perp() * handle_length));
+				let out_handle = (index < count).then(|| to_ellipse.transform_point2(point + point.perp() * handle_length));
+				ManipulatorGroup::new(to_ellipse.transform_point2(point), in_handle, out_handle)
+			})
+			.collect();
+		Self::new(manipulator_groups, false)
+	}
+
+	/// Constructs an open subpath along an arc of the circle around `center` with the given `radius`. See [Subpath::new_elliptical_arc].
+	pub fn new_circular_arc(center: DVec2, radius: f64, start_angle: f64, sweep_angle: f64, max_error: Option<f64>) -> Self {
+		Self::new_elliptical_arc(center, DVec2::splat(radius), 0., start_angle, sweep_angle, max_error)
+	}
+
+	/// Constructs an open subpath along an elliptical arc from `start` to `end`, given in the endpoint parameterization of the SVG arc command.
+	/// Following SVG, radii too small to span the endpoints are scaled up until they just do, and an arc with a zero radius is a straight line.
+	/// An arc which ends where it starts consists of a single anchor.
+	/// - `radii` - The radii of the ellipse, whose signs are ignored.
+	/// - `rotation` - The angle, in radians, by which the x-axis of the ellipse is rotated.
+	/// - `large_arc` - Whether the arc travels the longer way around the ellipse.
+	/// - `sweep` - Whether the arc travels in the direction of increasing angles.
+	/// - `max_error` - See [Subpath::new_elliptical_arc].
+	pub fn new_svg_arc(start: DVec2, end: DVec2, radii: DVec2, rotation: f64, large_arc: bool, sweep: bool, max_error: Option<f64>) -> Self {
+		if start == end {
+			return Self::new(vec![ManipulatorGroup::new(start, None, None)], false);
+		}
+		let radii = radii.abs();
+		if radii.x == 0. || radii.y == 0. {
+			return Self::new(vec![ManipulatorGroup::new(start, None, None), ManipulatorGroup::new(end, None, None)], false);
+		}
+
+		// Convert from the endpoint parameterization to the center parameterization, as described in the SVG specification
+		let half_chord = DAffine2::from_angle(-rotation).transform_vector2((start - end) / 2.);
+		let scale = half_chord * half_chord / (radii * radii);
+		let radii = radii * (scale.x + scale.y).sqrt().max(1.);
+		let (radii_squared, half_chord_squared) = (radii * radii, half_chord * half_chord);
+		let numerator = radii_squared.x * radii_squared.y - radii_squared.x * half_chord_squared.y - radii_squared.y * half_chord_squared.x;
+		let denominator = radii_squared.x * half_chord_squared.y + radii_squared.y * half_chord_squared.x;
+		let sign = if large_arc == sweep { -1. } else { 1. };
+		let center_offset = sign * (numerator / denominator).max(0.).sqrt() * DVec2::new(radii.x * half_chord.y / radii.y, -radii.y * half_chord.x / radii.x);
+		let center = DAffine2::from_angle(rotation).transform_vector2(center_offset) + (start + end) / 2.;
+
+		let start_vector = (half_chord - center_offset) / radii;
+		let end_vector = (-half_chord - center_offset) / radii;
+		let start_angle = start_vector.y.atan2(start_vector.x);
+		let mut sweep_angle = start_vector.angle_between(end_vector);
+		if sweep && sweep_angle < 0. {
+			sweep_angle += TAU;
+		} else if !sweep && sweep_angle > 0. {
+			sweep_angle -= TAU;
+		}
+
+		let mut arc = Self::new_elliptical_arc(center, radii, rotation, start_angle, sweep_angle, max_error);
+		// Place the ends exactly, rather than where rounding puts them
+		arc.manipulator_groups[0].anchor = start;
+		arc.manipulator_groups.last_mut().unwrap().anchor = end;
+		arc
+	}
+
 	/// Constructs a regular polygon (ngon). Based on `sides` and `radius`, which is the distance from the center to any vertex.
 	pub fn new_regular_polygon(center: DVec2, sides: u64, radius: f64) -> Self {
 		let anchor_positions = (0..sides).map(|i| {
@@ -327,3 +419,92 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		subpath
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::TValue;
+
+	/// Returns the largest distance between the segments of the subpath and the ellipse with the given center, radii and rotation.
+	fn ellipse_error(subpath: &Subpath<EmptyId>, center: DVec2, radii: DVec2, rotation: f64) -> f64 {
+		let to_unit_circle = (DAffine2::from_translation(center) * DAffine2::from_angle(rotation) * DAffine2::from_scale(radii)).inverse();
+		subpath
+			.iter()
+			.flat_map(|bezier| (0..=100).map(move |index| bezier.evaluate(TValue::Parametric(index as f64 / 100.))))
+			// Measured along the radius, which is close enough to the distance for an ellipse which is nearly a circle, or a point which is nearly on it
+			.map(|point| (to_unit_circle.transform_point2(point).length() - 1.).abs() * radii.min_element())
+			.fold(0., f64::max)
+	}
+
+	#[test]
+	fn circular_arcs() {
+		let center = DVec2::new(10., -20.);
+
+		// By default, each segment spans at most a quarter turn
+		let circle = Subpath::<EmptyId>::new_circular_arc(center, 100., 0., TAU, None);
+		assert_eq!(circle.len_segments(), 4);
+		assert!(circle.manipulator_groups[0].anchor.abs_diff_eq(circle.manipulator_groups[4].anchor, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(ellipse_error(&circle, center, DVec2::splat(100.), 0.) < 0.03);
+		assert_eq!(Subpath::<EmptyId>::new_circular_arc(center, 100., 0., FRAC_PI_2, None).len_segments(), 1);
+
+		// A tighter error needs more segments, while a looser one allows up to half a turn each
+		let precise = Subpath::<EmptyId>::new_circular_arc(center, 100., 0., TAU, Some(0.01));
+		assert_eq!(precise.len_segments(), 5);
+		assert!(ellipse_error(&precise, center, DVec2::splat(100.), 0.) <= 0.01);
+		assert_eq!(Subpath::<EmptyId>::new_circular_arc(center, 100., 0., TAU, Some(10.)).len_segments(), 2);
+		assert_eq!(Subpath::<EmptyId>::new_circular_arc(center, 100., 0., 0.1, Some(1e-6)).len_segments(), 1);
+
+		// A negative sweep travels in the direction of decreasing angles
+		let clockwise = Subpath::<EmptyId>::new_circular_arc(center, 10., FRAC_PI_2, -PI, None);
+		assert_eq!(clockwise.len_segments(), 2);
+		let anchors = clockwise.anchors();
+		assert!(anchors[0].abs_diff_eq(center + DVec2::new(0., 10.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors[1].abs_diff_eq(center + DVec2::new(10., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors[2].abs_diff_eq(center + DVec2::new(0., -10.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(!clockwise.closed());
+	}
+
+	#[test]
+	fn elliptical_arcs() {
+		let (center, radii, rotation) = (DVec2::new(5., 5.), DVec2::new(40., 20.), PI / 6.);
+		let arc = Subpath::<EmptyId>::new_elliptical_arc(center, radii, rotation, -FRAC_PI_2, 1.5 * PI, Some(1e-3));
+		assert!(ellipse_error(&arc, center, radii, rotation) <= 1e-3);
+
+		let to_ellipse = DAffine2::from_translation(center) * DAffine2::from_angle(rotation) * DAffine2::from_scale(radii);
+		let anchors = arc.anchors();
+		assert!(anchors[0].abs_diff_eq(to_ellipse.transform_point2(DVec2::new(0., -1.)), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors.last().unwrap().abs_diff_eq(to_ellipse.transform_point2(DVec2::new(-1., 0.)), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn svg_arcs() {
+		let (start, end) = (DVec2::new(0., 0.), DVec2::new(100., 0.));
+
+		// The four arcs between the endpoints are chosen by the flags
+		for (large_arc, sweep, middle) in [
+			(false, true, DVec2::new(50., -50.)),
+			(false, false, DVec2::new(50., 50.)),
+			(true, true, DVec2::new(50., -100. - 50. * 3_f64.sqrt())),
+			(true, false, DVec2::new(50., 100. + 50. * 3_f64.sqrt())),
+		] {
+			let radius = if large_arc { 100. } else { 50. };
+			let arc = Subpath::<EmptyId>::new_svg_arc(start, end, DVec2::splat(radius), 0., large_arc, sweep, Some(1e-3));
+			assert_eq!(arc.manipulator_groups[0].anchor, start);
+			assert_eq!(arc.manipulator_groups.last().unwrap().anchor, end);
+
+			let arc_length_table = arc.arc_length_table(None);
+			let halfway = arc_length_table.evaluate_at_distance(arc_length_table.length() / 2.);
+			assert!(halfway.abs_diff_eq(middle, 1e-2), "{halfway} is not {middle}");
+		}
+
+		// Radii too small to span the endpoints are scaled up
+		let scaled = Subpath::<EmptyId>::new_svg_arc(start, end, DVec2::splat(1.), 0., false, true, None);
+		assert!(ellipse_error(&scaled, DVec2::new(50., 0.), DVec2::splat(50.), 0.) < 0.02);
+
+		// An arc with a zero radius is a straight line, and one to its start has no segments
+		let line = Subpath::<EmptyId>::new_svg_arc(start, end, DVec2::new(0., 10.), 0., false, true, None);
+		assert_eq!(line.len_segments(), 1);
+		assert!(line.manipulator_groups.iter().all(|group| group.in_handle.is_none() && group.out_handle.is_none()));
+		assert_eq!(Subpath::<EmptyId>::new_svg_arc(start, start, DVec2::splat(10.), 0., false, true, None).len(), 1);
+	}
+}
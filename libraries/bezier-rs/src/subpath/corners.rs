@@ -0,0 +1,217 @@
+use super::fitting::start_direction;
+use super::*;
+use crate::consts::{CORNER_MIN_ANGLE, MAX_ABSOLUTE_DIFFERENCE, STRICT_MAX_ABSOLUTE_DIFFERENCE};
+use crate::utils::{Corner, TValue};
+
+use glam::DVec2;
+use std::f64::consts::PI;
+
+/// Returns the direction in which the bezier arrives at its end, or `None` if it has no length.
+fn end_direction(bezier: &Bezier) -> Option<DVec2> {
+	start_direction(&bezier.reverse()).map(|direction| -direction)
+}
+
+/// Returns the parametric `t`-values where the bezier is cut after cutting back its start and end by the given distances along it.
+fn cut_parameters(bezier: &Bezier, length: f64, start_distance: f64, end_distance: f64) -> [f64; 2] {
+	[
+		if start_distance > 0. {
+			bezier.euclidean_to_parametric(start_distance / length, STRICT_MAX_ABSOLUTE_DIFFERENCE)
+		} else {
+			0.
+		},
+		if end_distance > 0. {
+			bezier.euclidean_to_parametric(1. - end_distance / length, STRICT_MAX_ABSOLUTE_DIFFERENCE)
+		} else {
+			1.
+		},
+	]
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns a copy of the subpath with its sharp corners replaced by circular arcs of the given `radius`, or cut off by straight lines, depending on the `corner` style.
+	/// A corner is an anchor where the segments on either side meet at an angle, which excludes the ends of an open subpath and anchors where the subpath doubles back on itself.
+	/// Each corner is cut back by the same distance along the segments on either side, which is limited to half the length of each segment so that neighbouring corners never overlap, giving a smaller arc where the segments are short.
+	/// Corners which meet in the middle of a segment are joined directly, without the segment of zero length left between them.
+	/// - `selection` - The ids of the manipulator groups whose corners are replaced. By default, every corner is replaced.
+	pub fn round_corners(&self, radius: f64, corner: Corner, selection: Option<&[ManipulatorGroupId]>) -> Subpath<ManipulatorGroupId> {
+		let segments = self.iter().collect::<Vec<_>>();
+		if segments.is_empty() || radius <= 0. {
+			return self.clone();
+		}
+		let lengths = segments.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>();
+		let incoming = |index: usize| if index > 0 { Some(index - 1) } else { self.closed.then_some(segments.len() - 1) };
+		let outgoing = |index: usize| (index < segments.len()).then_some(index);
+
+		// Find how far each corner is cut back along its segments, which is zero for the anchors which are left as they are
+		let cut_distances = self
+			.manipulator_groups
+			.iter()
+			.enumerate()
+			.map(|(index, group)| {
+				let (Some(incoming), Some(outgoing)) = (incoming(index), outgoing(index)) else { return 0. };
+				if !selection.map_or(true, |ids| ids.contains(&group.id)) {
+					return 0.;
+				}
+				let (Some(in_direction), Some(out_direction)) = (end_direction(&segments[incoming]), start_direction(&segments[outgoing])) else {
+					return 0.;
+				};
+				let turn = in_direction.angle_between(out_direction).abs();
+				if !(CORNER_MIN_ANGLE..=PI - CORNER_MIN_ANGLE).contains(&turn) {
+					return 0.;
+				}
+				(radius * (turn / 2.).tan()).min(lengths[incoming] / 2.).min(lengths[outgoing] / 2.)
+			})
+			.collect::<Vec<_>>();
+		let cuts = segments
+			.iter()
+			.enumerate()
+			.map(|(index, bezier)| cut_parameters(bezier, lengths[index], cut_distances[index], cut_distances[(index + 1) % self.len()]))
+			.collect::<Vec<_>>();
+		let cut_segments = segments
+			.iter()
+			.zip(&cuts)
+			.map(|(bezier, &[t1, t2])| {
+				if t1 == 0. && t2 == 1. {
+					*bezier
+				} else {
+					bezier.trim(TValue::Parametric(t1), TValue::Parametric(t2))
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let mut manipulator_groups: Vec<ManipulatorGroup<ManipulatorGroupId>> = Vec::with_capacity(self.len() * 2);
+		let mut push_group = |group: ManipulatorGroup<ManipulatorGroupId>| match manipulator_groups.last_mut() {
+			// Merge the end of one corner with the start of the next where the segment between them has been cut away entirely
+			Some(previous) if previous.anchor.abs_diff_eq(group.anchor, MAX_ABSOLUTE_DIFFERENCE) => previous.out_handle = group.out_handle,
+			_ => manipulator_groups.push(group),
+		};
+		for (index, group) in self.manipulator_groups.iter().enumerate() {
+			let in_handle = incoming(index).map_or(group.in_handle, |incoming| cut_segments[incoming].handle_end());
+			let out_handle = outgoing(index).map_or(group.out_handle, |outgoing| cut_segments[outgoing].handle_start());
+			let (Some(incoming), Some(outgoing)) = (incoming(index), outgoing(index)) else {
+				push_group(ManipulatorGroup {
+					in_handle,
+					out_handle,
+					..group.clone()
+				});
+				continue;
+			};
+			let (corner_start, corner_end) = (cut_segments[incoming].end(), cut_segments[outgoing].start());
+			if cut_distances[index] == 0. || corner_start.abs_diff_eq(corner_end, MAX_ABSOLUTE_DIFFERENCE) {
+				push_group(ManipulatorGroup {
+					in_handle,
+					out_handle,
+					..group.clone()
+				});
+				continue;
+			}
+
+			// The arc leaves and arrives in the directions of the segments where they were cut, like a circle touching both of them
+			let in_direction = segments[incoming].tangent(TValue::Parametric(cuts[incoming][1])).try_normalize();
+			let out_direction = segments[outgoing].tangent(TValue::Parametric(cuts[outgoing][0])).try_normalize();
+			let directions = in_direction.zip(out_direction);
+			let (arc_out_handle, arc_in_handle) = match (corner, directions) {
+				(Corner::Round, Some((in_direction, out_direction))) if in_direction.angle_between(out_direction).abs() >= CORNER_MIN_ANGLE => {
+					let angle = in_direction.angle_between(out_direction).abs();
+					let arc_radius = corner_start.distance(corner_end) / (2. * (angle / 2.).sin());
+					let handle_length = 4. / 3. * (angle / 4.).tan() * arc_radius;
+					(Some(corner_start + in_direction * handle_length), Some(corner_end - out_direction * handle_length))
+				}
+				_ => (None, None),
+			};
+			push_group(ManipulatorGroup {
+				anchor: corner_start,
+				in_handle,
+				out_handle: arc_out_handle,
+				id: group.id.clone(),
+			});
+			push_group(ManipulatorGroup {
+				anchor: corner_end,
+				in_handle: arc_in_handle,
+				out_handle,
+				id: ManipulatorGroupId::new(),
+			});
+		}
+
+		// A closed subpath whose first and last anchors have met is joined in the same way
+		if self.closed
+			&& manipulator_groups.len() > 2
+			&& manipulator_groups[0]
+				.anchor
+				.abs_diff_eq(manipulator_groups[manipulator_groups.len() - 1].anchor, MAX_ABSOLUTE_DIFFERENCE)
+		{
+			let last = manipulator_groups.pop().unwrap();
+			manipulator_groups[0].in_handle = last.in_handle;
+		}
+		Subpath::new(manipulator_groups, self.closed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::f64_compare;
+	use crate::EmptyId;
+
+	#[test]
+	fn round_square() {
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let rounded = square.round_corners(10., Corner::Round, None);
+		assert_eq!(rounded.len(), 8);
+		assert!(rounded.closed());
+		assert!(f64_compare(rounded.area(), 10000. - (4. - PI) * 100., 0.5));
+
+		// Each arc stays on the circle which touches both sides of its corner
+		let arc = rounded.iter().find(|bezier| bezier.start().abs_diff_eq(DVec2::new(90., 0.), MAX_ABSOLUTE_DIFFERENCE)).unwrap();
+		assert!(arc.end().abs_diff_eq(DVec2::new(100., 10.), MAX_ABSOLUTE_DIFFERENCE));
+		for index in 0..=10 {
+			let point = arc.evaluate(TValue::Parametric(index as f64 / 10.));
+			assert!(f64_compare(point.distance(DVec2::new(90., 10.)), 10., 0.01));
+		}
+
+		let chamfered = square.round_corners(10., Corner::Chamfer, None);
+		assert_eq!(chamfered.len(), 8);
+		assert!(f64_compare(chamfered.area(), 10000. - 4. * 50., 0.5));
+	}
+
+	#[test]
+	fn corners_are_clamped() {
+		// Corners which are too large for the sides meet in the middle of each side, making a circle
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let circle = square.round_corners(80., Corner::Round, None);
+		assert_eq!(circle.len(), 4);
+		assert!(f64_compare(circle.area(), PI * 2500., 5.));
+		for bezier in circle.iter() {
+			assert!(f64_compare(bezier.evaluate(TValue::Parametric(0.5)).distance(DVec2::splat(50.)), 50., 0.1));
+		}
+
+		// A short side limits the corners at both of its ends
+		let rectangle = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::new(100., 10.));
+		let rounded = rectangle.round_corners(20., Corner::Round, None);
+		assert_eq!(rounded.len(), 6);
+		assert!(rounded.anchors().iter().any(|anchor| anchor.abs_diff_eq(DVec2::new(5., 0.), MAX_ABSOLUTE_DIFFERENCE)));
+	}
+
+	#[test]
+	fn open_subpaths_and_selection() {
+		let polyline = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 100.), DVec2::new(150., 150.)], false);
+
+		// The ends of an open subpath are left as they are, as are anchors where the segments continue in the same direction
+		let rounded = polyline.round_corners(10., Corner::Round, None);
+		let anchors = rounded.anchors();
+		assert_eq!(anchors.len(), 6);
+		assert_eq!(anchors[0], DVec2::ZERO);
+		assert!(anchors[1].abs_diff_eq(DVec2::new(90., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors[2].abs_diff_eq(DVec2::new(100., 10.), MAX_ABSOLUTE_DIFFERENCE));
+		assert_eq!(anchors[5], DVec2::new(150., 150.));
+		assert!(!rounded.closed());
+
+		let straight = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(50., 0.), DVec2::new(100., 0.)], false);
+		assert_eq!(straight.round_corners(10., Corner::Round, None).len(), 3);
+
+		// Only the selected corners are replaced
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		assert_eq!(square.round_corners(10., Corner::Round, Some(&[])).len(), 4);
+		assert_eq!(square.round_corners(10., Corner::Round, Some(&[EmptyId])).len(), 8);
+	}
+}
@@ -0,0 +1,315 @@
+use super::*;
+use crate::consts::{DISTANCE_SAMPLES_PER_SEGMENT, MAX_ABSOLUTE_DIFFERENCE, MIN_DISTANCE_ERROR};
+use crate::utils::{SubpathTValue, TValue};
+
+use glam::DVec2;
+
+/// The distance between two [Subpath]s, along with the pair of points between which it is measured, as returned by [Subpath::distance_to_subpath] and [Subpath::hausdorff_distance].
+/// Each point is given by the segment index and parametric `t`-value on its subpath, in the same way as by [Subpath::project].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SubpathDistance {
+	pub distance: f64,
+	/// The point on the subpath which the function was called on.
+	pub t: (usize, f64),
+	/// The point on the other subpath.
+	pub other_t: (usize, f64),
+}
+
+/// A piece of a segment of a subpath, between two parametric `t`-values, which is split in turn while searching for the closest points between two subpaths.
+#[derive(Copy, Clone)]
+struct Piece {
+	bezier: Bezier,
+	segment_index: usize,
+	t_range: [f64; 2],
+	/// The bounding box of the control points, which contains the whole piece.
+	bounding_box: [DVec2; 2],
+}
+
+impl Piece {
+	fn new(bezier: Bezier, segment_index: usize, t_range: [f64; 2]) -> Self {
+		let bounding_box = bezier.get_points().fold([DVec2::INFINITY, DVec2::NEG_INFINITY], |[min, max], point| [min.min(point), max.max(point)]);
+		Self {
+			bezier,
+			segment_index,
+			t_range,
+			bounding_box,
+		}
+	}
+
+	/// Returns the length of the diagonal of the bounding box, which no two points of the piece are further apart than.
+	fn size(&self) -> f64 {
+		self.bounding_box[0].distance(self.bounding_box[1])
+	}
+
+	/// Returns the point halfway along the piece by its `t`-values, along with its segment index and `t`-value on the subpath.
+	fn middle(&self) -> (DVec2, (usize, f64)) {
+		(self.bezier.evaluate(TValue::Parametric(0.5)), (self.segment_index, (self.t_range[0] + self.t_range[1]) / 2.))
+	}
+
+	fn split(&self) -> [Self; 2] {
+		let [first, second] = self.bezier.split(TValue::Parametric(0.5));
+		let middle_t = (self.t_range[0] + self.t_range[1]) / 2.;
+		[
+			Self::new(first, self.segment_index, [self.t_range[0], middle_t]),
+			Self::new(second, self.segment_index, [middle_t, self.t_range[1]]),
+		]
+	}
+
+	/// Returns the distance between the bounding boxes of the two pieces, which no two of their points are closer than.
+	fn minimum_distance(&self, other: &Self) -> f64 {
+		(self.bounding_box[0] - other.bounding_box[1])
+			.max(other.bounding_box[0] - self.bounding_box[1])
+			.max(DVec2::ZERO)
+			.length()
+	}
+}
+
+/// Returns the closest pair of points between two sets of pieces, as their distance and the `t`-values of the points on each, to within `error`.
+/// The pairs of pieces are split in turn, and those which cannot be closer than the closest pair found so far are discarded.
+fn closest_points(pieces1: &[Piece], pieces2: &[Piece], error: f64) -> (f64, (usize, f64), (usize, f64)) {
+	let mut closest = (f64::INFINITY, (0, 0.), (0, 0.));
+	let update_closest = |closest: &mut (f64, (usize, f64), (usize, f64)), piece1: &Piece, piece2: &Piece| {
+		let ((point1, t1), (point2, t2)) = (piece1.middle(), piece2.middle());
+		if point1.distance(point2) < closest.0 {
+			*closest = (point1.distance(point2), t1, t2);
+		}
+	};
+
+	// Start from the closest of the middles of each pair of pieces, so that most pairs are discarded straight away
+	let mut stack = Vec::with_capacity(pieces1.len() * pieces2.len());
+	for piece1 in pieces1 {
+		for piece2 in pieces2 {
+			update_closest(&mut closest, piece1, piece2);
+			stack.push((*piece1, *piece2));
+		}
+	}
+
+	while let Some((piece1, piece2)) = stack.pop() {
+		if piece1.minimum_distance(&piece2) >= closest.0 - error {
+			continue;
+		}
+		update_closest(&mut closest, &piece1, &piece2);
+		if piece1.size() + piece2.size() <= error {
+			continue;
+		}
+
+		// Split the larger piece, searching the half nearer to the other piece first
+		let mut pairs = if piece1.size() >= piece2.size() {
+			piece1.split().map(|half| (half, piece2))
+		} else {
+			piece2.split().map(|half| (piece1, half))
+		};
+		pairs.sort_by(|(a1, a2), (b1, b2)| b1.minimum_distance(b2).total_cmp(&a1.minimum_distance(a2)));
+		stack.extend(pairs);
+	}
+
+	closest
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns a piece covering each segment of the subpath, or a single point for a subpath with only one manipulator group.
+	fn distance_pieces(&self) -> Vec<Piece> {
+		if self.len_segments() == 0 {
+			return self
+				.manipulator_groups
+				.first()
+				.map(|group| Piece::new(Bezier::from_linear_dvec2(group.anchor, group.anchor), 0, [0., 0.]))
+				.into_iter()
+				.collect();
+		}
+		self.iter().enumerate().map(|(index, bezier)| Piece::new(bezier, index, [0., 1.])).collect()
+	}
+
+	/// Returns the shortest distance from the point to the subpath, or `None` if the subpath has no manipulator groups.
+	/// Unlike [Subpath::project], the closest point is searched for across the whole of every segment, so it is not missed between samples.
+	/// - `error` - The maximum amount by which the distance may exceed the true distance. The default value is `0.001`, and smaller values than `1e-6` are raised to it.
+	pub fn distance_to_point(&self, point: DVec2, error: Option<f64>) -> Option<f64> {
+		if self.is_empty() {
+			return None;
+		}
+		let point_piece = Piece::new(Bezier::from_linear_dvec2(point, point), 0, [0., 0.]);
+		Some(closest_points(&self.distance_pieces(), &[point_piece], error.unwrap_or(MAX_ABSOLUTE_DIFFERENCE).max(MIN_DISTANCE_ERROR)).0)
+	}
+
+	/// Returns the shortest distance between the two subpaths, along with the closest pair of points on them, or `None` if either subpath has no manipulator groups.
+	/// Subpaths which intersect or touch are at a distance of zero, measured between a pair of points where they meet.
+	/// - `error` - The maximum amount by which the distance may exceed the true distance. The default value is `0.001`, and smaller values than `1e-6` are raised to it.
+	pub fn distance_to_subpath(&self, other: &Subpath<ManipulatorGroupId>, error: Option<f64>) -> Option<SubpathDistance> {
+		if self.is_empty() || other.is_empty() {
+			return None;
+		}
+		let (distance, t, other_t) = closest_points(&self.distance_pieces(), &other.distance_pieces(), error.unwrap_or(MAX_ABSOLUTE_DIFFERENCE).max(MIN_DISTANCE_ERROR));
+		Some(SubpathDistance { distance, t, other_t })
+	}
+
+	/// Returns the point on this subpath which is farthest from the other subpath, as its distance and the `t`-values of it and of the closest point to it on the other subpath.
+	fn farthest_point_from(&self, other: &Subpath<ManipulatorGroupId>, error: f64) -> (f64, (usize, f64), (usize, f64)) {
+		let other_pieces = other.distance_pieces();
+		let closest_on_other = |segment_index: usize, t: f64| {
+			let point = if self.len_segments() == 0 {
+				self[0].anchor
+			} else {
+				self.evaluate(SubpathTValue::Parametric { segment_index, t })
+			};
+			let (distance, _, other_t) = closest_points(&[Piece::new(Bezier::from_linear_dvec2(point, point), 0, [0., 0.])], &other_pieces, error);
+			(distance, (segment_index, t), other_t)
+		};
+		if self.len_segments() == 0 {
+			return closest_on_other(0, 0.);
+		}
+
+		// Find the farthest of the points sampled along each segment
+		let (mut farthest, mut sample_index) = ((f64::NEG_INFINITY, (0, 0.), (0, 0.)), 0);
+		for segment_index in 0..self.len_segments() {
+			for index in 0..=DISTANCE_SAMPLES_PER_SEGMENT {
+				let candidate = closest_on_other(segment_index, index as f64 / DISTANCE_SAMPLES_PER_SEGMENT as f64);
+				if candidate.0 > farthest.0 {
+					(farthest, sample_index) = (candidate, index);
+				}
+			}
+		}
+
+		// Refine it between the neighbouring samples, where the distance rises to a peak and falls again
+		let segment_index = farthest.1 .0;
+		let step = 1. / DISTANCE_SAMPLES_PER_SEGMENT as f64;
+		let (mut low, mut high) = ((sample_index as f64 - 1.).max(0.) * step, (sample_index as f64 + 1.).min(DISTANCE_SAMPLES_PER_SEGMENT as f64) * step);
+		while high - low > error {
+			let (t1, t2) = (low + (high - low) / 3., high - (high - low) / 3.);
+			let (candidate1, candidate2) = (closest_on_other(segment_index, t1), closest_on_other(segment_index, t2));
+			for candidate in [candidate1, candidate2] {
+				if candidate.0 > farthest.0 {
+					farthest = candidate;
+				}
+			}
+			if candidate1.0 < candidate2.0 {
+				low = t1;
+			} else {
+				high = t2;
+			}
+		}
+
+		farthest
+	}
+
+	/// Returns the Hausdorff distance between the two subpaths, which is the farthest that any point on either subpath is from the other subpath, or `None` if either subpath has no manipulator groups.
+	/// It is returned along with the farthest point and the closest point to it on the other subpath, making it useful to check that two shapes match to within a tolerance.
+	/// The farthest point is found by sampling each segment and refining the farthest sample, so a peak in the distance narrower than the spacing of the samples may be missed.
+	/// - `error` - The precision to which the distances are found. The default value is `0.001`, and smaller values than `1e-6` are raised to it.
+	pub fn hausdorff_distance(&self, other: &Subpath<ManipulatorGroupId>, error: Option<f64>) -> Option<SubpathDistance> {
+		if self.is_empty() || other.is_empty() {
+			return None;
+		}
+		let error = error.unwrap_or(MAX_ABSOLUTE_DIFFERENCE).max(MIN_DISTANCE_ERROR);
+
+		let (distance, t, other_t) = self.farthest_point_from(other, error);
+		let (other_distance, other_t_from_other, t_from_other) = other.farthest_point_from(self, error);
+		Some(if distance >= other_distance {
+			SubpathDistance { distance, t, other_t }
+		} else {
+			SubpathDistance {
+				distance: other_distance,
+				t: t_from_other,
+				other_t: other_t_from_other,
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::f64_compare;
+	use crate::EmptyId;
+
+	use std::f64::consts::PI;
+
+	#[test]
+	fn distance_to_point() {
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-50.), DVec2::splat(50.));
+		assert!(f64_compare(circle.distance_to_point(DVec2::new(100., 100.), None).unwrap(), 50. * 2_f64.sqrt() * 2. - 50., 0.1));
+		assert!(f64_compare(circle.distance_to_point(DVec2::new(10., 0.), None).unwrap(), 40., 0.1));
+
+		let line = Subpath::<EmptyId>::new_line(DVec2::ZERO, DVec2::new(100., 0.));
+		assert!(f64_compare(line.distance_to_point(DVec2::new(30., 20.), None).unwrap(), 20., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(line.distance_to_point(DVec2::new(-30., 40.), None).unwrap(), 50., MAX_ABSOLUTE_DIFFERENCE));
+
+		let point = Subpath::<EmptyId>::from_anchors([DVec2::new(3., 4.)], false);
+		assert!(f64_compare(point.distance_to_point(DVec2::ZERO, None).unwrap(), 5., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(Subpath::<EmptyId>::new(vec![], false).distance_to_point(DVec2::ZERO, None).is_none());
+	}
+
+	#[test]
+	fn distance_to_subpath() {
+		// A square beside a circle, closest at the corner nearest to the circle
+		let square = Subpath::<EmptyId>::new_rect(DVec2::new(100., 100.), DVec2::new(200., 200.));
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-50.), DVec2::splat(50.));
+		let result = square.distance_to_subpath(&circle, None).unwrap();
+		assert!(f64_compare(result.distance, 100. * 2_f64.sqrt() - 50., 0.1));
+		assert!(square
+			.evaluate(SubpathTValue::Parametric {
+				segment_index: result.t.0,
+				t: result.t.1
+			})
+			.abs_diff_eq(DVec2::splat(100.), 0.01));
+		let closest_on_circle = circle.evaluate(SubpathTValue::Parametric {
+			segment_index: result.other_t.0,
+			t: result.other_t.1,
+		});
+		assert!(closest_on_circle.abs_diff_eq(DVec2::from_angle(PI / 4.) * 50., 0.5));
+
+		// Parallel lines are as far apart along their whole length
+		let line1 = Subpath::<EmptyId>::new_line(DVec2::ZERO, DVec2::new(100., 0.));
+		let line2 = Subpath::<EmptyId>::new_line(DVec2::new(-50., 10.), DVec2::new(50., 10.));
+		assert!(f64_compare(line1.distance_to_subpath(&line2, None).unwrap().distance, 10., MAX_ABSOLUTE_DIFFERENCE));
+
+		// Crossing subpaths are at a distance of zero, measured where they cross
+		let crossing = Subpath::<EmptyId>::new_line(DVec2::new(25., -10.), DVec2::new(25., 10.));
+		let result = line1.distance_to_subpath(&crossing, None).unwrap();
+		assert!(result.distance < MAX_ABSOLUTE_DIFFERENCE);
+		let point = line1.evaluate(SubpathTValue::Parametric {
+			segment_index: result.t.0,
+			t: result.t.1,
+		});
+		let other_point = crossing.evaluate(SubpathTValue::Parametric {
+			segment_index: result.other_t.0,
+			t: result.other_t.1,
+		});
+		assert!(point.abs_diff_eq(DVec2::new(25., 0.), MAX_ABSOLUTE_DIFFERENCE) && other_point.abs_diff_eq(DVec2::new(25., 0.), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn hausdorff_distance() {
+		// Concentric circles are as far apart everywhere
+		let circle1 = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-50.), DVec2::splat(50.));
+		let circle2 = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-40.), DVec2::splat(40.));
+		assert!(f64_compare(circle1.hausdorff_distance(&circle2, None).unwrap().distance, 10., 0.1));
+
+		// A line and a shorter part of it are farthest apart at the far end of the longer line, whichever it is called on
+		let line = Subpath::<EmptyId>::new_line(DVec2::ZERO, DVec2::new(100., 0.));
+		let part = Subpath::<EmptyId>::new_line(DVec2::new(10., 0.), DVec2::new(60., 0.));
+		let result = line.hausdorff_distance(&part, None).unwrap();
+		assert!(f64_compare(result.distance, 40., 0.01));
+		assert!(f64_compare(result.t.1, 1., 0.01) && f64_compare(result.other_t.1, 1., 0.01));
+		let result = part.hausdorff_distance(&line, None).unwrap();
+		assert!(f64_compare(result.distance, 40., 0.01));
+		assert!(f64_compare(result.t.1, 1., 0.01) && f64_compare(result.other_t.1, 1., 0.01));
+
+		// A peak between the samples is refined
+		let arc = Subpath::<EmptyId>::new_circular_arc(DVec2::ZERO, 50., 0.3, 2.5, Some(10.));
+		let wide_line = Subpath::<EmptyId>::new_line(DVec2::new(-50., 0.), DVec2::new(50., 0.));
+		let bezier = arc.iter().next().unwrap();
+		let peak = (0..=10000).map(|index| bezier.evaluate(TValue::Parametric(index as f64 / 10000.)).y).fold(0., f64::max);
+		assert!(f64_compare(wide_line.hausdorff_distance(&arc, None).unwrap().distance, peak, 2e-3));
+
+		// A subpath matches itself
+		assert!(circle1.hausdorff_distance(&circle1, None).unwrap().distance < MAX_ABSOLUTE_DIFFERENCE);
+
+		// A precision of zero or less is raised to the smallest one searched for, so the search still ends
+		let curve = Subpath::<EmptyId>::from_bezier(&Bezier::from_cubic_coordinates(0., 0., 30., 40., 70., -40., 100., 0.));
+		for error in [0., -1.] {
+			assert!(f64_compare(line.hausdorff_distance(&part, Some(error)).unwrap().distance, 40., 1e-5));
+			assert!(curve.hausdorff_distance(&line, Some(error)).unwrap().distance > 0.);
+			assert!(f64_compare(curve.distance_to_point(DVec2::new(0., -10.), Some(error)).unwrap(), 10., 1e-5));
+		}
+	}
+}
@@ -0,0 +1,429 @@
+use super::*;
+use crate::consts::{
+	DEFAULT_FIT_CORNER_ANGLE, FIT_MAX_REPARAMETERIZATION_ITERATIONS, FIT_REPARAMETERIZATION_ERROR_FACTOR, MAX_ABSOLUTE_DIFFERENCE, SIMPLIFY_MAX_REPARAMETERIZATION_ITERATIONS,
+	SIMPLIFY_SAMPLES_PER_SEGMENT, STRICT_MAX_ABSOLUTE_DIFFERENCE,
+};
+use crate::utils::TValue;
+
+use glam::DVec2;
+
+/// Returns the parameter of each point, proportional to the distance travelled along the polyline through the points.
+fn chord_length_parameterize(points: &[DVec2]) -> Vec<f64> {
+	let mut parameters = Vec::with_capacity(points.len());
+	let mut distance = 0.;
+	parameters.push(0.);
+	for pair in points.windows(2) {
+		distance += pair[0].distance(pair[1]);
+		parameters.push(distance);
+	}
+	parameters.iter().map(|parameter| parameter / distance).collect()
+}
+
+/// Estimates the direction in which the samples leave the first point, from the parabola through the first three points parameterized by chord length.
+fn start_tangent(points: &[DVec2]) -> DVec2 {
+	let chord_direction = (points[1] - points[0]).normalize();
+	if points.len() < 3 {
+		return chord_direction;
+	}
+
+	let (distance1, distance2) = (points[0].distance(points[1]), points[1].distance(points[2]));
+	let total = distance1 + distance2;
+	let derivative = -(2. * distance1 + distance2) / (distance1 * total) * points[0] + total / (distance1 * distance2) * points[1] - distance1 / (distance2 * total) * points[2];
+
+	// Only trust the estimate if it leaves in roughly the same direction as the samples, which may not be the case for noisy samples
+	derivative.try_normalize().filter(|direction| direction.dot(chord_direction) > 0.).unwrap_or(chord_direction)
+}
+
+/// Finds the cubic bezier with the given end tangents which best fits the points at their given parameters, in the least-squares sense.
+/// The tangents point away from the curve at the start, and back towards the curve at the end.
+fn generate_bezier(points: &[DVec2], parameters: &[f64], start_tangent: DVec2, end_tangent: DVec2) -> Bezier {
+	let (first, last) = (points[0], points[points.len() - 1]);
+
+	// Solve the normal equations for the distances of the handles from the anchors
+	let mut c = [[0.; 2]; 2];
+	let mut x = [0.; 2];
+	for (&point, &t) in points.iter().zip(parameters) {
+		let basis = [(1. - t).powi(3), 3. * t * (1. - t).powi(2), 3. * t.powi(2) * (1. - t), t.powi(3)];
+		let a = [start_tangent * basis[1], end_tangent * basis[2]];
+
+		c[0][0] += a[0].dot(a[0]);
+		c[0][1] += a[0].dot(a[1]);
+		c[1][1] += a[1].dot(a[1]);
+
+		let remainder = point - (first * (basis[0] + basis[1]) + last * (basis[2] + basis[3]));
+		x[0] += a[0].dot(remainder);
+		x[1] += a[1].dot(remainder);
+	}
+	c[1][0] = c[0][1];
+
+	let determinant = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+	let (start_distance, end_distance) = if determinant.abs() > f64::EPSILON {
+		((x[0] * c[1][1] - x[1] * c[0][1]) / determinant, (c[0][0] * x[1] - c[1][0] * x[0]) / determinant)
+	} else {
+		(0., 0.)
+	};
+
+	// Fall back to handles at a third of the chord length when the solution is degenerate, as the handles would then point the wrong way
+	let chord_length = first.distance(last);
+	let epsilon = STRICT_MAX_ABSOLUTE_DIFFERENCE * chord_length;
+	let (start_distance, end_distance) = if start_distance < epsilon || end_distance < epsilon {
+		(chord_length / 3., chord_length / 3.)
+	} else {
+		(start_distance, end_distance)
+	};
+
+	Bezier::from_cubic_dvec2(first, first + start_tangent * start_distance, last + end_tangent * end_distance, last)
+}
+
+/// Improves the parameter of each point with a step of Newton's method, so each parameter better corresponds to the closest point on the curve.
+fn reparameterize(bezier: &Bezier, points: &[DVec2], parameters: &[f64]) -> Vec<f64> {
+	let derivative = bezier.derivative();
+
+	points
+		.iter()
+		.zip(parameters)
+		.map(|(&point, &t)| {
+			let offset = bezier.evaluate(TValue::Parametric(t)) - point;
+			let velocity = bezier.non_normalized_tangent(t);
+			let acceleration = derivative.map_or(DVec2::ZERO, |derivative| derivative.non_normalized_tangent(t));
+
+			let denominator = velocity.dot(velocity) + offset.dot(acceleration);
+			if denominator.abs() < f64::EPSILON {
+				t
+			} else {
+				(t - offset.dot(velocity) / denominator).clamp(0., 1.)
+			}
+		})
+		.collect()
+}
+
+/// Returns the largest distance between a point and the nearby closest position on the curve, along with the index of that point.
+/// The parameters are refined before measuring, so points which lie on the curve at a slightly different parameter aren't counted as errors.
+fn max_error(bezier: &Bezier, points: &[DVec2], parameters: &[f64]) -> (f64, usize) {
+	let parameters = reparameterize(bezier, points, parameters);
+	let mut max_error = 0.;
+	let mut split_index = points.len() / 2;
+	for (index, (&point, &t)) in points.iter().zip(&parameters).enumerate().skip(1).take(points.len().saturating_sub(2)) {
+		let error = bezier.evaluate(TValue::Parametric(t)).distance(point);
+		if error > max_error {
+			max_error = error;
+			split_index = index;
+		}
+	}
+	(max_error, split_index)
+}
+
+/// Recursively fits cubic beziers to the points, splitting them at the point of largest error until every point is within `tolerance` of the curve.
+fn fit_cubic(points: &[DVec2], start_tangent: DVec2, end_tangent: DVec2, tolerance: f64, beziers: &mut Vec<Bezier>) {
+	let (first, last) = (points[0], points[points.len() - 1]);
+	if points.len() == 2 {
+		let distance = first.distance(last) / 3.;
+		beziers.push(Bezier::from_cubic_dvec2(first, first + start_tangent * distance, last + end_tangent * distance, last));
+		return;
+	}
+
+	let mut parameters = chord_length_parameterize(points);
+	let mut bezier = generate_bezier(points, &parameters, start_tangent, end_tangent);
+	let (mut error, mut split_index) = max_error(&bezier, points, &parameters);
+	if error <= tolerance {
+		beziers.push(bezier);
+		return;
+	}
+
+	// If the fit is close, adjusting the parameters of the points may be enough to bring it within the tolerance
+	if error <= tolerance * FIT_REPARAMETERIZATION_ERROR_FACTOR {
+		for _ in 0..FIT_MAX_REPARAMETERIZATION_ITERATIONS {
+			parameters = reparameterize(&bezier, points, &parameters);
+			bezier = generate_bezier(points, &parameters, start_tangent, end_tangent);
+			(error, split_index) = max_error(&bezier, points, &parameters);
+			if error <= tolerance {
+				beziers.push(bezier);
+				return;
+			}
+		}
+	}
+
+	// Otherwise split the points where the error is largest, keeping the curve smooth across the split
+	let center_tangent = (points[split_index - 1] - points[split_index + 1])
+		.try_normalize()
+		.or_else(|| (points[split_index - 1] - points[split_index]).try_normalize())
+		.unwrap_or(end_tangent);
+	fit_cubic(&points[..=split_index], start_tangent, center_tangent, tolerance, beziers);
+	fit_cubic(&points[split_index..], -center_tangent, end_tangent, tolerance, beziers);
+}
+
+/// Returns the direction in which the bezier leaves its start, ignoring any handles which coincide with the start.
+pub(crate) fn start_direction(bezier: &Bezier) -> Option<DVec2> {
+	bezier.get_points().skip(1).find_map(|point| (point - bezier.start()).try_normalize())
+}
+
+/// Returns whether the bezier is a straight line, which is the case when its handles are absent or lie on its anchors.
+pub(crate) fn is_straight_line(bezier: &Bezier) -> bool {
+	bezier
+		.get_points()
+		.all(|point| point.abs_diff_eq(bezier.start(), STRICT_MAX_ABSOLUTE_DIFFERENCE) || point.abs_diff_eq(bezier.end(), STRICT_MAX_ABSOLUTE_DIFFERENCE))
+}
+
+/// Returns a single curve which replaces the consecutive segments while staying within `tolerance` of them, if there is one.
+/// The curve leaves and arrives in the same directions as the segments, so the smoothness at the anchors on either end is preserved.
+fn merge_segments(segments: &[Bezier], tolerance: f64) -> Option<Bezier> {
+	let (first, last) = (segments[0].start(), segments[segments.len() - 1].end());
+
+	// Sample each segment, also keeping track of which segment lies between each pair of samples to check that the merged curve doesn't stray from it
+	let step_size = 1. / SIMPLIFY_SAMPLES_PER_SEGMENT as f64;
+	let mut points = vec![first];
+	let mut sampled_segments = Vec::new();
+	for segment in segments {
+		for step in 1..=SIMPLIFY_SAMPLES_PER_SEGMENT {
+			let t = step as f64 * step_size;
+			sampled_segments.push(segment);
+			points.push(segment.evaluate(TValue::Parametric(t)));
+		}
+	}
+
+	let (bezier, parameters) = if segments.iter().all(is_straight_line) {
+		// Runs of lines, such as collinear or nearly coincident anchors, are merged into a single line
+		if first.abs_diff_eq(last, STRICT_MAX_ABSOLUTE_DIFFERENCE) {
+			return None;
+		}
+		let line = Bezier::from_linear_dvec2(first, last);
+		let parameters = reparameterize(&line, &points, &chord_length_parameterize(&points));
+		(line, parameters)
+	} else {
+		let start_tangent = segments.iter().find_map(start_direction)?;
+		let end_tangent = segments.iter().rev().find_map(|segment| start_direction(&segment.reverse()))?;
+
+		// Keep improving the parameters for as long as it takes to come within the tolerance, since merges are only accepted if they do
+		let mut parameters = chord_length_parameterize(&points);
+		let mut bezier = generate_bezier(&points, &parameters, start_tangent, end_tangent);
+		for _ in 0..SIMPLIFY_MAX_REPARAMETERIZATION_ITERATIONS {
+			if max_error(&bezier, &points, &parameters).0 <= tolerance {
+				break;
+			}
+			parameters = reparameterize(&bezier, &points, &parameters);
+			bezier = generate_bezier(&points, &parameters, start_tangent, end_tangent);
+		}
+		(bezier, reparameterize(&bezier, &points, &parameters))
+	};
+
+	// Bound the Hausdorff distance by measuring both how far the samples are from the merged curve and how far the merged curve is from the samples
+	let (points_error, _) = max_error(&bezier, &points, &parameters);
+	let curve_error = parameters
+		.windows(2)
+		.zip(sampled_segments)
+		.map(|(pair, segment)| {
+			let point = bezier.evaluate(TValue::Parametric((pair[0] + pair[1]) / 2.));
+			segment.evaluate(TValue::Parametric(segment.project(point, None))).distance(point)
+		})
+		.fold(0., f64::max);
+	(points_error <= tolerance && curve_error <= tolerance).then_some(bezier)
+}
+
+/// Functionality for fitting curves to sampled points.
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns an open subpath made of as few cubic segments as possible which passes within `tolerance` of every point, such as the samples of a freehand stroke
+	/// or the outline traced around a raster shape. Curves are fitted by least squares with the algorithm of Philip J. Schneider from *Graphics Gems* (1990).
+	/// The result is smooth everywhere except at the points where the samples turn sharply, which become corners of the subpath.
+	/// - `tolerance` - The maximum distance allowed between any of the points and the resulting subpath.
+	/// - `corner_angle` - The smallest angle, in radians, by which the direction of the samples must turn at a point for it to become a corner. The default value is `π / 3`.
+	pub fn fit_points(points: &[DVec2], tolerance: f64, corner_angle: Option<f64>) -> Self {
+		let mut points = points.to_vec();
+		points.dedup_by(|point, previous| point.abs_diff_eq(*previous, MAX_ABSOLUTE_DIFFERENCE));
+		if points.len() < 2 {
+			return Subpath::from_anchors(points, false);
+		}
+
+		let corner_angle = corner_angle.unwrap_or(DEFAULT_FIT_CORNER_ANGLE);
+		let mut beziers = Vec::new();
+		let mut run_start = 0;
+		for index in 1..points.len() {
+			let is_corner = index == points.len() - 1 || (points[index] - points[index - 1]).angle_between(points[index + 1] - points[index]).abs() > corner_angle;
+			if !is_corner {
+				continue;
+			}
+
+			let run = &points[run_start..=index];
+			let reversed_run = run.iter().rev().copied().collect::<Vec<_>>();
+			fit_cubic(run, start_tangent(run), start_tangent(&reversed_run), tolerance, &mut beziers);
+			run_start = index;
+		}
+
+		Subpath::from_beziers(&beziers, false)
+	}
+
+	/// Returns a copy of the subpath with as many anchors removed as possible while keeping its shape within `tolerance` of the original, measured by the Hausdorff distance.
+	/// Anchors which are collinear with or nearly coincide with their neighbors are removed, and runs of segments which are closely approximated by a single segment are merged into it.
+	/// The remaining manipulator groups keep their IDs, and the first anchor is always kept, along with the last anchor of an open subpath.
+	/// - `tolerance` - The maximum distance allowed between the original and the simplified subpath.
+	pub fn simplify(&self, tolerance: f64) -> Self {
+		let segments = self.iter().collect::<Vec<_>>();
+		if segments.len() < 2 {
+			return self.clone();
+		}
+
+		let mut manipulator_groups = vec![self.manipulator_groups[0].clone()];
+		let mut run_start = 0;
+		let mut merged_run = None;
+		for index in 1..segments.len() {
+			// A closed subpath needs at least two segments, so the last run can't reach all the way around from the first anchor
+			let closes_single_run = self.closed && index == segments.len() - 1 && manipulator_groups.len() == 1;
+			if !closes_single_run {
+				if let Some(bezier) = merge_segments(&segments[run_start..=index], tolerance) {
+					merged_run = Some(bezier);
+					continue;
+				}
+			}
+
+			let mut manipulator_group = self.manipulator_groups[index].clone();
+			if let Some(bezier) = merged_run.take() {
+				manipulator_groups.last_mut().unwrap().out_handle = bezier.handle_start();
+				manipulator_group.in_handle = bezier.handle_end();
+			}
+			manipulator_groups.push(manipulator_group);
+			run_start = index;
+		}
+
+		// The last run ends at the last anchor of an open subpath, or back at the first anchor of a closed subpath
+		if let Some(bezier) = &merged_run {
+			manipulator_groups.last_mut().unwrap().out_handle = bezier.handle_start();
+		}
+		if !self.closed {
+			manipulator_groups.push(self.manipulator_groups[self.len() - 1].clone());
+		}
+		if let Some(bezier) = merged_run {
+			let end_index = if self.closed { 0 } else { manipulator_groups.len() - 1 };
+			manipulator_groups[end_index].in_handle = bezier.handle_end();
+		}
+
+		Subpath::new(manipulator_groups, self.closed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::f64::consts::PI;
+
+	/// Returns the largest distance between any of the points and the subpath.
+	fn max_distance(subpath: &Subpath<EmptyId>, points: &[DVec2]) -> f64 {
+		points
+			.iter()
+			.map(|&point| {
+				let (segment_index, t) = subpath.project(point, None).unwrap();
+				subpath.get_segment(segment_index).unwrap().evaluate(TValue::Parametric(t)).distance(point)
+			})
+			.fold(0., f64::max)
+	}
+
+	/// Returns whether the segments of the subpath all meet with matching tangents.
+	fn is_smooth(subpath: &Subpath<EmptyId>) -> bool {
+		subpath.manipulator_groups()[1..subpath.len() - 1].iter().all(|group| {
+			let (in_handle, out_handle) = (group.in_handle.unwrap(), group.out_handle.unwrap());
+			(group.anchor - in_handle).normalize().abs_diff_eq((out_handle - group.anchor).normalize(), MAX_ABSOLUTE_DIFFERENCE)
+		})
+	}
+
+	#[test]
+	fn fit_samples_of_a_single_curve() {
+		let bezier = Bezier::from_cubic_coordinates(10., 10., 40., 90., 120., 100., 150., 40.);
+		let points = (0..=50).map(|step| bezier.evaluate(TValue::Parametric(step as f64 / 50.))).collect::<Vec<_>>();
+
+		let fitted = Subpath::<EmptyId>::fit_points(&points, 0.5, None);
+		assert!(fitted.len_segments() <= 2);
+		assert_eq!(fitted[0].anchor, points[0]);
+		assert_eq!(fitted[fitted.len() - 1].anchor, points[50]);
+		assert!(max_distance(&fitted, &points) <= 0.5 + MAX_ABSOLUTE_DIFFERENCE);
+		assert!(is_smooth(&fitted));
+	}
+
+	#[test]
+	fn fit_circular_arc() {
+		let points = (0..=100).map(|step| DVec2::from_angle(step as f64 / 100. * 1.5 * PI) * 50.).collect::<Vec<_>>();
+
+		let fitted = Subpath::<EmptyId>::fit_points(&points, 0.1, None);
+		assert!(fitted.len_segments() > 1);
+		assert!(fitted.len_segments() <= 6);
+		assert!(max_distance(&fitted, &points) <= 0.1 + MAX_ABSOLUTE_DIFFERENCE);
+
+		assert!(is_smooth(&fitted));
+	}
+
+	#[test]
+	fn fit_corners() {
+		let points = (0..=10)
+			.map(|step| DVec2::new(step as f64 * 10., 0.))
+			.chain((1..=10).map(|step| DVec2::new(100., step as f64 * 10.)))
+			.collect::<Vec<_>>();
+
+		let fitted = Subpath::<EmptyId>::fit_points(&points, 0.1, None);
+		assert_eq!(fitted.len_segments(), 2);
+		assert_eq!(fitted[1].anchor, DVec2::new(100., 0.));
+		assert!(max_distance(&fitted, &points) <= 0.1 + MAX_ABSOLUTE_DIFFERENCE);
+
+		// With a large enough corner angle, the corner is rounded off instead
+		let rounded = Subpath::<EmptyId>::fit_points(&points, 5., Some(PI));
+		assert!(max_distance(&rounded, &points) <= 5. + MAX_ABSOLUTE_DIFFERENCE);
+		assert!(is_smooth(&rounded));
+	}
+
+	#[test]
+	fn fit_degenerate_input() {
+		assert!(Subpath::<EmptyId>::fit_points(&[], 1., None).is_empty());
+		assert_eq!(Subpath::<EmptyId>::fit_points(&[DVec2::ONE, DVec2::ONE], 1., None).len(), 1);
+
+		let line = Subpath::<EmptyId>::fit_points(&[DVec2::ZERO, DVec2::ZERO, DVec2::new(5., 5.), DVec2::new(10., 10.)], 0.01, None);
+		assert_eq!(line.len_segments(), 1);
+		assert_eq!(line.anchors(), vec![DVec2::ZERO, DVec2::new(10., 10.)]);
+	}
+
+	#[test]
+	fn simplify_collinear_and_coincident_anchors() {
+		let subpath = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(20., 0.), DVec2::new(20., 1e-4), DVec2::new(30., 10.)], false);
+
+		let simplified = subpath.simplify(0.01);
+		assert_eq!(simplified.anchors(), vec![DVec2::ZERO, DVec2::new(20., 1e-4), DVec2::new(30., 10.)]);
+		assert!(simplified.iter().all(|bezier| is_straight_line(&bezier)));
+	}
+
+	#[test]
+	fn simplify_subdivided_curve() {
+		let bezier = Bezier::from_cubic_coordinates(10., 10., 40., 90., 120., 100., 150., 40.);
+		let [first, rest] = bezier.split(TValue::Parametric(0.3));
+		let [second, third] = rest.split(TValue::Parametric(0.5));
+		let subpath = Subpath::<EmptyId>::from_beziers(&[first, second, third], false);
+
+		let simplified = subpath.simplify(0.5);
+		assert_eq!(simplified.len_segments(), 1);
+		let points = (0..=50).map(|step| bezier.evaluate(TValue::Parametric(step as f64 / 50.))).collect::<Vec<_>>();
+		// The default projection is too coarse to measure a tolerance this tight
+		let options = crate::ProjectionOptions {
+			lut_size: 200,
+			convergence_epsilon: 1e-8,
+			..Default::default()
+		};
+		let segment = simplified.get_segment(0).unwrap();
+		assert!(points.iter().all(|&point| segment.evaluate(TValue::Parametric(segment.project(point, Some(options)))).distance(point) <= 0.5));
+	}
+
+	#[test]
+	fn simplify_keeps_corners_and_curves() {
+		let square = Subpath::<EmptyId>::from_anchors([(0., 0.), (5., 0.), (10., 0.), (10., 10.), (5., 10.), (0., 10.), (0., 5.)].map(|(x, y)| DVec2::new(x, y)), true);
+		let simplified = square.simplify(0.01);
+		assert!(simplified.closed());
+		assert_eq!(simplified.anchors(), vec![DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 10.), DVec2::new(0., 10.)]);
+
+		// Each quarter of an ellipse is too far from a single segment spanning two quarters to be merged
+		let ellipse = Subpath::<EmptyId>::new_ellipse(DVec2::ZERO, DVec2::new(100., 50.));
+		assert_eq!(ellipse.simplify(0.1), ellipse);
+
+		// A closed subpath keeps at least two segments
+		let lens = Subpath::<EmptyId>::from_beziers(
+			&[
+				Bezier::from_quadratic_coordinates(0., 0., 50., 1., 100., 0.),
+				Bezier::from_quadratic_coordinates(100., 0., 50., -1., 0., 0.),
+			],
+			true,
+		);
+		assert_eq!(lens.simplify(10.).len_segments(), 2);
+	}
+}
@@ -1,6 +1,6 @@
 use super::*;
-use crate::consts::{DEFAULT_EUCLIDEAN_ERROR_BOUND, DEFAULT_LUT_STEP_SIZE};
-use crate::utils::{SubpathTValue, TValue, TValueType};
+use crate::consts::{DEFAULT_EUCLIDEAN_ERROR_BOUND, DEFAULT_LUT_STEP_SIZE, STRICT_MAX_ABSOLUTE_DIFFERENCE};
+use crate::utils::{SubpathTValue, TValue, TValueType, WindingDirection};
 use crate::ProjectionOptions;
 use glam::DVec2;
 
@@ -116,6 +116,50 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 
 		Some((index, project_t))
 	}
+
+	/// Returns the signed area of the subpath and the first moments of that area about `origin`.
+	/// An open subpath is closed with a straight segment from its last anchor back to its first.
+	fn area_and_moments(&self, origin: DVec2) -> (f64, DVec2) {
+		let closing_segment = (!self.closed && self.len() > 1).then(|| Bezier::from_linear_dvec2(self[self.len() - 1].anchor, self[0].anchor));
+		self.iter()
+			.chain(closing_segment)
+			.map(|bezier| bezier.area_and_moments(origin))
+			.fold((0., DVec2::ZERO), |(area, moments), (bezier_area, bezier_moments)| (area + bezier_area, moments + bezier_moments))
+	}
+
+	/// Returns the signed area enclosed by the subpath, computed exactly using Green's theorem.
+	/// An open subpath is treated as if it were closed by a straight segment from its last anchor back to its first, as it is when filled.
+	/// The area is positive when the subpath winds counterclockwise with +y pointing up, as for [Bezier::winding].
+	/// If the subpath intersects itself, regions wound in opposite directions, such as the two loops of a figure eight, cancel out.
+	pub fn signed_area(&self) -> f64 {
+		self.manipulator_groups.first().map_or(0., |group| self.area_and_moments(group.anchor).0)
+	}
+
+	/// Returns the area enclosed by the subpath, treating open subpaths as if they were closed by a straight segment.
+	pub fn area(&self) -> f64 {
+		self.signed_area().abs()
+	}
+
+	/// Returns the centroid of the area enclosed by the subpath, treating open subpaths as if they were closed by a straight segment.
+	/// Returns `None` if the subpath encloses no area.
+	pub fn centroid(&self) -> Option<DVec2> {
+		let origin = self.manipulator_groups.first()?.anchor;
+		let (area, moments) = self.area_and_moments(origin);
+		(area.abs() > STRICT_MAX_ABSOLUTE_DIFFERENCE).then(|| origin + moments / area)
+	}
+
+	/// Returns the direction in which the subpath winds around the area it encloses, based on the sign of [Subpath::signed_area].
+	/// Returns `None` if the subpath encloses no area.
+	pub fn winding_direction(&self) -> Option<WindingDirection> {
+		let signed_area = self.signed_area();
+		if signed_area > STRICT_MAX_ABSOLUTE_DIFFERENCE {
+			Some(WindingDirection::Counterclockwise)
+		} else if signed_area < -STRICT_MAX_ABSOLUTE_DIFFERENCE {
+			Some(WindingDirection::Clockwise)
+		} else {
+			None
+		}
+	}
 }
 
 #[cfg(test)]
@@ -253,6 +297,40 @@ mod tests {
 		assert_eq!(closed_subpath.t_value_to_parametric(SubpathTValue::GlobalParametric(1.)), (4, 1.));
 	}
 
+	#[test]
+	fn area_and_centroid_rectangle() {
+		let rectangle: Subpath<EmptyId> = Subpath::new_rect(DVec2::new(10., 20.), DVec2::new(40., 30.));
+		let signed_area = rectangle.signed_area();
+
+		assert!(f64_compare(signed_area.abs(), 300., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(rectangle.reverse().signed_area(), -signed_area, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(rectangle.area(), 300., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(rectangle.centroid().unwrap().abs_diff_eq(DVec2::new(25., 25.), MAX_ABSOLUTE_DIFFERENCE));
+		assert_ne!(rectangle.winding_direction(), rectangle.reverse().winding_direction());
+	}
+
+	#[test]
+	fn area_and_centroid_open_and_curved() {
+		// An open subpath is closed by a straight segment
+		let triangle: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(0., 0.), DVec2::new(6., 0.), DVec2::new(0., 3.)], false);
+		assert!(f64_compare(triangle.signed_area(), 9., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(triangle.centroid().unwrap().abs_diff_eq(DVec2::new(2., 1.), MAX_ABSOLUTE_DIFFERENCE));
+		assert_eq!(triangle.winding_direction(), Some(WindingDirection::Counterclockwise));
+
+		// The four cubic segments approximating a circle enclose slightly more than the circle itself
+		let circle: Subpath<EmptyId> = Subpath::new_ellipse(DVec2::new(-10., -10.), DVec2::new(10., 10.));
+		assert!(f64_compare(circle.area(), std::f64::consts::PI * 100., 0.1));
+		assert!(circle.centroid().unwrap().abs_diff_eq(DVec2::ZERO, MAX_ABSOLUTE_DIFFERENCE));
+
+		// Both loops of a figure eight cancel out, and a line encloses no area
+		let figure_eight: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(0., 0.), DVec2::new(10., 10.), DVec2::new(10., 0.), DVec2::new(0., 10.)], true);
+		assert!(f64_compare(figure_eight.signed_area(), 0., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(figure_eight.winding_direction().is_none());
+		let line: Subpath<EmptyId> = Subpath::new_line(DVec2::ZERO, DVec2::new(5., 5.));
+		assert_eq!(line.area(), 0.);
+		assert!(line.centroid().is_none());
+	}
+
 	#[test]
 	fn exact_start_end() {
 		let start = DVec2::new(20., 30.);
@@ -1,9 +1,21 @@
+mod arc_length;
+mod boolean;
 mod core;
+mod corners;
+mod distance;
+mod fitting;
 mod lookup;
 mod manipulators;
+mod morph;
+mod offset;
+mod parse;
 mod solvers;
+mod stroke;
 mod structs;
 mod transform;
+pub use arc_length::*;
+pub use distance::*;
+pub use parse::*;
 pub use structs::*;
 
 use crate::Bezier;
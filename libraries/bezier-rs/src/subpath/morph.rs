@@ -0,0 +1,157 @@
+use super::*;
+use crate::utils::TValue;
+use crate::BezierHandles;
+
+use glam::DVec2;
+
+/// Returns the start point, handles and end point of the bezier as a cubic, placing the handles of a straight line a third of the way along it from either end.
+fn cubic_points(bezier: &Bezier) -> [DVec2; 4] {
+	let (start, end) = (bezier.start, bezier.end);
+	match bezier.handles {
+		BezierHandles::Linear => [start, start + (end - start) / 3., end + (start - end) / 3., end],
+		BezierHandles::Quadratic { handle } => [start, start + (handle - start) * 2. / 3., end + (handle - end) * 2. / 3., end],
+		BezierHandles::Cubic { handle_start, handle_end } => [start, handle_start, handle_end, end],
+	}
+}
+
+/// Returns the segments after splitting the longest one in half until there are `count` of them.
+fn split_to_count(mut segments: Vec<Bezier>, count: usize) -> Vec<Bezier> {
+	let mut lengths = segments.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>();
+	while segments.len() < count {
+		let longest = (0..segments.len()).max_by(|&a, &b| lengths[a].total_cmp(&lengths[b])).unwrap();
+		let [first, second] = segments[longest].split(TValue::Euclidean(0.5));
+		segments.splice(longest..=longest, [first, second]);
+		lengths.splice(longest..=longest, [lengths[longest] / 2.; 2]);
+	}
+	segments
+}
+
+/// Returns the sum of the squared distances between the starts of corresponding segments, measured from the centers of each set of segments so the result doesn't depend on where they are.
+fn alignment_cost(segments: &[Bezier], other: &[Bezier], center: DVec2, other_center: DVec2) -> f64 {
+	segments.iter().zip(other).map(|(a, b)| (a.start - center).distance_squared(b.start - other_center)).sum()
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns the shape a fraction `t` of the way from this subpath to the `other` one, where `t` is `0` for this subpath and `1` for the other.
+	/// The subpaths are first matched up so that the shape changes as little as possible along the way:
+	/// - The subpath with fewer segments has its longest segments split in half until both have the same number.
+	/// - Closed subpaths are made to wind in the same direction, and the other subpath is started from whichever of its anchors lines up best with the start of this one.
+	/// - An open subpath may be followed in reverse if its ends line up better that way, and a closed subpath morphing with an open one is treated as open, ending where it started.
+	/// - A subpath with no segments, such as a single point, grows into or shrinks from the other subpath.
+	pub fn morph(&self, other: &Subpath<ManipulatorGroupId>, t: f64) -> Subpath<ManipulatorGroupId> {
+		if other.is_empty() {
+			return self.clone();
+		}
+		if self.is_empty() {
+			return other.clone();
+		}
+
+		let closed = match (self.len_segments(), other.len_segments()) {
+			(0, _) => other.closed,
+			(_, 0) => self.closed,
+			_ => self.closed && other.closed,
+		};
+		let segments = self.iter().collect::<Vec<_>>();
+		let mut other_segments = other.iter().collect::<Vec<_>>();
+		if closed && self.signed_area() * other.signed_area() < 0. {
+			other_segments = other.reverse().iter().collect();
+		}
+
+		// A single point is spread out over as many segments as the other subpath has, all of no length
+		let count = segments.len().max(other_segments.len()).max(1);
+		let point_segments = |subpath: &Subpath<ManipulatorGroupId>| vec![Bezier::from_linear_dvec2(subpath.manipulator_groups[0].anchor, subpath.manipulator_groups[0].anchor); count];
+		let mut segments = if segments.is_empty() { point_segments(self) } else { split_to_count(segments, count) };
+		let mut other_segments = if other_segments.is_empty() {
+			point_segments(other)
+		} else {
+			split_to_count(other_segments, count)
+		};
+
+		// Line up the starts of the segments, trying each possible starting anchor of a closed subpath, or both directions of an open one
+		let center = |segments: &[Bezier]| {
+			let (min, max) = segments
+				.iter()
+				.map(|bezier| bezier.bounding_box())
+				.fold((DVec2::INFINITY, DVec2::NEG_INFINITY), |(min, max), [a, b]| (min.min(a), max.max(b)));
+			(min + max) / 2.
+		};
+		let (this_center, other_center) = (center(&segments), center(&other_segments));
+		let candidates: Vec<Vec<Bezier>> = if closed {
+			(0..count).map(|start| other_segments[start..].iter().chain(&other_segments[..start]).copied().collect()).collect()
+		} else {
+			vec![other_segments.clone(), other_segments.iter().rev().map(|bezier| bezier.reverse()).collect()]
+		};
+		other_segments = candidates
+			.into_iter()
+			.min_by(|a, b| alignment_cost(&segments, a, this_center, other_center).total_cmp(&alignment_cost(&segments, b, this_center, other_center)))
+			.unwrap();
+
+		for (bezier, other) in segments.iter_mut().zip(&other_segments) {
+			*bezier = if bezier.handles == BezierHandles::Linear && other.handles == BezierHandles::Linear {
+				Bezier::from_linear_dvec2(bezier.start.lerp(other.start, t), bezier.end.lerp(other.end, t))
+			} else {
+				let (points, other_points) = (cubic_points(bezier), cubic_points(other));
+				let [start, handle_start, handle_end, end] = [0, 1, 2, 3].map(|index| points[index].lerp(other_points[index], t));
+				Bezier::from_cubic_dvec2(start, handle_start, handle_end, end)
+			};
+		}
+		Subpath::from_beziers(&segments, closed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
+	use crate::utils::f64_compare;
+	use crate::EmptyId;
+
+	#[test]
+	fn morph_closed_subpaths() {
+		let small = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let large = Subpath::<EmptyId>::new_rect(DVec2::splat(100.), DVec2::splat(300.));
+		assert!(f64_compare(small.morph(&large, 0.).area(), 100. * 100., 1e-6));
+		assert!(f64_compare(small.morph(&large, 0.5).area(), 150. * 150., 1e-6));
+		assert!(f64_compare(small.morph(&large, 1.).area(), 200. * 200., 1e-6));
+
+		// Subpaths winding in opposite directions, or starting from different corners, are lined up rather than turning inside out
+		let reversed = large.reverse();
+		assert!(f64_compare(small.morph(&reversed, 0.5).area(), 150. * 150., 1e-6));
+		let rotated = Subpath::<EmptyId>::from_anchors([DVec2::new(300., 300.), DVec2::new(100., 300.), DVec2::new(100., 100.), DVec2::new(300., 100.)], true);
+		assert!(f64_compare(small.morph(&rotated, 0.5).area(), 150. * 150., 1e-6));
+	}
+
+	#[test]
+	fn morph_different_anchor_counts() {
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let triangle = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(50., 100.)], true);
+
+		let start = square.morph(&triangle, 0.);
+		let end = square.morph(&triangle, 1.);
+		assert_eq!(start.len(), 4);
+		assert_eq!(end.len(), 4);
+		assert!(start.closed() && end.closed());
+		assert!(f64_compare(start.area(), 100. * 100., 1e-6));
+		assert!(f64_compare(end.area(), 100. * 100. / 2., 1e-6));
+		for anchor in end.anchors() {
+			assert!(triangle.distance_to_point(anchor, None).unwrap() < MAX_ABSOLUTE_DIFFERENCE);
+		}
+
+		// A single point grows into the other subpath
+		let point = Subpath::<EmptyId>::from_anchors([DVec2::splat(50.)], false);
+		let grown = point.morph(&square, 0.5);
+		assert!(grown.closed());
+		assert!(f64_compare(grown.area(), 50. * 50., 1e-6));
+	}
+
+	#[test]
+	fn morph_open_subpaths() {
+		// The line is followed in reverse so that the ends which are closest together meet, instead of crossing over
+		let line = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.)], false);
+		let reversed = Subpath::<EmptyId>::from_anchors([DVec2::new(100., 10.), DVec2::new(0., 10.)], false);
+		let halfway = line.morph(&reversed, 0.5);
+		assert!(!halfway.closed());
+		assert!(halfway.anchors()[0].abs_diff_eq(DVec2::new(0., 5.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(halfway.anchors()[1].abs_diff_eq(DVec2::new(100., 5.), MAX_ABSOLUTE_DIFFERENCE));
+	}
+}
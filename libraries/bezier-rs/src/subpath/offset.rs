@@ -0,0 +1,278 @@
+use super::fitting::is_straight_line;
+use super::*;
+use crate::consts::{MAX_ABSOLUTE_DIFFERENCE, MIN_SEPARATION_VALUE, OFFSET_INTERSECTION_ERROR, OFFSET_MIN_DISTANCE_FACTOR, STRICT_MAX_ABSOLUTE_DIFFERENCE};
+use crate::utils::{BooleanOperation, Cap, FillRule, Join, TValue};
+
+use glam::DVec2;
+
+/// Returns the points where the segments of an open subpath cross themselves or one another, as the index of a segment and the parametric `t`-value along it, sorted in order along the segments.
+/// Each crossing appears twice, once for each of the segments which meet there, except for the anchors shared by consecutive segments.
+fn crossings(segments: &[Bezier]) -> Vec<(usize, f64)> {
+	let mut crossings = Vec::new();
+	for (index, bezier) in segments.iter().enumerate() {
+		crossings.extend(bezier.self_intersections(Some(OFFSET_INTERSECTION_ERROR)).into_iter().flat_map(|[t1, t2]| [(index, t1), (index, t2)]));
+		for (other_index, other) in segments.iter().enumerate().skip(index + 1) {
+			let other_t_values = other.intersections(bezier, Some(OFFSET_INTERSECTION_ERROR), None);
+			for t in bezier.intersections(other, Some(OFFSET_INTERSECTION_ERROR), None) {
+				let point = bezier.evaluate(TValue::Parametric(t));
+				let distance_to = |other_t: f64| other.evaluate(TValue::Parametric(other_t)).distance(point);
+				let Some(other_t) = other_t_values.iter().copied().min_by(|a, b| distance_to(*a).total_cmp(&distance_to(*b))) else {
+					continue;
+				};
+
+				// Consecutive segments always meet at the anchor between them
+				if other_index == index + 1 && t > 1. - MIN_SEPARATION_VALUE && other_t < MIN_SEPARATION_VALUE {
+					continue;
+				}
+				crossings.push((index, t));
+				crossings.push((other_index, other_t));
+			}
+		}
+	}
+	crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+	crossings
+}
+
+/// Splits the segments at the crossings, returning the runs of curves between one crossing and the next.
+fn split_at_crossings(segments: &[Bezier], crossings: &[(usize, f64)]) -> Vec<Vec<Bezier>> {
+	let mut pieces = vec![Vec::new()];
+	let mut crossings = crossings.iter().peekable();
+	for (index, bezier) in segments.iter().enumerate() {
+		let mut start = 0.;
+		while let Some(&(_, t)) = crossings.next_if(|(crossing_index, _)| *crossing_index == index) {
+			if t - start > STRICT_MAX_ABSOLUTE_DIFFERENCE {
+				pieces.last_mut().unwrap().push(bezier.trim(TValue::Parametric(start), TValue::Parametric(t)));
+				start = t;
+			}
+			pieces.push(Vec::new());
+		}
+		if start == 0. {
+			pieces.last_mut().unwrap().push(*bezier);
+		} else if 1. - start > STRICT_MAX_ABSOLUTE_DIFFERENCE {
+			pieces.last_mut().unwrap().push(bezier.trim(TValue::Parametric(start), TValue::Parametric(1.)));
+		}
+	}
+	pieces.retain(|piece| !piece.is_empty());
+	pieces
+}
+
+/// Returns the point at the given fraction of the way along the run of curves.
+fn point_along(piece: &[Bezier], fraction: f64) -> DVec2 {
+	let lengths = piece.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>();
+	let mut remaining = lengths.iter().sum::<f64>() * fraction;
+	for (bezier, length) in piece.iter().zip(&lengths) {
+		if remaining <= *length && *length > 0. {
+			return bezier.evaluate(TValue::Euclidean(remaining / length));
+		}
+		remaining -= length;
+	}
+	piece[piece.len() - 1].end()
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns the subpaths found by offsetting the subpath by `distance`, without the loops a plain [Subpath::offset] leaves where the offset crosses itself, such as where the subpath bends more tightly than the `distance`.
+	/// Only the parts of the offset which stay the full `distance` away from the subpath are kept, which may leave several subpaths or none at all.
+	/// Unlike [Subpath::offset], inner corners whose offset segments cannot be clipped against each other are beveled rather than given the `join`, which would bulge out on the wrong side of the corner.
+	/// - For a closed subpath, a positive `distance` grows the area it encloses and a negative one shrinks it, no matter which way it winds. The result is the boundary of that area, winding in the same direction as the subpath, with the `join` drawn at each corner where it grows.
+	/// - For an open subpath, a positive `distance` offsets it in the same direction as its normals, as for [Subpath::offset].
+	pub fn offset_path(&self, distance: f64, join: Join) -> Vec<Subpath<ManipulatorGroupId>> {
+		// An offset of a single point is not defined
+		if distance == 0. || self.iter().all(|bezier| bezier.is_point()) {
+			return vec![self.clone()];
+		}
+
+		if self.closed {
+			let result = Subpath::offset_area(std::slice::from_ref(self), distance, join, FillRule::NonZero);
+			return if self.signed_area() < 0. { result.iter().map(Subpath::reverse).collect() } else { result };
+		}
+
+		// Straight segments whose handles lie on their anchors have no direction at their ends, which is needed to tell which way each corner turns
+		let straightened = self
+			.iter()
+			.map(|bezier| {
+				if is_straight_line(&bezier) {
+					Bezier::from_linear_dvec2(bezier.start(), bezier.end())
+				} else {
+					bezier
+				}
+			})
+			.collect::<Vec<_>>();
+		let offset = if straightened.len() == 1 {
+			straightened[0].offset(distance)
+		} else {
+			Subpath::<ManipulatorGroupId>::from_beziers(&straightened, false).offset_with_concave_join(distance, join, Join::Bevel)
+		};
+		let segments = offset.iter().collect::<Vec<_>>();
+		let pieces = split_at_crossings(&segments, &crossings(&segments));
+
+		// Join each piece which is kept onto the last if it continues on from where the last ended, which skips over any loops removed in between
+		let mut runs: Vec<Vec<Bezier>> = Vec::new();
+		for piece in pieces {
+			let far_enough = |fraction: f64| self.distance_to_point(point_along(&piece, fraction), None).unwrap_or_default() >= OFFSET_MIN_DISTANCE_FACTOR * distance.abs();
+			// Sampling a piece in several places stops a short join which cuts a corner from deciding whether the whole piece is kept
+			if [0.25, 0.5, 0.75].into_iter().filter(|&fraction| far_enough(fraction)).count() < 2 {
+				continue;
+			}
+			match runs.last_mut() {
+				Some(run) if run[run.len() - 1].end().abs_diff_eq(piece[0].start(), MAX_ABSOLUTE_DIFFERENCE) => run.extend(piece),
+				_ => runs.push(piece),
+			}
+		}
+		runs.iter().map(|run| Subpath::from_beziers(run, false)).collect()
+	}
+
+	/// Returns the closed subpaths bounding the area enclosed by the closed `subpaths` when filled with `fill_rule`, grown by a positive `distance` or shrunk by a negative one.
+	/// The area is offset as a whole, so its holes shrink as it grows and grow as it shrinks, and parts which come to overlap are merged into one.
+	/// As with [Subpath::boolean_operation], each resulting subpath winds such that the area is on its left, with the `join` drawn at each corner where the area grows.
+	pub fn offset_area(subpaths: &[Subpath<ManipulatorGroupId>], distance: f64, join: Join, fill_rule: FillRule) -> Vec<Subpath<ManipulatorGroupId>> {
+		// The area within `distance` of the boundary is the area covered by its stroke, which is added to or removed from the area it encloses
+		let stroke = if distance == 0. {
+			Vec::new()
+		} else {
+			Subpath::outline_stroke(subpaths, 2. * distance.abs(), join, Cap::Butt)
+		};
+		let operation = if distance < 0. { BooleanOperation::Difference } else { BooleanOperation::Union };
+		Subpath::boolean_operation(subpaths, &stroke, operation, fill_rule)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::{f64_compare, SubpathTValue};
+	use crate::EmptyId;
+
+	#[test]
+	fn inset_and_outset_closed_subpaths() {
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let reversed = square.reverse();
+		for square in [square, reversed] {
+			let outset = square.offset_path(10., Join::Miter(None));
+			assert_eq!(outset.len(), 1);
+			assert!(outset[0].closed());
+			assert!(f64_compare(outset[0].area(), 120. * 120., 1.));
+
+			let inset = square.offset_path(-10., Join::Miter(None));
+			assert_eq!(inset.len(), 1);
+			assert!(inset[0].closed());
+			assert!(f64_compare(inset[0].area(), 80. * 80., 1.));
+
+			// The result winds in the same direction as the subpath
+			assert_eq!(outset[0].signed_area().signum(), square.signed_area().signum());
+			assert_eq!(inset[0].signed_area().signum(), square.signed_area().signum());
+		}
+
+		// An inset of a circle by more than its radius leaves nothing
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-50.), DVec2::splat(50.));
+		assert!(circle.offset_path(-60., Join::Round).is_empty());
+	}
+
+	#[test]
+	fn offset_area_with_holes() {
+		// The hole of a ring, wound the other way to its outer boundary, shrinks as the ring grows and grows as it shrinks
+		let ring = [
+			Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.)),
+			Subpath::<EmptyId>::new_rect(DVec2::splat(30.), DVec2::splat(70.)).reverse(),
+		];
+		let areas = |subpaths: &[Subpath<EmptyId>]| {
+			let mut areas = subpaths.iter().map(Subpath::area).collect::<Vec<_>>();
+			areas.sort_by(f64::total_cmp);
+			areas
+		};
+		let outset = Subpath::offset_area(&ring, 10., Join::Miter(None), FillRule::NonZero);
+		let outset_areas = areas(&outset);
+		assert_eq!(outset_areas.len(), 2);
+		assert!(f64_compare(outset_areas[0], 20. * 20., 1.));
+		assert!(f64_compare(outset_areas[1], 120. * 120., 1.));
+		let inset = Subpath::offset_area(&ring, -10., Join::Miter(None), FillRule::NonZero);
+		let inset_areas = areas(&inset);
+		assert_eq!(inset_areas.len(), 2);
+		assert!(f64_compare(inset_areas[0], 60. * 60., 1.));
+		assert!(f64_compare(inset_areas[1], 80. * 80., 1.));
+
+		// An outset closing up the hole leaves only the outer boundary
+		assert_eq!(Subpath::offset_area(&ring, 25., Join::Miter(None), FillRule::NonZero).len(), 1);
+
+		// Neighbouring squares which overlap once grown are merged into one
+		let squares = [
+			Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(10.)),
+			Subpath::<EmptyId>::new_rect(DVec2::new(15., 0.), DVec2::new(25., 10.)),
+		];
+		let merged = Subpath::offset_area(&squares, 5., Join::Miter(None), FillRule::NonZero);
+		assert_eq!(merged.len(), 1);
+		assert!(f64_compare(merged[0].area(), 35. * 20., 1.));
+	}
+
+	#[test]
+	fn inset_splits_closed_subpath() {
+		// Two squares joined by a narrow neck, which an inset removes
+		let dumbbell = Subpath::<EmptyId>::from_anchors(
+			[
+				DVec2::new(0., 0.),
+				DVec2::new(100., 0.),
+				DVec2::new(100., 40.),
+				DVec2::new(150., 40.),
+				DVec2::new(150., 0.),
+				DVec2::new(250., 0.),
+				DVec2::new(250., 100.),
+				DVec2::new(150., 100.),
+				DVec2::new(150., 60.),
+				DVec2::new(100., 60.),
+				DVec2::new(100., 100.),
+				DVec2::new(0., 100.),
+			],
+			true,
+		);
+		let inset = dumbbell.offset_path(-15., Join::Miter(None));
+		assert_eq!(inset.len(), 2);
+		for subpath in &inset {
+			assert!(subpath.closed());
+			assert!(f64_compare(subpath.area(), 70. * 70., 1.));
+		}
+
+		// A round join keeps the inset of the concave corners where the neck meets each square the full distance away from them
+		let inset = dumbbell.offset_path(-15., Join::Round);
+		assert_eq!(inset.len(), 2);
+		for subpath in &inset {
+			assert!(subpath.area() > 70. * 70. && subpath.area() < 70. * 70. + 50.);
+		}
+	}
+
+	#[test]
+	fn offset_open_subpaths() {
+		// An open subpath without any loops is offset as a whole
+		let chevron = Subpath::<EmptyId>::from_anchors([DVec2::new(-100., 100.), DVec2::ZERO, DVec2::new(100., 100.)], false);
+		let offset = chevron.offset_path(10., Join::Miter(None));
+		assert_eq!(offset.len(), 1);
+		assert!(!offset[0].closed());
+		assert!(offset[0].anchors().iter().any(|anchor| anchor.abs_diff_eq(DVec2::new(0., 10. * 2_f64.sqrt()), MAX_ABSOLUTE_DIFFERENCE)));
+
+		// Crossings of the subpath with itself are kept where the offset stays far enough from it
+		let bowtie = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 100.), DVec2::new(100., 0.), DVec2::new(0., 100.)], false);
+		assert_eq!(bowtie.offset_path(5., Join::Round).len(), 1);
+
+		// Nothing is left of the inside of a hairpin narrower than the distance, but the outside is offset as a whole
+		let hairpin = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 10.), DVec2::new(0., 10.)], false);
+		assert!(hairpin.offset_path(20., Join::Round).is_empty());
+		let outside = hairpin.offset_path(-20., Join::Miter(None));
+		assert_eq!(outside.len(), 1);
+		let anchors = outside[0].anchors();
+		assert!(anchors[0].abs_diff_eq(DVec2::new(0., -20.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors.iter().any(|anchor| anchor.abs_diff_eq(DVec2::new(120., -20.), MAX_ABSOLUTE_DIFFERENCE)));
+		assert!(anchors[anchors.len() - 1].abs_diff_eq(DVec2::new(0., 30.), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn loops_of_open_subpaths() {
+		// The loop left where the curve bends more tightly than the distance is removed, leaving the offset shorter than the unclipped one
+		let bend = Subpath::<EmptyId>::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::new(100., 100.), DVec2::new(0., 100.), DVec2::new(100., 0.)));
+		let unclipped = bend.get_segment(0).unwrap().offset::<EmptyId>(-30.);
+		let offset = bend.offset_path(-30., Join::Round);
+		assert_eq!(offset.len(), 1);
+		assert!(offset[0].length(None) < unclipped.length(None) - 10.);
+		for index in 0..=20 {
+			let point = offset[0].evaluate(SubpathTValue::GlobalParametric(index as f64 / 20.));
+			assert!(bend.distance_to_point(point, None).unwrap() > 0.9 * 30.);
+		}
+	}
+}
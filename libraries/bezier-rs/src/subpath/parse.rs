@@ -0,0 +1,475 @@
+use super::{ManipulatorGroup, Subpath};
+use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
+
+use glam::DVec2;
+use std::fmt::{Display, Formatter};
+
+/// The reason why SVG path data could not be parsed, as part of a [SvgPathError].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SvgPathErrorKind {
+	/// The path data does not begin with a `M` or `m` command.
+	MissingMoveTo,
+	/// A character was found where a command or the argument of a command was expected.
+	UnexpectedCharacter(char),
+	/// A number was expected as the argument of a command.
+	ExpectedNumber,
+	/// A flag, `0` or `1`, was expected as the argument of an arc command.
+	ExpectedFlag,
+	/// A number was too large to be represented.
+	NumberOutOfRange,
+	/// The path data ended before all of the arguments of a command were given.
+	UnexpectedEnd,
+}
+
+/// Error returned by [Subpath::from_svg_path] when the path data is not valid.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SvgPathError {
+	pub kind: SvgPathErrorKind,
+	/// The byte offset into the path data at which the error was found.
+	pub position: usize,
+}
+
+impl Display for SvgPathError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self.kind {
+			SvgPathErrorKind::MissingMoveTo => write!(f, "path data must begin with a move-to command")?,
+			SvgPathErrorKind::UnexpectedCharacter(character) => write!(f, "unexpected character '{character}'")?,
+			SvgPathErrorKind::ExpectedNumber => write!(f, "expected a number")?,
+			SvgPathErrorKind::ExpectedFlag => write!(f, "expected a flag of 0 or 1")?,
+			SvgPathErrorKind::NumberOutOfRange => write!(f, "number is out of range")?,
+			SvgPathErrorKind::UnexpectedEnd => write!(f, "unexpected end of path data")?,
+		}
+		write!(f, " at position {}", self.position)
+	}
+}
+
+impl std::error::Error for SvgPathError {}
+
+/// Reads the numbers, flags and command letters of SVG path data, keeping track of the position in it.
+struct PathDataReader<'a> {
+	data: &'a [u8],
+	position: usize,
+}
+
+impl<'a> PathDataReader<'a> {
+	fn error(&self, kind: SvgPathErrorKind, position: usize) -> SvgPathError {
+		SvgPathError { kind, position }
+	}
+
+	/// Returns the error for an argument which was expected at the current position, but is missing.
+	fn missing_argument(&self, kind: SvgPathErrorKind) -> SvgPathError {
+		match self.data.get(self.position) {
+			None => self.error(SvgPathErrorKind::UnexpectedEnd, self.position),
+			Some(_) => self.error(kind, self.position),
+		}
+	}
+
+	fn peek(&self) -> Option<u8> {
+		self.data.get(self.position).copied()
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')) {
+			self.position += 1;
+		}
+	}
+
+	/// Skips the whitespace and the single optional comma which may separate two arguments.
+	fn skip_separator(&mut self) {
+		self.skip_whitespace();
+		if self.peek() == Some(b',') {
+			self.position += 1;
+			self.skip_whitespace();
+		}
+	}
+
+	/// Returns whether a number begins at the current position.
+	fn at_number(&self) -> bool {
+		matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+	}
+
+	fn skip_digits(&mut self) -> usize {
+		let start = self.position;
+		while matches!(self.peek(), Some(b'0'..=b'9')) {
+			self.position += 1;
+		}
+		self.position - start
+	}
+
+	/// Reads a number, which may directly follow the previous one if it can't be mistaken for its continuation, as in `0.5.5` or `1-2`.
+	fn number(&mut self) -> Result<f64, SvgPathError> {
+		self.skip_separator();
+		let start = self.position;
+		if matches!(self.peek(), Some(b'-' | b'+')) {
+			self.position += 1;
+		}
+		let mut digits = self.skip_digits();
+		if self.peek() == Some(b'.') {
+			self.position += 1;
+			digits += self.skip_digits();
+		}
+		if digits == 0 {
+			self.position = start;
+			return Err(self.missing_argument(SvgPathErrorKind::ExpectedNumber));
+		}
+
+		// The exponent is only part of the number if it has digits, since an `e` can't begin the next command
+		if matches!(self.peek(), Some(b'e' | b'E')) {
+			let mantissa_end = self.position;
+			self.position += 1;
+			if matches!(self.peek(), Some(b'-' | b'+')) {
+				self.position += 1;
+			}
+			if self.skip_digits() == 0 {
+				self.position = mantissa_end;
+			}
+		}
+
+		// The slice only contains ASCII digits, signs, a decimal point and an exponent, so it is valid UTF-8 and a valid float
+		let number = std::str::from_utf8(&self.data[start..self.position]).unwrap().parse::<f64>().unwrap();
+		if !number.is_finite() {
+			return Err(self.error(SvgPathErrorKind::NumberOutOfRange, start));
+		}
+		Ok(number)
+	}
+
+	fn point(&mut self) -> Result<DVec2, SvgPathError> {
+		Ok(DVec2::new(self.number()?, self.number()?))
+	}
+
+	/// Reads a flag of an arc command, which is a single digit that may be directly followed by the next argument, as in `a1 1 0 00 1 1`.
+	fn flag(&mut self) -> Result<bool, SvgPathError> {
+		self.skip_separator();
+		let flag = match self.peek() {
+			Some(b'0') => false,
+			Some(b'1') => true,
+			_ => return Err(self.missing_argument(SvgPathErrorKind::ExpectedFlag)),
+		};
+		self.position += 1;
+		Ok(flag)
+	}
+}
+
+/// Builds the subpaths described by path data, one segment at a time.
+struct SubpathBuilder<ManipulatorGroupId: crate::Identifier> {
+	subpaths: Vec<Subpath<ManipulatorGroupId>>,
+	manipulator_groups: Vec<ManipulatorGroup<ManipulatorGroupId>>,
+}
+
+impl<ManipulatorGroupId: crate::Identifier> SubpathBuilder<ManipulatorGroupId> {
+	/// Returns the end of the last segment, or the start of the last subpath once it has been closed.
+	fn current_point(&self) -> DVec2 {
+		match self.manipulator_groups.last() {
+			Some(group) => group.anchor,
+			None => self.subpaths.last().map_or(DVec2::ZERO, |subpath| subpath[0].anchor),
+		}
+	}
+
+	fn move_to(&mut self, point: DVec2) {
+		self.finish_subpath(false);
+		self.manipulator_groups.push(ManipulatorGroup::new(point, None, None));
+	}
+
+	/// Appends a segment with the given handles, where a single handle (on either side) makes a quadratic segment, from the current point to the `end`.
+	fn segment_to(&mut self, out_handle: Option<DVec2>, in_handle: Option<DVec2>, end: DVec2) {
+		// A drawing command which follows a closed subpath starts a new subpath at the same point
+		if self.manipulator_groups.is_empty() {
+			self.manipulator_groups.push(ManipulatorGroup::new(self.current_point(), None, None));
+		}
+		self.manipulator_groups.last_mut().unwrap().out_handle = out_handle;
+		self.manipulator_groups.push(ManipulatorGroup::new(end, in_handle, None));
+	}
+
+	/// Appends the segments of an elliptical arc, as described by the arguments of an SVG arc command, from the current point to the `end`. See [Subpath::new_svg_arc].
+	fn arc_to(&mut self, radii: DVec2, x_axis_rotation: f64, large_arc: bool, sweep: bool, end: DVec2) {
+		let arc = Subpath::<ManipulatorGroupId>::new_svg_arc(self.current_point(), end, radii, x_axis_rotation.to_radians(), large_arc, sweep, None);
+		for pair in arc.manipulator_groups.windows(2) {
+			self.segment_to(pair[0].out_handle, pair[1].in_handle, pair[1].anchor);
+		}
+	}
+
+	/// Ends the current subpath. A closed subpath which returns to its first anchor has its last anchor merged into the first.
+	fn finish_subpath(&mut self, closed: bool) {
+		if self.manipulator_groups.is_empty() {
+			return;
+		}
+		let mut manipulator_groups = std::mem::take(&mut self.manipulator_groups);
+
+		if closed
+			&& manipulator_groups.len() > 2
+			&& manipulator_groups[0]
+				.anchor
+				.abs_diff_eq(manipulator_groups[manipulator_groups.len() - 1].anchor, MAX_ABSOLUTE_DIFFERENCE)
+		{
+			let last = manipulator_groups.pop().unwrap();
+			manipulator_groups[0].in_handle = last.in_handle;
+		}
+
+		// A closed subpath needs more than one anchor, so a subpath closed at its only anchor is left open
+		let closed = closed && manipulator_groups.len() > 1;
+		self.subpaths.push(Subpath::new(manipulator_groups, closed));
+	}
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Parses SVG path data, the contents of the `d` attribute of a `path` element, into the subpaths it describes.
+	/// The full grammar of SVG path data is supported, including relative commands, implicitly repeated commands and their compact forms such as `M.5.5-1-2`.
+	/// Smooth curve commands reflect the handle of the previous curve, and elliptical arcs are approximated by cubic segments spanning up to a quarter turn each.
+	/// A closed subpath ending at its first anchor has its last anchor merged into the first, so the output of [Subpath::subpath_to_svg] is parsed back into the same subpath.
+	///
+	/// Returns a [SvgPathError] with the position of the first error if the path data is not valid.
+	pub fn from_svg_path(path_data: &str) -> Result<Vec<Subpath<ManipulatorGroupId>>, SvgPathError> {
+		let mut reader = PathDataReader {
+			data: path_data.as_bytes(),
+			position: 0,
+		};
+		let mut builder = SubpathBuilder {
+			subpaths: Vec::new(),
+			manipulator_groups: Vec::new(),
+		};
+		let mut command: Option<u8> = None;
+		// The handle of the previous segment which is reflected by a smooth curve command, if the previous segment was a curve of the same degree
+		let mut previous_cubic_handle: Option<DVec2> = None;
+		let mut previous_quadratic_handle: Option<DVec2> = None;
+
+		loop {
+			reader.skip_whitespace();
+			let Some(character) = reader.peek() else { break };
+
+			// A command letter may be omitted when it is repeated, except after closing a subpath, and an omitted move is repeated as a line
+			let command_position = reader.position;
+			if character.is_ascii_alphabetic() {
+				reader.position += 1;
+				command = Some(character);
+			} else if reader.at_number() && !matches!(command, None | Some(b'Z' | b'z')) {
+				command = command.map(|command| match command {
+					b'M' => b'L',
+					b'm' => b'l',
+					command => command,
+				});
+			} else if command.is_none() {
+				return Err(reader.error(SvgPathErrorKind::MissingMoveTo, command_position));
+			} else {
+				return Err(reader.error(SvgPathErrorKind::UnexpectedCharacter(path_data[command_position..].chars().next().unwrap()), command_position));
+			}
+			let command = command.unwrap();
+			if builder.subpaths.is_empty() && builder.manipulator_groups.is_empty() && !matches!(command, b'M' | b'm') {
+				return Err(reader.error(SvgPathErrorKind::MissingMoveTo, command_position));
+			}
+
+			let current = builder.current_point();
+			let origin = if command.is_ascii_lowercase() { current } else { DVec2::ZERO };
+			let absolute_command = command.to_ascii_uppercase();
+			let (mut cubic_handle, mut quadratic_handle) = (None, None);
+			match absolute_command {
+				b'M' => builder.move_to(origin + reader.point()?),
+				b'L' => builder.segment_to(None, None, origin + reader.point()?),
+				b'H' => builder.segment_to(None, None, DVec2::new(origin.x + reader.number()?, current.y)),
+				b'V' => builder.segment_to(None, None, DVec2::new(current.x, origin.y + reader.number()?)),
+				b'C' | b'S' => {
+					let handle_start = if absolute_command == b'C' {
+						origin + reader.point()?
+					} else {
+						previous_cubic_handle.map_or(current, |handle| 2. * current - handle)
+					};
+					let handle_end = origin + reader.point()?;
+					builder.segment_to(Some(handle_start), Some(handle_end), origin + reader.point()?);
+					cubic_handle = Some(handle_end);
+				}
+				b'Q' | b'T' => {
+					let handle = if absolute_command == b'Q' {
+						origin + reader.point()?
+					} else {
+						previous_quadratic_handle.map_or(current, |handle| 2. * current - handle)
+					};
+					builder.segment_to(Some(handle), None, origin + reader.point()?);
+					quadratic_handle = Some(handle);
+				}
+				b'A' => {
+					let radii = reader.point()?;
+					let x_axis_rotation = reader.number()?;
+					let large_arc = reader.flag()?;
+					let sweep = reader.flag()?;
+					builder.arc_to(radii, x_axis_rotation, large_arc, sweep, origin + reader.point()?);
+				}
+				b'Z' => {
+					builder.finish_subpath(true);
+				}
+				_ => return Err(reader.error(SvgPathErrorKind::UnexpectedCharacter(character as char), command_position)),
+			}
+			previous_cubic_handle = cubic_handle;
+			previous_quadratic_handle = quadratic_handle;
+
+			if absolute_command != b'Z' {
+				reader.skip_separator();
+			}
+		}
+
+		builder.finish_subpath(false);
+		Ok(builder.subpaths)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::compare::{compare_subpaths, compare_vec_of_points};
+	use crate::utils::TValue;
+	use crate::{Bezier, EmptyId};
+
+	use glam::DAffine2;
+
+	fn parse(path_data: &str) -> Vec<Subpath<EmptyId>> {
+		Subpath::from_svg_path(path_data).unwrap()
+	}
+
+	fn error(path_data: &str) -> SvgPathError {
+		Subpath::<EmptyId>::from_svg_path(path_data).unwrap_err()
+	}
+
+	#[test]
+	fn lines() {
+		let expected = Subpath::<EmptyId>::new(
+			[DVec2::new(10., 20.), DVec2::new(30., 20.), DVec2::new(30., 50.), DVec2::new(0., 50.), DVec2::new(-5., 45.)]
+				.into_iter()
+				.map(|anchor| ManipulatorGroup::new(anchor, None, None))
+				.collect(),
+			false,
+		);
+
+		// Absolute and relative commands, the horizontal and vertical forms, and repeated commands with their letters omitted all describe the same lines
+		for path_data in [
+			"M10 20 L30 20 L30 50 L0 50 L-5 45",
+			"m10,20 20,0 v30 h-30 l-5-5",
+			"M 10 20 H 30 V 50 H 0 L -5 45",
+			"M10 20 30 20 30 50 0 50 -5 45",
+		] {
+			let subpaths = parse(path_data);
+			assert_eq!(subpaths.len(), 1);
+			assert!(compare_subpaths(&subpaths[0], &expected), "{path_data}");
+		}
+
+		assert!(parse("").is_empty());
+		assert!(parse(" \n").is_empty());
+	}
+
+	#[test]
+	fn compact_numbers() {
+		let subpaths = parse("M.5.5-1e1-2,3E-1+4");
+		assert!(compare_vec_of_points(
+			subpaths[0].anchors(),
+			vec![DVec2::new(0.5, 0.5), DVec2::new(-10., -2.), DVec2::new(0.3, 4.)],
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+	}
+
+	#[test]
+	fn curves() {
+		let subpaths = parse("M0 0 C10 0 20 10 20 20 S30 40 40 40 Q50 40 50 30 T60 10 t10 0");
+		let beziers = subpaths[0].iter().collect::<Vec<_>>();
+		assert_eq!(beziers.len(), 5);
+		assert_eq!(beziers[0], Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(20., 10.), DVec2::new(20., 20.)));
+
+		// Smooth curves reflect the handle of the previous curve of the same degree
+		assert_eq!(
+			beziers[1],
+			Bezier::from_cubic_dvec2(DVec2::new(20., 20.), DVec2::new(20., 30.), DVec2::new(30., 40.), DVec2::new(40., 40.))
+		);
+		assert_eq!(beziers[2], Bezier::from_quadratic_dvec2(DVec2::new(40., 40.), DVec2::new(50., 40.), DVec2::new(50., 30.)));
+		assert_eq!(beziers[3], Bezier::from_quadratic_dvec2(DVec2::new(50., 30.), DVec2::new(50., 20.), DVec2::new(60., 10.)));
+		assert_eq!(beziers[4], Bezier::from_quadratic_dvec2(DVec2::new(60., 10.), DVec2::new(70., 0.), DVec2::new(70., 10.)));
+
+		// Without a previous curve of the same degree, the reflected handle is the current point
+		let beziers = parse("M0 0 L10 0 S20 10 30 10").into_iter().flat_map(|subpath| subpath.iter().collect::<Vec<_>>()).collect::<Vec<_>>();
+		assert_eq!(
+			beziers[1],
+			Bezier::from_cubic_dvec2(DVec2::new(10., 0.), DVec2::new(10., 0.), DVec2::new(20., 10.), DVec2::new(30., 10.))
+		);
+	}
+
+	#[test]
+	fn arcs() {
+		// A half circle, sweeping in the positive angle direction from its start at the angle π
+		let subpath = &parse("M0 0 A50 50 0 0 1 100 0")[0];
+		assert_eq!(subpath.len_segments(), 2);
+		for bezier in subpath.iter() {
+			for t in [0., 0.25, 0.5, 0.75, 1.] {
+				assert!((bezier.evaluate(TValue::Parametric(t)).distance(DVec2::new(50., 0.)) - 50.).abs() < 0.05);
+			}
+		}
+		assert!(subpath[1].anchor.abs_diff_eq(DVec2::new(50., -50.), MAX_ABSOLUTE_DIFFERENCE));
+
+		// Radii which are too small are scaled up, so this is the same half circle
+		assert!(compare_subpaths(&parse("M0 0 A1 1 0 0 1 100 0")[0], subpath));
+
+		// The half of an ellipse, rotated to have its major axis along the y-axis, on either side depending on the sweep direction
+		for (sweep, middle) in [(0, DVec2::new(-25., 50.)), (1, DVec2::new(25., 50.))] {
+			let subpath = &parse(&format!("M0 0 a50 25 90 0 {sweep} 0 100"))[0];
+			assert_eq!(subpath.len_segments(), 2);
+			assert!(subpath[1].anchor.abs_diff_eq(middle, MAX_ABSOLUTE_DIFFERENCE));
+			assert!(subpath[2].anchor.abs_diff_eq(DVec2::new(0., 100.), MAX_ABSOLUTE_DIFFERENCE));
+		}
+
+		// The large arc goes the long way around the circle
+		assert_eq!(parse("M0 0 A50 50 0 0 1 50 50")[0].len_segments(), 1);
+		assert_eq!(parse("M0 0 A50 50 0 1 1 50 50")[0].len_segments(), 3);
+
+		// Flags may be written without separators, an arc with a zero radius is a line, and an arc to its own start is omitted
+		let subpath = &parse("M0 0a1 1 0 00 1 1a0 5 0 0 0 10 0A5 5 0 0 0 11 1")[0];
+		assert_eq!(subpath.len(), 3);
+		assert_eq!(subpath.get_segment(1), Some(Bezier::from_linear_dvec2(DVec2::new(1., 1.), DVec2::new(11., 1.))));
+	}
+
+	#[test]
+	fn closed_subpaths() {
+		let subpaths = parse("M0 0 L10 0 L10 10 Z L-10 0 Z m5 5 h1 v1 L5 5 z");
+		assert_eq!(subpaths.len(), 3);
+		assert!(subpaths.iter().all(|subpath| subpath.closed()));
+		assert_eq!(subpaths[0].anchors(), vec![DVec2::ZERO, DVec2::new(10., 0.), DVec2::splat(10.)]);
+
+		// A subpath drawn after closing one starts at its first anchor, as does a relative move
+		assert_eq!(subpaths[1].anchors(), vec![DVec2::ZERO, DVec2::new(-10., 0.)]);
+		// The last anchor is merged into the first when it returns to it
+		assert_eq!(subpaths[2].anchors(), vec![DVec2::splat(5.), DVec2::new(6., 5.), DVec2::splat(6.)]);
+
+		// A subpath closed at its only anchor is left open
+		let subpaths = parse("M1 1 Z M2 2");
+		assert_eq!(subpaths.len(), 2);
+		assert!(!subpaths[0].closed());
+	}
+
+	#[test]
+	fn round_trip() {
+		let mut ellipse = Subpath::<EmptyId>::new_ellipse(DVec2::new(-10.5, 3.), DVec2::new(20., 40.25));
+		let mut open = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(1e-7, 30.), DVec2::new(-20., 1e6)], false);
+		open[1].in_handle = None;
+		ellipse[2].out_handle = None;
+
+		let mut svg = String::new();
+		for subpath in [&ellipse, &open] {
+			subpath.subpath_to_svg(&mut svg, DAffine2::IDENTITY).unwrap();
+		}
+		let subpaths = parse(&svg);
+		assert_eq!(subpaths.len(), 2);
+		assert!(compare_subpaths(&subpaths[0], &ellipse));
+		assert!(compare_subpaths(&subpaths[1], &open));
+	}
+
+	#[test]
+	fn errors() {
+		let expect = |path_data, kind, position| assert_eq!(error(path_data), SvgPathError { kind, position }, "{path_data}");
+
+		expect("L10 10", SvgPathErrorKind::MissingMoveTo, 0);
+		expect("  10 10", SvgPathErrorKind::MissingMoveTo, 2);
+		expect("M0 0 L10", SvgPathErrorKind::UnexpectedEnd, 8);
+		expect("M0 0 L10,", SvgPathErrorKind::UnexpectedEnd, 9);
+		expect("M0 0 L10 x", SvgPathErrorKind::ExpectedNumber, 9);
+		expect("M0 0 L10 10 X5 5", SvgPathErrorKind::UnexpectedCharacter('X'), 12);
+		expect("M0 0 L10 10 é", SvgPathErrorKind::UnexpectedCharacter('é'), 12);
+		expect("M0 0 Z 5 5", SvgPathErrorKind::UnexpectedCharacter('5'), 7);
+		expect("M0 0 L1e400 0", SvgPathErrorKind::NumberOutOfRange, 6);
+		expect("M0 0 A1 1 0 2 0 5 5", SvgPathErrorKind::ExpectedFlag, 12);
+		expect("M0 0 L1,,2", SvgPathErrorKind::ExpectedNumber, 8);
+		assert_eq!(error("M0 0 L10").to_string(), "unexpected end of path data at position 8");
+	}
+}
@@ -137,6 +137,17 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		self.iter().map(|bezier| bezier.winding(target_point)).sum::<i32>() != 0
 	}
 
+	/// Returns the number of times the subpath winds counterclockwise around a point, with +y pointing up, as for [Bezier::winding].
+	/// An open subpath is treated as if it were closed by a straight segment from its last anchor back to its first, as it is when filled.
+	/// The windings of several subpaths around a point can be summed and passed to [crate::FillRule::is_inside] to find whether the point is filled.
+	pub fn winding(&self, target_point: DVec2) -> i32 {
+		let closing_segment = match (self.closed, self.manipulator_groups.first(), self.manipulator_groups.last()) {
+			(false, Some(first), Some(last)) => Some(Bezier::from_linear_dvec2(last.anchor, first.anchor)),
+			_ => None,
+		};
+		self.iter().chain(closing_segment).map(|bezier| bezier.winding(target_point)).sum()
+	}
+
 	/// Returns the manipulator point that is needed for a miter join if it is possible.
 	/// - `miter_limit`: Defines a limit for the ratio between the miter length and the stroke width.
 	/// Alternatively, this can be interpreted as limiting the angle that the miter can form.
@@ -706,4 +717,25 @@ mod tests {
 		assert!((round_point - middle).angle_between(round_start - middle) < 0.);
 		assert!((round_end - middle).angle_between(round_point - middle) < 0.);
 	}
+
+	#[test]
+	fn winding() {
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let hole = Subpath::<EmptyId>::new_rect(DVec2::splat(25.), DVec2::splat(75.));
+		let winding = |point| square.winding(point) + hole.winding(point);
+		assert_eq!(winding(DVec2::splat(10.)).abs(), 1);
+		assert_eq!(winding(DVec2::splat(50.)).abs(), 2);
+		assert_eq!(winding(DVec2::splat(150.)), 0);
+		assert!(utils::FillRule::NonZero.is_inside(winding(DVec2::splat(50.))));
+		assert!(!utils::FillRule::EvenOdd.is_inside(winding(DVec2::splat(50.))));
+
+		// The hole winding the other way round is left unfilled under either rule
+		let reversed_hole = hole.reverse();
+		assert_eq!(square.winding(DVec2::splat(50.)) + reversed_hole.winding(DVec2::splat(50.)), 0);
+
+		// An open subpath is filled as if it were closed
+		let open = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(0., 100.)], false);
+		assert_eq!(open.winding(DVec2::splat(20.)).abs(), 1);
+		assert_eq!(open.winding(DVec2::splat(80.)), 0);
+	}
 }
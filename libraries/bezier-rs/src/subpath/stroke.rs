@@ -0,0 +1,390 @@
+use super::fitting::{is_straight_line, start_direction};
+use super::*;
+use crate::consts::{MAX_ABSOLUTE_DIFFERENCE, STRICT_MAX_ABSOLUTE_DIFFERENCE, STROKE_SAMPLES_PER_SEGMENT, STROKE_TOLERANCE};
+use crate::utils::{BooleanOperation, Cap, FillRule, Join, TValue};
+use crate::BezierHandles;
+
+use glam::DVec2;
+
+/// Functionality for converting the stroke drawn along subpaths into the area it covers.
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Returns closed subpaths enclosing the area painted by a stroke of the given `width` along the `subpaths`, in the same way as an SVG renderer draws a stroke.
+	/// Unlike [Subpath::outline], the area is found as the union of the area covered by each segment, join and cap, so it is also correct for strokes which overlap
+	/// themselves, curves bending more tightly than the stroke is wide, and cusps. As with [Subpath::boolean_operation], each resulting subpath winds such that
+	/// the stroked area is on its left, so the result may be filled with either fill rule.
+	/// - `width` - The width of the stroke, which extends half of this distance to either side of the subpaths.
+	/// - `join` - The join drawn at each corner between segments. A miter join exceeding its limit falls back to a bevel join, and cusps within a segment are always rounded.
+	/// - `cap` - The cap drawn at both ends of each open subpath. Open subpaths of zero length are drawn as a dot if the cap is round or square.
+	pub fn outline_stroke(subpaths: &[Subpath<ManipulatorGroupId>], width: f64, join: Join, cap: Cap) -> Vec<Subpath<ManipulatorGroupId>> {
+		let distance = width / 2.;
+		if distance.is_nan() || distance <= 0. {
+			return Vec::new();
+		}
+
+		let mut pieces = Vec::new();
+		for subpath in subpaths {
+			subpath.stroke_pieces(&vec![distance; subpath.len()], join, cap, &mut pieces);
+		}
+		Subpath::boolean_operation(&pieces, &[], BooleanOperation::Union, FillRule::NonZero)
+	}
+
+	/// Returns closed subpaths enclosing the area painted by a stroke along the subpath whose width changes from one anchor to the next, such as for a brush stroke which tapers at its ends.
+	/// The outline is found in the same way as [Subpath::outline_stroke], with the same guarantees for strokes which overlap themselves, bend tightly or contain cusps.
+	/// - `widths` - The width of the stroke at each manipulator group, which changes gradually along each segment between them. Missing widths repeat the last one, and negative widths are treated as zero.
+	/// - `join` - The join drawn at each corner between segments, at the width of the stroke at that corner.
+	/// - `cap` - The cap drawn at both ends of an open subpath, at the width of the stroke at that end.
+	pub fn outline_variable_stroke(&self, widths: &[f64], join: Join, cap: Cap) -> Vec<Subpath<ManipulatorGroupId>> {
+		let Some(&last_width) = widths.last() else {
+			return Vec::new();
+		};
+		let distances = (0..self.len())
+			.map(|index| widths.get(index).copied().unwrap_or(last_width) / 2.)
+			.map(|distance| if distance.is_nan() { 0. } else { distance.max(0.) })
+			.collect::<Vec<_>>();
+		if distances.iter().all(|&distance| distance == 0.) {
+			return Vec::new();
+		}
+
+		let mut pieces = Vec::new();
+		self.stroke_pieces(&distances, join, cap, &mut pieces);
+		Subpath::boolean_operation(&pieces, &[], BooleanOperation::Union, FillRule::NonZero)
+	}
+
+	/// Appends closed subpaths which together cover the stroke of this subpath, extending the distance given for each manipulator group to either side of it.
+	/// The distance changes gradually along each segment, in proportion to the length along it, from the distance at its start to the distance at its end.
+	/// Each piece winds counterclockwise with +y pointing up around the area it covers, so that overlapping pieces never cancel each other out when filled with the nonzero rule.
+	fn stroke_pieces(&self, distances: &[f64], join: Join, cap: Cap, pieces: &mut Vec<Subpath<ManipulatorGroupId>>) {
+		let len = self.len();
+		let segments = self
+			.iter()
+			.enumerate()
+			.filter(|(_, bezier)| !bezier.is_point())
+			.map(|(index, bezier)| (index, split_at_extrema(bezier)))
+			.filter(|(_, segment)| !segment.is_empty())
+			.collect::<Vec<_>>();
+
+		// A subpath of zero length is drawn as a dot, with any square cap aligned to the x-axis
+		if segments.is_empty() {
+			if self.len_segments() > 0 && !self.closed {
+				let (point, distance) = (self[0].anchor, distances[0]);
+				match cap {
+					Cap::Butt => {}
+					Cap::Round => push_piece(pieces, disc(point, distance)),
+					Cap::Square => push_piece(
+						pieces,
+						polygon(&[point - distance, point + DVec2::new(distance, -distance), point + distance, point + DVec2::new(-distance, distance)]),
+					),
+				}
+			}
+			return;
+		}
+
+		for (index, pieces_of_segment) in &segments {
+			// The distance at the end of each piece is found from the fraction of the length of the segment up to there
+			let (start_distance, end_distance) = (distances[*index], distances[(index + 1) % len]);
+			let lengths = pieces_of_segment.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>();
+			let total_length = lengths.iter().sum::<f64>();
+			let mut piece_distances = Vec::with_capacity(lengths.len() + 1);
+			let mut length_so_far = 0.;
+			piece_distances.push(start_distance);
+			for length in &lengths {
+				length_so_far += length;
+				let fraction = if total_length > 0. { length_so_far / total_length } else { 1. };
+				piece_distances.push(start_distance + (end_distance - start_distance) * fraction);
+			}
+
+			for (bezier, distances) in pieces_of_segment.iter().zip(piece_distances.windows(2)) {
+				if distances[0] == 0. && distances[1] == 0. {
+					continue;
+				}
+				let sleeve = sleeve(bezier, distances[0], distances[1]);
+				// A curve which bends more tightly than the stroke is wide folds its sleeve over itself, so it is first resolved into the area it covers
+				if bezier.handles == BezierHandles::Linear {
+					push_piece(pieces, sleeve);
+				} else {
+					pieces.extend(Subpath::boolean_operation(&[sleeve], &[], BooleanOperation::Union, FillRule::NonZero));
+				}
+			}
+
+			// The curve turns around at a cusp within the segment, which is rounded off
+			for (pair, &distance) in pieces_of_segment.windows(2).zip(&piece_distances[1..]) {
+				if !end_direction(&pair[0]).abs_diff_eq(start_direction(&pair[1]).unwrap_or_default(), MAX_ABSOLUTE_DIFFERENCE) {
+					push_piece(pieces, disc(pair[0].end(), distance));
+				}
+			}
+		}
+
+		// Join each segment to the next, including the last segment to the first if the subpath is closed
+		let joins = if self.closed { segments.len() } else { segments.len() - 1 };
+		for index in 0..joins {
+			let (incoming_index, incoming) = &segments[index];
+			let outgoing = segments[(index + 1) % segments.len()].1.first().unwrap();
+			let incoming = incoming.last().unwrap();
+			let distance = distances[(incoming_index + 1) % len];
+			add_join(pieces, incoming.end(), end_direction(incoming), start_direction(outgoing).unwrap_or_default(), distance, join);
+		}
+
+		if !self.closed {
+			let (first_index, first) = (segments[0].0, segments[0].1[0]);
+			let (last_index, last) = (segments[segments.len() - 1].0, segments[segments.len() - 1].1.last().unwrap());
+			add_cap(pieces, first.start(), -start_direction(&first).unwrap_or_default(), distances[first_index], cap);
+			add_cap(pieces, last.end(), end_direction(last), distances[(last_index + 1) % len], cap);
+		}
+	}
+}
+
+/// Splits the bezier at the extrema of each of its dimensions, so that no piece turns by more than a right angle.
+/// This also splits the bezier at any cusp, where it turns around in both dimensions at once.
+fn split_at_extrema(bezier: Bezier) -> Vec<Bezier> {
+	if is_straight_line(&bezier) {
+		return vec![Bezier::from_linear_dvec2(bezier.start(), bezier.end())];
+	}
+
+	bezier
+		.get_extrema_t_list()
+		.windows(2)
+		.map(|window| {
+			let [t1, t2] = [window[0], window[1]];
+			let piece = if t2 < 1. { bezier.split(TValue::Parametric(t2))[0] } else { bezier };
+			if t1 > 0. {
+				piece.split(TValue::Parametric(t1 / t2))[1]
+			} else {
+				piece
+			}
+		})
+		.filter(|piece| !piece.is_point())
+		.collect()
+}
+
+/// Returns the direction in which the bezier arrives at its end, ignoring any handles which coincide with the end.
+fn end_direction(bezier: &Bezier) -> DVec2 {
+	-start_direction(&bezier.reverse()).unwrap_or_default()
+}
+
+/// Returns the closed subpath around the area covered by sweeping a line, held perpendicular to the bezier and centered on it, along the bezier.
+/// The line extends `start_distance` to either side at the start of the bezier, changing gradually to `end_distance` at its end.
+/// Each side is fitted to points sampled at exactly that distance from the bezier, so it stays accurate where the bezier bends tightly compared to the distance.
+fn sleeve<ManipulatorGroupId: crate::Identifier>(bezier: &Bezier, start_distance: f64, end_distance: f64) -> Subpath<ManipulatorGroupId> {
+	let (start_normal, end_normal) = (start_direction(bezier).unwrap_or_default().perp(), end_direction(bezier).perp());
+	if bezier.handles == BezierHandles::Linear {
+		return polygon(&[
+			bezier.start() + start_normal * start_distance,
+			bezier.end() + end_normal * end_distance,
+			bezier.end() - end_normal * end_distance,
+			bezier.start() - start_normal * start_distance,
+		]);
+	}
+
+	// The normals at the ends are found from the directions of the bezier, since its derivative vanishes at a cusp
+	let samples = (0..=STROKE_SAMPLES_PER_SEGMENT)
+		.map(|step| {
+			let t = step as f64 / STROKE_SAMPLES_PER_SEGMENT as f64;
+			let normal = match step {
+				0 => start_normal,
+				STROKE_SAMPLES_PER_SEGMENT => end_normal,
+				_ => bezier.normal(TValue::Parametric(t)),
+			};
+			(bezier.evaluate(TValue::Parametric(t)), normal * (start_distance + (end_distance - start_distance) * t))
+		})
+		.collect::<Vec<_>>();
+	let left = samples.iter().map(|&(point, offset)| point + offset).collect::<Vec<_>>();
+	let right = samples.iter().rev().map(|&(point, offset)| point - offset).collect::<Vec<_>>();
+	Subpath::fit_points(&left, STROKE_TOLERANCE, None).combine_outline(&Subpath::fit_points(&right, STROKE_TOLERANCE, None), Cap::Butt)
+}
+
+/// Appends the piece covering the outer side of the corner where a segment arriving in the `incoming` direction meets a segment leaving in the `outgoing` direction.
+fn add_join<ManipulatorGroupId: crate::Identifier>(pieces: &mut Vec<Subpath<ManipulatorGroupId>>, point: DVec2, incoming: DVec2, outgoing: DVec2, distance: f64, join: Join) {
+	let (cross, dot) = (incoming.perp_dot(outgoing), incoming.dot(outgoing));
+
+	// Segments which continue in the same direction need no join
+	if cross.abs() < STRICT_MAX_ABSOLUTE_DIFFERENCE && dot > 0. {
+		return;
+	}
+
+	let side = if cross > 0. { -distance } else { distance };
+	let start = point + incoming.perp() * side;
+	let end = point + outgoing.perp() * side;
+	match join {
+		Join::Bevel => push_piece(pieces, polygon(&[point, start, end])),
+		Join::Miter(miter_limit) => {
+			// The miter limit is the ratio of the miter length to the stroke width above which the join falls back to a bevel, defaulting to 4 as for the `outline` function
+			let miter_limit = match miter_limit {
+				Some(miter_limit) if miter_limit >= 1. => miter_limit,
+				_ => 4.,
+			};
+			// This ratio is `1 / cos(θ / 2)` for a turn by the angle `θ`, where `cos(θ / 2)` is found from `cos(θ)` with the half-angle formula
+			let miter_ratio = 1. / ((1. + dot) / 2.).sqrt();
+			if miter_ratio <= miter_limit {
+				let miter = point + (start + end - 2. * point).normalize() * distance * miter_ratio;
+				push_piece(pieces, polygon(&[point, start, miter, end]));
+			} else {
+				push_piece(pieces, polygon(&[point, start, end]));
+			}
+		}
+		Join::Round => push_piece(pieces, disc(point, distance)),
+	}
+}
+
+/// Appends the cap at the end of an open subpath, which extends in the `direction` pointing away from the subpath.
+fn add_cap<ManipulatorGroupId: crate::Identifier>(pieces: &mut Vec<Subpath<ManipulatorGroupId>>, point: DVec2, direction: DVec2, distance: f64, cap: Cap) {
+	let normal = direction.perp() * distance;
+	match cap {
+		Cap::Butt => {}
+		Cap::Round => push_piece(pieces, disc(point, distance)),
+		Cap::Square => push_piece(
+			pieces,
+			polygon(&[point + normal, point + normal + direction * distance, point - normal + direction * distance, point - normal]),
+		),
+	}
+}
+
+/// Returns a closed subpath made of straight segments between the points.
+fn polygon<ManipulatorGroupId: crate::Identifier>(points: &[DVec2]) -> Subpath<ManipulatorGroupId> {
+	Subpath::new(points.iter().map(|&point| ManipulatorGroup::new(point, None, None)).collect(), true)
+}
+
+/// Returns a circle with the given center and radius.
+fn disc<ManipulatorGroupId: crate::Identifier>(center: DVec2, radius: f64) -> Subpath<ManipulatorGroupId> {
+	Subpath::new_ellipse(center - radius, center + radius)
+}
+
+/// Appends the piece so that it winds counterclockwise with +y pointing up, dropping it if it encloses no area.
+fn push_piece<ManipulatorGroupId: crate::Identifier>(pieces: &mut Vec<Subpath<ManipulatorGroupId>>, piece: Subpath<ManipulatorGroupId>) {
+	let signed_area = piece.signed_area();
+	if signed_area > STRICT_MAX_ABSOLUTE_DIFFERENCE {
+		pieces.push(piece);
+	} else if signed_area < -STRICT_MAX_ABSOLUTE_DIFFERENCE {
+		pieces.push(piece.reverse());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::f64_compare;
+
+	use std::f64::consts::PI;
+
+	/// Returns the total area enclosed by the outline, with holes subtracted.
+	fn outline_area(outline: &[Subpath<EmptyId>]) -> f64 {
+		outline.iter().map(|subpath| subpath.signed_area()).sum()
+	}
+
+	/// Returns whether the point lies within the area enclosed by the outline.
+	fn outline_contains(outline: &[Subpath<EmptyId>], point: DVec2) -> bool {
+		outline.iter().flat_map(|subpath| subpath.iter()).map(|bezier| bezier.winding(point)).sum::<i32>() != 0
+	}
+
+	#[test]
+	fn caps_of_open_subpath() {
+		let line = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.)], false);
+		let area = |cap| outline_area(&Subpath::outline_stroke(std::slice::from_ref(&line), 10., Join::Miter(None), cap));
+
+		assert!(f64_compare(area(Cap::Butt), 1000., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(area(Cap::Square), 1100., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(area(Cap::Round), 1000. + 25. * PI, 0.1));
+
+		// A subpath of zero length is drawn as a dot, unless its caps are butt caps
+		let dot = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::ZERO], false);
+		assert!(Subpath::outline_stroke(std::slice::from_ref(&dot), 10., Join::Miter(None), Cap::Butt).is_empty());
+		assert!(f64_compare(
+			outline_area(&Subpath::outline_stroke(&[dot], 10., Join::Miter(None), Cap::Square)),
+			100.,
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+	}
+
+	#[test]
+	fn joins_of_closed_subpath() {
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let outline = |join| Subpath::outline_stroke(std::slice::from_ref(&square), 10., join, Cap::Butt);
+
+		// The stroke of a closed subpath has no caps, and encloses a hole
+		let mitered = outline(Join::Miter(None));
+		assert_eq!(mitered.len(), 2);
+		assert!(f64_compare(outline_area(&mitered), 110. * 110. - 90. * 90., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(outline_contains(&mitered, DVec2::splat(-4.)));
+		assert!(!outline_contains(&mitered, DVec2::splat(50.)));
+
+		// Each bevel cuts off half of the 5 by 5 square at the outer corner
+		assert!(f64_compare(outline_area(&outline(Join::Bevel)), 4000. - 4. * 12.5, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(outline_area(&outline(Join::Round)), 4000. - 4. * (25. - 25. * PI / 4.), 0.1));
+	}
+
+	#[test]
+	fn miter_limit() {
+		let corner = Subpath::<EmptyId>::from_anchors([DVec2::new(-100., 10.), DVec2::ZERO, DVec2::new(-100., -10.)], false);
+		let area = |join| outline_area(&Subpath::outline_stroke(std::slice::from_ref(&corner), 2., join, Cap::Butt));
+
+		// The corner is sharp enough for the miter to exceed the default limit, so it falls back to a bevel unless the limit is raised
+		assert!(f64_compare(area(Join::Miter(None)), area(Join::Bevel), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(area(Join::Miter(Some(20.))) > area(Join::Bevel) + 1.);
+	}
+
+	#[test]
+	fn curves_tighter_than_stroke() {
+		// The stroke is wider than the circle, so it covers the whole disc without leaving a hole in the middle
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-5.), DVec2::splat(5.));
+		let outline = Subpath::outline_stroke(&[circle], 20., Join::Miter(None), Cap::Butt);
+		assert!(outline_contains(&outline, DVec2::ZERO));
+		assert!(f64_compare(outline_area(&outline), 225. * PI, 1.));
+
+		// A wide circle leaves a ring
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-50.), DVec2::splat(50.));
+		let outline = Subpath::outline_stroke(&[circle], 10., Join::Miter(None), Cap::Butt);
+		assert!(!outline_contains(&outline, DVec2::ZERO));
+		assert!(f64_compare(outline_area(&outline), (55. * 55. - 45. * 45.) * PI, 1.));
+	}
+
+	#[test]
+	fn cusp() {
+		let bezier = Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::new(100., 100.), DVec2::new(0., 100.), DVec2::new(100., 0.));
+		let cusp = bezier.evaluate(TValue::Parametric(0.5));
+		let outline = Subpath::outline_stroke(&[Subpath::from_bezier(&bezier)], 10., Join::Miter(None), Cap::Butt);
+
+		// The cusp is rounded off, while the rest of the stroke follows the curve at half the width of the stroke to either side
+		for angle in (0..16).map(|step| step as f64 * PI / 8.) {
+			assert!(outline_contains(&outline, cusp + DVec2::from_angle(angle) * 4.9));
+		}
+		for t in (1..20).map(|step| step as f64 / 20.) {
+			let point = bezier.evaluate(TValue::Parametric(t));
+			let normal = bezier.normal(TValue::Parametric(t));
+			if normal.is_finite() {
+				assert!(outline_contains(&outline, point + normal * 4.9));
+				assert!(outline_contains(&outline, point - normal * 4.9));
+			}
+		}
+	}
+
+	#[test]
+	fn variable_width() {
+		let line = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.)], false);
+		let area = |widths: &[f64], cap| outline_area(&line.outline_variable_stroke(widths, Join::Miter(None), cap));
+
+		// A stroke tapering to nothing at its start is a triangle, with a cap only at its wide end
+		assert!(f64_compare(area(&[0., 20.], Cap::Butt), 1000., MAX_ABSOLUTE_DIFFERENCE));
+		// The round cap is a full disc at the wide end, which reaches a little past the sides of the triangle as they widen towards it
+		let round = area(&[0., 20.], Cap::Round);
+		assert!(round > 1000. + 50. * PI && round < 1000. + 60. * PI);
+		assert!(f64_compare(area(&[10., 30.], Cap::Square), 2000. + 5. * 10. + 15. * 30., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(line.outline_variable_stroke(&[0., -10.], Join::Miter(None), Cap::Round).is_empty());
+		assert!(line.outline_variable_stroke(&[], Join::Miter(None), Cap::Round).is_empty());
+
+		// Missing widths repeat the last one, matching a stroke of constant width
+		let square = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::splat(100.));
+		let constant = Subpath::outline_stroke(std::slice::from_ref(&square), 10., Join::Round, Cap::Butt);
+		let variable = square.outline_variable_stroke(&[10.], Join::Round, Cap::Butt);
+		assert_eq!(variable.len(), 2);
+		assert!(f64_compare(outline_area(&variable), outline_area(&constant), 0.1));
+
+		// Along a curve, the width changes gradually from one anchor to the next
+		let bezier = Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::new(0., 100.), DVec2::new(100., 100.), DVec2::new(100., 0.));
+		let outline = Subpath::from_bezier(&bezier).outline_variable_stroke(&[2., 40.], Join::Miter(None), Cap::Butt);
+		let middle = bezier.evaluate(TValue::Euclidean(0.5));
+		let normal = bezier.normal(TValue::Euclidean(0.5));
+		assert!(outline_contains(&outline, middle + normal * 9.));
+		assert!(!outline_contains(&outline, middle + normal * 12.));
+		assert!(outline_contains(&outline, bezier.end() + DVec2::X * 19.));
+		assert!(!outline_contains(&outline, bezier.start() + DVec2::X * 2.));
+	}
+}
@@ -1,7 +1,7 @@
 use std::vec;
 
 use super::*;
-use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
+use crate::consts::{MAX_ABSOLUTE_DIFFERENCE, STRICT_MAX_ABSOLUTE_DIFFERENCE};
 use crate::utils::{Cap, Join, SubpathTValue, TValue};
 
 use glam::{DAffine2, DVec2};
@@ -273,6 +273,96 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		}
 	}
 
+	/// Returns the pieces of the Subpath which are drawn when it is stroked with a dash pattern, following the `stroke-dasharray` and `stroke-dashoffset` properties of SVG.
+	/// A dash which crosses the start of a closed Subpath is kept as a single piece, and dashes of zero length are left out.
+	/// - `dash_lengths` - The lengths of the alternating dashes and gaps, repeated to make an even number if there is an odd number of them. The Subpath is returned whole if any are negative or all are zero.
+	/// - `dash_offset` - The distance into the dash pattern at which the start of the Subpath lies.
+	pub fn dash(&self, dash_lengths: &[f64], dash_offset: f64) -> Vec<Subpath<ManipulatorGroupId>> {
+		let pattern_length = dash_lengths.iter().sum::<f64>() * if dash_lengths.len() % 2 == 1 { 2. } else { 1. };
+		let is_valid_pattern = dash_lengths.iter().all(|&length| length >= 0. && length.is_finite()) && pattern_length > 0.;
+		if !is_valid_pattern || self.len_segments() == 0 || !dash_offset.is_finite() {
+			return vec![self.clone()];
+		}
+		let pattern = dash_lengths.iter().chain(if dash_lengths.len() % 2 == 1 { dash_lengths } else { &[] }).copied().collect::<Vec<_>>();
+
+		// Find the part of the pattern at the start of the Subpath
+		let mut index = 0;
+		let mut remaining = dash_offset.rem_euclid(pattern_length);
+		while remaining >= pattern[index] {
+			remaining -= pattern[index];
+			index = (index + 1) % pattern.len();
+		}
+		remaining = pattern[index] - remaining;
+
+		// Walk along the Subpath, collecting the ranges of distances covered by dashes and joining those which touch
+		let arc_length_table = self.arc_length_table(None);
+		let length = arc_length_table.length();
+		let mut dashes: Vec<(f64, f64)> = Vec::new();
+		let mut distance = 0.;
+		while distance < length {
+			let end = (distance + remaining).min(length);
+			if index % 2 == 0 && end > distance {
+				match dashes.last_mut() {
+					Some(dash) if dash.1 == distance => dash.1 = end,
+					_ => dashes.push((distance, end)),
+				}
+			}
+			distance = end;
+			index = (index + 1) % pattern.len();
+			remaining = pattern[index];
+		}
+
+		if self.closed && dashes.len() == 1 && dashes[0] == (0., length) {
+			return vec![self.clone()];
+		}
+		let crosses_start = self.closed && dashes.len() > 1 && dashes[0].0 == 0. && dashes[dashes.len() - 1].1 == length;
+		let first_dash = if crosses_start { Some(dashes.remove(0)) } else { None };
+
+		let mut subpaths = dashes.iter().map(|&(start, end)| arc_length_table.beziers_between_distances(start, end)).collect::<Vec<_>>();
+		if let (Some((start, end)), Some(last)) = (first_dash, subpaths.last_mut()) {
+			last.extend(arc_length_table.beziers_between_distances(start, end));
+		}
+		subpaths
+			.into_iter()
+			.filter(|beziers| !beziers.is_empty())
+			.map(|beziers| Subpath::from_beziers(&beziers, false))
+			.collect()
+	}
+
+	/// Returns a copy of the Subpath with each segment split into the fewest pieces of equal length which are no longer than `max_length`, without changing its shape.
+	/// The original manipulator groups are kept, with their handles shortened to fit the pieces on either side, and new ones are added between them.
+	pub fn subdivide(&self, max_length: f64) -> Subpath<ManipulatorGroupId> {
+		if max_length.is_nan() || max_length <= 0. || self.len_segments() == 0 {
+			return self.clone();
+		}
+
+		let mut manipulator_groups = vec![self.manipulator_groups[0].clone()];
+		for (index, bezier) in self.iter().enumerate() {
+			let count = (bezier.length(None) / max_length).ceil().max(1.) as usize;
+			let t_values = (0..=count)
+				.map(|piece| bezier.euclidean_to_parametric(piece as f64 / count as f64, STRICT_MAX_ABSOLUTE_DIFFERENCE))
+				.collect::<Vec<_>>();
+			let pieces = t_values.windows(2).map(|t| bezier.trim(TValue::Parametric(t[0]), TValue::Parametric(t[1]))).collect::<Vec<_>>();
+
+			manipulator_groups.last_mut().unwrap().out_handle = pieces[0].handle_start();
+			for pair in pieces.windows(2) {
+				manipulator_groups.push(ManipulatorGroup {
+					anchor: pair[1].start(),
+					in_handle: pair[0].handle_end(),
+					out_handle: pair[1].handle_start(),
+					id: ManipulatorGroupId::new(),
+				});
+			}
+			// The last piece arrives at the next original manipulator group, which is the first one for the closing segment
+			let in_handle = pieces[count - 1].handle_end();
+			match self.manipulator_groups.get(index + 1) {
+				Some(next) => manipulator_groups.push(ManipulatorGroup { in_handle, ..next.clone() }),
+				None => manipulator_groups[0].in_handle = in_handle,
+			}
+		}
+		Subpath::new(manipulator_groups, self.closed)
+	}
+
 	/// Apply a transformation to all of the [ManipulatorGroup]s in the [Subpath].
 	pub fn apply_transform(&mut self, affine_transform: DAffine2) {
 		for manipulator_group in &mut self.manipulator_groups {
@@ -360,6 +450,11 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 	/// The intersections of segments of the subpath are joined using the method specified by the `join` argument.
 	/// <iframe frameBorder="0" width="100%" height="400px" src="https://graphite.rs/libraries/bezier-rs#subpath/offset/solo" title="Offset Demo"></iframe>
 	pub fn offset(&self, distance: f64, join: Join) -> Subpath<ManipulatorGroupId> {
+		self.offset_with_concave_join(distance, join, join)
+	}
+
+	/// Offsets the subpath in the same way as [Subpath::offset], except that `concave_join` is used instead of `join` at concave angles where the offset segments could not be clipped against each other.
+	pub(crate) fn offset_with_concave_join(&self, distance: f64, join: Join, concave_join: Join) -> Subpath<ManipulatorGroupId> {
 		assert!(self.len_segments() > 1, "Cannot offset an empty Subpath.");
 
 		// An offset at a distance 0 from the curve is simply the same curve
@@ -396,7 +491,8 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 
 			// The angle is concave. The Subpath overlap and must be clipped
 			let mut apply_join = true;
-			if (angle > 0. && distance > 0.) || (angle < 0. && distance < 0.) {
+			let concave = (angle > 0. && distance > 0.) || (angle < 0. && distance < 0.);
+			if concave {
 				// If the distance is large enough, there may still be no intersections. Also, if the angle is close enough to zero,
 				// subpath intersections may find no intersections. In this case, the points are likely close enough that we can approximate
 				// the points as being on top of one another.
@@ -409,7 +505,7 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 			// The angle is convex. The Subpath must be joined using the specified join type
 			if apply_join {
 				drop_common_point[j] = false;
-				match join {
+				match if concave { concave_join } else { join } {
 					Join::Bevel => {}
 					Join::Miter(miter_limit) => {
 						let miter_manipulator_group = subpaths[i].miter_line_join(&subpaths[j], miter_limit);
@@ -435,7 +531,8 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 			let angle = out_tangent.angle_between(in_tangent);
 
 			let mut apply_join = true;
-			if (angle > 0. && distance > 0.) || (angle < 0. && distance < 0.) {
+			let concave = (angle > 0. && distance > 0.) || (angle < 0. && distance < 0.);
+			if concave {
 				if let Some((clipped_subpath1, clipped_subpath2)) = Subpath::clip_simple_subpaths(&subpaths[subpaths.len() - 1], &subpaths[0]) {
 					// Merge the clipped subpaths
 					let last_index = subpaths.len() - 1;
@@ -446,7 +543,7 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 			}
 			if apply_join {
 				drop_common_point[0] = false;
-				match join {
+				match if concave { concave_join } else { join } {
 					Join::Bevel => {}
 					Join::Miter(miter_limit) => {
 						let last_subpath_index = subpaths.len() - 1;
@@ -549,7 +646,7 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 
 #[cfg(test)]
 mod tests {
-	use super::{Cap, Join, ManipulatorGroup, Subpath};
+	use super::{Bezier, Cap, Join, ManipulatorGroup, Subpath};
 	use crate::compare::{compare_points, compare_subpaths, compare_vec_of_points};
 	use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
 	use crate::utils::{SubpathTValue, TValue};
@@ -1086,4 +1183,116 @@ mod tests {
 		assert_eq!(outline_closed.0, square);
 		assert_eq!(outline_closed.1, None);
 	}
+
+	#[test]
+	fn dash_an_open_subpath() {
+		let line: Subpath<EmptyId> = Subpath::from_beziers(&[Bezier::from_linear_coordinates(0., 0., 60., 0.), Bezier::from_linear_coordinates(60., 0., 60., 40.)], false);
+		let dash_endpoints = |dashes: Vec<Subpath<EmptyId>>| dashes.iter().map(|dash| (dash[0].anchor, dash[dash.len() - 1].anchor)).collect::<Vec<_>>();
+
+		let dashes = line.dash(&[20., 10.], 0.);
+		assert_eq!(
+			dash_endpoints(dashes),
+			vec![
+				(DVec2::new(0., 0.), DVec2::new(20., 0.)),
+				(DVec2::new(30., 0.), DVec2::new(50., 0.)),
+				(DVec2::new(60., 0.), DVec2::new(60., 20.)),
+				(DVec2::new(60., 30.), DVec2::new(60., 40.)),
+			]
+		);
+
+		// A dash which turns a corner is a single piece
+		let dashes = line.dash(&[20., 10.], 15.);
+		assert_eq!(dashes[2].len(), 3);
+		assert!(compare_vec_of_points(
+			dashes[2].anchors(),
+			vec![DVec2::new(45., 0.), DVec2::new(60., 0.), DVec2::new(60., 5.)],
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+
+		// An odd number of lengths is repeated, so dashes and gaps alternate between the lengths
+		let dashes = line.dash(&[10., 20., 30.], 0.);
+		assert_eq!(
+			dash_endpoints(dashes),
+			vec![
+				(DVec2::new(0., 0.), DVec2::new(10., 0.)),
+				(DVec2::new(30., 0.), DVec2::new(60., 0.)),
+				(DVec2::new(60., 10.), DVec2::new(60., 30.)),
+			]
+		);
+
+		// Invalid patterns leave the subpath whole
+		assert_eq!(line.dash(&[], 0.), vec![line.clone()]);
+		assert_eq!(line.dash(&[0., 0.], 0.), vec![line.clone()]);
+		assert_eq!(line.dash(&[10., -5.], 0.), vec![line.clone()]);
+	}
+
+	#[test]
+	fn dash_a_closed_subpath() {
+		let square: Subpath<EmptyId> = Subpath::new_rect(DVec2::ZERO, DVec2::splat(40.));
+
+		let dashes = square.dash(&[30., 10.], 0.);
+		assert_eq!(dashes.len(), 4);
+		assert!(dashes.iter().all(|dash| !dash.closed() && (dash.length(None) - 30.).abs() < MAX_ABSOLUTE_DIFFERENCE));
+
+		// The dash crossing the start of the subpath is joined into one piece
+		let dashes = square.dash(&[30., 10.], 10.);
+		assert_eq!(dashes.len(), 4);
+		assert!(compare_points(dashes[0][0].anchor, DVec2::new(30., 0.)));
+		let crossing = &dashes[3];
+		assert!(compare_vec_of_points(
+			crossing.anchors(),
+			vec![DVec2::new(0., 10.), DVec2::new(0., 0.), DVec2::new(20., 0.)],
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+
+		// Without any gaps, the subpath is left whole
+		assert_eq!(square.dash(&[30., 0.], 5.), vec![square.clone()]);
+	}
+
+	#[test]
+	fn dash_with_short_dashes() {
+		let circle: Subpath<EmptyId> = Subpath::new_ellipse(DVec2::splat(-100.), DVec2::splat(100.));
+		let length = circle.length(None);
+
+		let dashes = circle.dash(&[0.5, 1.5], 0.);
+		// The partial dash at the end crosses the start of the circle, so it is joined with the first dash
+		assert_eq!(dashes.len(), (length / 2.).floor() as usize);
+		assert!(dashes[..dashes.len() - 1].iter().all(|dash| (dash.length(None) - 0.5).abs() < 1e-2));
+	}
+
+	#[test]
+	fn subdivide_an_open_subpath() {
+		let subpath = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 30.)], false);
+		let subdivided = subpath.subdivide(25.);
+		assert!(compare_vec_of_points(
+			subdivided.anchors(),
+			vec![
+				DVec2::ZERO,
+				DVec2::new(25., 0.),
+				DVec2::new(50., 0.),
+				DVec2::new(75., 0.),
+				DVec2::new(100., 0.),
+				DVec2::new(100., 15.),
+				DVec2::new(100., 30.)
+			],
+			MAX_ABSOLUTE_DIFFERENCE
+		));
+		assert!(!subdivided.closed());
+
+		// Segments which are already short enough are left whole
+		assert_eq!(subpath.subdivide(200.).len(), 3);
+	}
+
+	#[test]
+	fn subdivide_a_closed_subpath() {
+		let circle = Subpath::<EmptyId>::new_ellipse(DVec2::splat(-50.), DVec2::splat(50.));
+		let subdivided = circle.subdivide(20.);
+		assert!(subdivided.closed());
+		assert_eq!(subdivided.len(), 4 * (circle.get_segment(0).unwrap().length(None) / 20.).ceil() as usize);
+		assert!((subdivided.length(None) - circle.length(None)).abs() < MAX_ABSOLUTE_DIFFERENCE);
+		for bezier in subdivided.iter() {
+			assert!(bezier.length(None) <= 20.);
+			assert!((bezier.evaluate(TValue::Parametric(0.5)).length() - 50.).abs() < 0.1);
+		}
+	}
 }
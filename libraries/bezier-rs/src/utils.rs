@@ -52,6 +52,15 @@ pub enum Join {
 	Round,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+/// Represents the shape which replaces the sharp corners of a path, as used by [Subpath::round_corners](crate::Subpath::round_corners).
+pub enum Corner {
+	/// The corner is replaced by a circular arc which continues smoothly into the segments on either side, also known as a fillet.
+	Round,
+	/// The corner is cut off by a straight line, also known as a chamfer.
+	Chamfer,
+}
+
 #[derive(Copy, Clone)]
 /// Enum to represent the cap type at the ends of an outline
 /// As defined in SVG: <https://www.w3.org/TR/SVG2/painting.html#LineCaps>.
@@ -61,6 +70,63 @@ pub enum Cap {
 	Square,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+/// Rule used to decide which regions enclosed by a set of subpaths are considered to be inside of the shape.
+/// As defined in SVG: <https://www.w3.org/TR/SVG2/painting.html#WindingRule>.
+pub enum FillRule {
+	/// A point is inside if the sum of the windings of the subpaths around it is not zero.
+	NonZero,
+	/// A point is inside if the sum of the windings of the subpaths around it is odd.
+	EvenOdd,
+}
+
+impl FillRule {
+	/// Returns whether a point with the given winding number is filled under this rule.
+	pub fn is_inside(&self, winding: i32) -> bool {
+		match self {
+			FillRule::NonZero => winding != 0,
+			FillRule::EvenOdd => winding % 2 != 0,
+		}
+	}
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+/// Enum to represent the boolean operations that can be performed between two sets of subpaths, `A` and `B`.
+pub enum BooleanOperation {
+	/// The area covered by either `A` or `B`.
+	Union,
+	/// The area covered by `A` but not by `B`.
+	Difference,
+	/// The area covered by both `A` and `B`.
+	Intersection,
+	/// The area covered by exactly one of `A` or `B`.
+	Exclusion,
+	/// The area covered by `A`, cut into separate pieces along the edges of `B`.
+	Division,
+}
+
+impl BooleanOperation {
+	/// Returns whether a region that is inside or outside of each operand is part of the result of this operation.
+	/// For [BooleanOperation::Division] this is the area of `A`, since the pieces are only separated by the edges of `B`.
+	pub fn is_inside(&self, inside_a: bool, inside_b: bool) -> bool {
+		match self {
+			BooleanOperation::Union => inside_a || inside_b,
+			BooleanOperation::Difference => inside_a && !inside_b,
+			BooleanOperation::Intersection => inside_a && inside_b,
+			BooleanOperation::Exclusion => inside_a != inside_b,
+			BooleanOperation::Division => inside_a,
+		}
+	}
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+/// Direction in which a closed path is traversed, as seen with +y pointing up (matching [crate::Bezier::winding]).
+/// In a coordinate system where +y points down, such as the canvas, the directions appear mirrored.
+pub enum WindingDirection {
+	Clockwise,
+	Counterclockwise,
+}
+
 /// Helper to perform the computation of a and c, where b is the provided point on the curve.
 /// Given the correct power of `t` and `(1-t)`, the computation is the same for quadratic and cubic cases.
 /// Relevant derivation and the definitions of a, b, and c can be found in [the projection identity section](https://pomax.github.io/bezierinfo/#abc) of Pomax's bezier curve primer.
@@ -199,6 +265,27 @@ pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
 	}
 }
 
+/// Multiply two polynomials given by their coefficients, starting with the constant term.
+pub fn polynomial_product(polynomial1: &[f64], polynomial2: &[f64]) -> Vec<f64> {
+	let mut product = vec![0.; (polynomial1.len() + polynomial2.len()).saturating_sub(1)];
+	for (power1, coefficient1) in polynomial1.iter().enumerate() {
+		for (power2, coefficient2) in polynomial2.iter().enumerate() {
+			product[power1 + power2] += coefficient1 * coefficient2;
+		}
+	}
+	product
+}
+
+/// Compute the derivative of a polynomial given by its coefficients, starting with the constant term.
+pub fn polynomial_derivative(polynomial: &[f64]) -> Vec<f64> {
+	polynomial.iter().enumerate().skip(1).map(|(power, coefficient)| power as f64 * coefficient).collect()
+}
+
+/// Compute the definite integral over `[0, 1]` of a polynomial given by its coefficients, starting with the constant term.
+pub fn polynomial_unit_integral(polynomial: &[f64]) -> f64 {
+	polynomial.iter().enumerate().map(|(power, coefficient)| coefficient / (power + 1) as f64).sum()
+}
+
 /// Determine if two rectangles have any overlap. The rectangles are represented by a pair of coordinates that designate the top left and bottom right corners (in a graphical coordinate system).
 pub fn do_rectangles_overlap(rectangle1: [DVec2; 2], rectangle2: [DVec2; 2]) -> bool {
 	let [bottom_left1, top_right1] = rectangle1;
@@ -317,6 +404,31 @@ pub fn compute_circular_subpath_details<ManipulatorGroupId: crate::Identifier>(
 	)
 }
 
+/// Returns the largest distance between a unit circle and the cubic bezier approximating an arc of it which spans `angle` radians, for an angle of at most half a turn.
+/// The handles of the bezier are placed as in [compute_circular_subpath_details], so that its midpoint lies on the circle.
+pub fn unit_arc_approximation_error(angle: f64) -> f64 {
+	let handle_length = 4. / 3. * (angle / 4.).tan();
+	let end = DVec2::from_angle(angle);
+	let [p0, p1, p2, p3] = [DVec2::X, DVec2::new(1., handle_length), end - end.perp() * handle_length, end];
+	let error = |t: f64| {
+		let one_minus_t = 1. - t;
+		let point = p0 * one_minus_t.powi(3) + p1 * 3. * one_minus_t.powi(2) * t + p2 * 3. * one_minus_t * t.powi(2) + p3 * t.powi(3);
+		(point.length() - 1.).abs()
+	};
+
+	// The bezier is symmetric about its midpoint, and strays furthest from the circle once on either side of it
+	let (mut low, mut high) = (0., 0.5);
+	for _ in 0..50 {
+		let (t1, t2) = (low + (high - low) / 3., high - (high - low) / 3.);
+		if error(t1) < error(t2) {
+			low = t1;
+		} else {
+			high = t2;
+		}
+	}
+	error((low + high) / 2.)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -337,6 +449,14 @@ mod tests {
 		assert!(solve_linear(2., -8.) == vec![4.]);
 	}
 
+	#[test]
+	fn test_polynomials() {
+		// (1 + 2t)(3 - t) = 3 + 5t - 2t^2
+		assert_eq!(polynomial_product(&[1., 2.], &[3., -1.]), vec![3., 5., -2.]);
+		assert_eq!(polynomial_derivative(&[3., 5., -2.]), vec![5., -4.]);
+		assert!(f64_compare(polynomial_unit_integral(&[3., 5., -2.]), 3. + 5. / 2. - 2. / 3., MAX_ABSOLUTE_DIFFERENCE));
+	}
+
 	#[test]
 	fn test_solve_cubic() {
 		// discriminant == 0
@@ -3,3 +3,21 @@ use crate::raster::Color;
 // RENDERING
 pub const LAYER_OUTLINE_STROKE_COLOR: Color = Color::BLACK;
 pub const LAYER_OUTLINE_STROKE_WEIGHT: f64 = 1.;
+
+// BLEND STEPS
+/// Largest number of intermediate shapes the Blend Steps node places between its source and target.
+pub const BLEND_STEPS_MAX: u32 = 1000;
+
+// IMAGE SIZE
+/// Largest width or height in pixels of the images made by the Resize Image and Canvas Size nodes.
+pub const IMAGE_SIZE_MAX: u32 = 16384;
+/// Largest number of pixels in the images made by the Resize Image and Canvas Size nodes, which at four 32-bit channels per pixel is a gigabyte.
+pub const IMAGE_PIXELS_MAX: u64 = 1 << 26;
+
+// SCATTER
+/// Number of random positions tried for each point requested from the Scatter Points node before it gives up on finding room for more.
+pub const SCATTER_MAX_ATTEMPTS_PER_POINT: usize = 30;
+
+// VARIABLE WIDTH STROKE
+/// Number of pieces each subpath is split into by the Variable Width Stroke node, so that the width follows the shape of its profile curve between the anchors.
+pub const VARIABLE_WIDTH_STROKE_PROFILE_SAMPLES: usize = 64;
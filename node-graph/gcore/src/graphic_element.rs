@@ -51,6 +51,10 @@ pub struct GraphicElement {
 	pub visible: bool,
 	pub locked: bool,
 	pub collapsed: bool,
+	/// Whether an image in the [`GraphicElementData`] is drawn using the blend mode, as the effects made by the layer style nodes are.
+	/// Other images are drawn normally whatever their blend mode, as they always have been.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub blend_image: bool,
 	pub graphic_element_data: GraphicElementData,
 }
 
@@ -63,6 +67,7 @@ impl Default for GraphicElement {
 			visible: true,
 			locked: false,
 			collapsed: false,
+			blend_image: false,
 			graphic_element_data: GraphicElementData::VectorShape(Box::new(VectorData::empty())),
 		}
 	}
@@ -124,6 +129,7 @@ async fn construct_layer<Data: Into<GraphicElementData>, Fut1: Future<Output = D
 		visible,
 		locked,
 		collapsed,
+		blend_image: false,
 		graphic_element_data: graphic_element_data.into(),
 	});
 	stack
@@ -332,6 +338,7 @@ impl core::hash::Hash for GraphicElement {
 		self.visible.hash(state);
 		self.locked.hash(state);
 		self.collapsed.hash(state);
+		self.blend_image.hash(state);
 		self.graphic_element_data.hash(state);
 	}
 }
@@ -183,6 +183,17 @@ pub fn format_transform_matrix(transform: DAffine2) -> String {
 	result
 }
 
+/// Adds the blend mode of the element being rendered as a style attribute, unless it is the default.
+fn push_blend_mode_style(attributes: &mut SvgRenderAttrs) {
+	if attributes.0.blend_mode != BlendMode::default() {
+		attributes.push_complex("style", |v| {
+			v.svg.push("mix-blend-mode: ");
+			v.svg.push(v.blend_mode.to_svg_style_name());
+			v.svg.push(";");
+		})
+	}
+}
+
 pub trait GraphicElementRendered {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams);
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]>;
@@ -198,7 +209,9 @@ impl GraphicElementRendered for GraphicGroup {
 			|attributes| attributes.push("transform", format_transform_matrix(self.transform)),
 			|render| {
 				for element in self.iter() {
-					render.blend_mode = element.blend_mode;
+					// Images ignore the blend mode of their layer unless they are marked to use it, so existing documents keep drawing them normally
+					let blends = element.blend_image || !matches!(element.graphic_element_data, GraphicElementData::ImageFrame(_));
+					render.blend_mode = if blends { element.blend_mode } else { BlendMode::default() };
 					element.graphic_element_data.render_svg(render, render_params);
 				}
 			},
@@ -230,13 +243,7 @@ impl GraphicElementRendered for VectorData {
 			let render = &mut attributes.0;
 			let style = self.style.render(render_params.view_mode, &mut render.svg_defs, multiplied_transform, layer_bounds, transformed_bounds);
 			attributes.push_val(style);
-			if attributes.0.blend_mode != BlendMode::default() {
-				attributes.push_complex("style", |v| {
-					v.svg.push("mix-blend-mode: ");
-					v.svg.push(v.blend_mode.to_svg_style_name());
-					v.svg.push(";");
-				})
-			}
+			push_blend_mode_style(attributes);
 		});
 	}
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
@@ -329,7 +336,8 @@ impl GraphicElementRendered for ImageFrame<Color> {
 					attributes.push("height", 1.to_string());
 					attributes.push("preserveAspectRatio", "none");
 					attributes.push("transform", transform);
-					attributes.push("href", SvgSegment::BlobUrl(uuid))
+					attributes.push("href", SvgSegment::BlobUrl(uuid));
+					push_blend_mode_style(attributes);
 				});
 				render.image_data.push((uuid, self.image.clone()))
 			}
@@ -350,7 +358,8 @@ impl GraphicElementRendered for ImageFrame<Color> {
 					attributes.push("height", 1.to_string());
 					attributes.push("preserveAspectRatio", "none");
 					attributes.push("transform", transform);
-					attributes.push("href", base64_string)
+					attributes.push("href", base64_string);
+					push_blend_mode_style(attributes);
 				});
 			}
 			ImageRenderMode::Canvas => {
@@ -1,7 +1,7 @@
 #![allow(clippy::too_many_arguments)]
 
 #[cfg(feature = "alloc")]
-use super::curve::{Curve, CurveManipulatorGroup, ValueMapperNode};
+use super::curve::{Curve, ValueMapperNode};
 #[cfg(feature = "alloc")]
 use super::ImageFrame;
 use super::{Channel, Color, Node, RGBMut};
@@ -703,6 +703,114 @@ impl core::fmt::Display for RelativeAbsolute {
 	}
 }
 
+/// The filter used to interpolate between pixels when an image is resampled to a new size.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum ResampleFilter {
+	Nearest,
+	Bilinear,
+	Bicubic,
+	Lanczos,
+}
+
+impl core::fmt::Display for ResampleFilter {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			ResampleFilter::Nearest => write!(f, "Nearest Neighbor"),
+			ResampleFilter::Bilinear => write!(f, "Bilinear"),
+			ResampleFilter::Bicubic => write!(f, "Bicubic"),
+			ResampleFilter::Lanczos => write!(f, "Lanczos"),
+		}
+	}
+}
+
+impl ResampleFilter {
+	pub fn list() -> [ResampleFilter; 4] {
+		[ResampleFilter::Nearest, ResampleFilter::Bilinear, ResampleFilter::Bicubic, ResampleFilter::Lanczos]
+	}
+}
+
+/// The side or corner of a resized canvas which the existing image stays attached to.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum CanvasAnchor {
+	TopLeft,
+	Top,
+	TopRight,
+	Left,
+	Center,
+	Right,
+	BottomLeft,
+	Bottom,
+	BottomRight,
+}
+
+impl core::fmt::Display for CanvasAnchor {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			CanvasAnchor::TopLeft => write!(f, "Top Left"),
+			CanvasAnchor::Top => write!(f, "Top"),
+			CanvasAnchor::TopRight => write!(f, "Top Right"),
+			CanvasAnchor::Left => write!(f, "Left"),
+			CanvasAnchor::Center => write!(f, "Center"),
+			CanvasAnchor::Right => write!(f, "Right"),
+			CanvasAnchor::BottomLeft => write!(f, "Bottom Left"),
+			CanvasAnchor::Bottom => write!(f, "Bottom"),
+			CanvasAnchor::BottomRight => write!(f, "Bottom Right"),
+		}
+	}
+}
+
+impl CanvasAnchor {
+	pub fn list() -> [CanvasAnchor; 9] {
+		[
+			CanvasAnchor::TopLeft,
+			CanvasAnchor::Top,
+			CanvasAnchor::TopRight,
+			CanvasAnchor::Left,
+			CanvasAnchor::Center,
+			CanvasAnchor::Right,
+			CanvasAnchor::BottomLeft,
+			CanvasAnchor::Bottom,
+			CanvasAnchor::BottomRight,
+		]
+	}
+
+	/// Returns how far across the canvas the anchor lies horizontally and vertically, from 0 at the top left to 1 at the bottom right.
+	pub fn factor(&self) -> (f64, f64) {
+		let index = *self as u8;
+		((index % 3) as f64 / 2., (index / 3) as f64 / 2.)
+	}
+}
+
+/// Where the band of a raster stroke lies relative to the edges of the layer it outlines.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum StrokePosition {
+	Inside,
+	Center,
+	Outside,
+}
+
+impl core::fmt::Display for StrokePosition {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			StrokePosition::Inside => write!(f, "Inside"),
+			StrokePosition::Center => write!(f, "Center"),
+			StrokePosition::Outside => write!(f, "Outside"),
+		}
+	}
+}
+
+impl StrokePosition {
+	pub fn list() -> [StrokePosition; 3] {
+		[StrokePosition::Inside, StrokePosition::Center, StrokePosition::Outside]
+	}
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", derive(specta::Type))]
@@ -969,40 +1077,7 @@ pub struct GenerateCurvesNode<OutputChannel, Curve> {
 #[cfg(feature = "alloc")]
 #[node_macro::node_fn(GenerateCurvesNode<_Channel>)]
 fn generate_curves<_Channel: Channel + super::Linear>(_primary: (), curve: Curve) -> ValueMapperNode<_Channel> {
-	use bezier_rs::{Bezier, TValue};
-	let [mut pos, mut param]: [[f32; 2]; 2] = [[0.; 2], curve.first_handle];
-	let mut lut = vec![_Channel::from_f64(0.); WINDOW_SIZE];
-	let end = CurveManipulatorGroup {
-		anchor: [1.; 2],
-		handles: [curve.last_handle, [0.; 2]],
-	};
-	for sample in curve.manipulator_groups.iter().chain(core::iter::once(&end)) {
-		let [x0, y0, x1, y1, x2, y2, x3, y3] = [pos[0], pos[1], param[0], param[1], sample.handles[0][0], sample.handles[0][1], sample.anchor[0], sample.anchor[1]].map(f64::from);
-
-		let bezier = Bezier::from_cubic_coordinates(x0, y0, x1, y1, x2, y2, x3, y3);
-
-		let [left, right] = [pos[0], sample.anchor[0]].map(|c| c.clamp(0., 1.));
-		let lut_index_left: usize = (left * (lut.len() - 1) as f32).floor() as _;
-		let lut_index_right: usize = (right * (lut.len() - 1) as f32).ceil() as _;
-		for index in lut_index_left..=lut_index_right {
-			let x = index as f64 / (lut.len() - 1) as f64;
-			let y = if x <= x0 {
-				y0
-			} else if x >= x3 {
-				y3
-			} else {
-				bezier.find_tvalues_for_x(x)
-					.next()
-					.map(|t| bezier.evaluate(TValue::Parametric(t.clamp(0., 1.))).y)
-					// Fall back to a very bad approximation if Bezier-rs fails
-					.unwrap_or_else(|| (x - x0) / (x3 - x0) * (y3 - y0) + y0)
-			};
-			lut[index] = _Channel::from_f64(y);
-		}
-
-		pos = sample.anchor;
-		param = sample.handles[1];
-	}
+	let lut = (0..WINDOW_SIZE).map(|index| _Channel::from_f64(curve.evaluate(index as f64 / (WINDOW_SIZE - 1) as f64))).collect();
 	ValueMapperNode::new(lut)
 }
 
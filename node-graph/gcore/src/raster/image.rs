@@ -115,7 +115,7 @@ impl<P: Pixel> Image<P> {
 		Self {
 			width,
 			height,
-			data: vec![color; (width * height) as usize],
+			data: vec![color; width as usize * height as usize],
 		}
 	}
 
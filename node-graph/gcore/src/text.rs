@@ -8,14 +8,45 @@ pub use to_path::*;
 
 use crate::Node;
 
-pub struct TextGenerator<Text, FontName, Size> {
+pub struct TextGenerator<Text, FontName, Size, Path, StartOffset, Alignment, LetterSpacing, Flip, RotateGlyphs> {
 	text: Text,
 	font_name: FontName,
 	font_size: Size,
+	path: Path,
+	start_offset: StartOffset,
+	alignment: Alignment,
+	letter_spacing: LetterSpacing,
+	flip: Flip,
+	rotate_glyphs: RotateGlyphs,
 }
 
 #[node_fn(TextGenerator)]
-fn generate_text<'a: 'input, T>(editor: EditorApi<'a, T>, text: String, font_name: Font, font_size: f64) -> crate::vector::VectorData {
+fn generate_text<'a: 'input, T>(
+	editor: EditorApi<'a, T>,
+	text: String,
+	font_name: Font,
+	font_size: f64,
+	path: crate::vector::VectorData,
+	start_offset: f64,
+	alignment: TextPathAlignment,
+	letter_spacing: f64,
+	flip: bool,
+	rotate_glyphs: bool,
+) -> crate::vector::VectorData {
 	let buzz_face = editor.font_cache.get(&font_name).map(|data| load_face(data));
-	crate::vector::VectorData::from_subpaths(to_path(&text, buzz_face, font_size, None))
+
+	// The text follows the first subpath of the path with any segments, placed in the same coordinates as the path
+	let Some(subpath) = path.subpaths.iter().find(|subpath| subpath.len_segments() > 0) else {
+		return crate::vector::VectorData::from_subpaths(to_path(&text, buzz_face, font_size, None));
+	};
+	let mut subpath = subpath.clone();
+	subpath.apply_transform(path.transform);
+	let options = TextPathOptions {
+		start_offset,
+		alignment,
+		letter_spacing,
+		flip,
+		rotate_glyphs,
+	};
+	crate::vector::VectorData::from_subpaths(to_path_along_subpath(&text, buzz_face, font_size, &subpath, options))
 }
@@ -2,9 +2,37 @@ use crate::uuid::ManipulatorGroupId;
 
 use bezier_rs::{ManipulatorGroup, Subpath};
 
-use glam::DVec2;
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
 use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder};
 use rustybuzz::{GlyphBuffer, UnicodeBuffer};
+use serde::{Deserialize, Serialize};
+
+/// Where each line of text laid out along a path is placed along it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum TextPathAlignment {
+	/// The line starts at the start offset from the start of the path.
+	#[default]
+	Start,
+	/// The line is centered on the middle of the path, moved along it by the start offset.
+	Center,
+	/// The line ends at the start offset back from the end of the path.
+	End,
+}
+
+/// How text is laid out along a path by [to_path_along_subpath].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TextPathOptions {
+	/// Distance along the path from where the text is placed according to the alignment.
+	pub start_offset: f64,
+	pub alignment: TextPathAlignment,
+	/// Extra space between each glyph and the next, which may be negative to bring them closer together.
+	pub letter_spacing: f64,
+	/// Follow the path in reverse, which places the text on its other side.
+	pub flip: bool,
+	/// Turn each glyph to follow the direction of the path at its center, instead of keeping it upright.
+	pub rotate_glyphs: bool,
+}
 
 struct Builder {
 	current_subpath: Subpath<ManipulatorGroupId>,
@@ -131,6 +159,77 @@ pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, li
 	builder.other_subpaths
 }
 
+/// Lays out the text with the baseline of each glyph following the path, shaping each line of the text as a single run without wrapping.
+/// Later lines are placed a line height further from the path, on the side below the text. Glyphs whose centers fall beyond the ends of an open path are
+/// left out, while those on a closed path wrap around it.
+pub fn to_path_along_subpath(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, path: &Subpath<ManipulatorGroupId>, options: TextPathOptions) -> Vec<Subpath<ManipulatorGroupId>> {
+	let buzz_face = match buzz_face {
+		Some(face) => face,
+		// Show blank layer if font has not loaded
+		None => return vec![],
+	};
+	if path.len_segments() == 0 {
+		return vec![];
+	}
+
+	let path = if options.flip { path.reverse() } else { path.clone() };
+	let arc_length_table = path.arc_length_table(None);
+	let path_length = arc_length_table.length();
+	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
+
+	let mut builder = Builder {
+		current_subpath: Subpath::new(Vec::new(), false),
+		other_subpaths: Vec::new(),
+		pos: DVec2::ZERO,
+		offset: DVec2::ZERO,
+		ascender: (buzz_face.ascender() as f64 / buzz_face.height() as f64) * font_size / scale,
+		scale,
+		id: ManipulatorGroupId::ZERO,
+	};
+	let baseline = builder.ascender * scale;
+
+	for (line_index, line) in str.split('\n').enumerate() {
+		buffer.push_str(line);
+		let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+
+		// Letter spacing is only added between glyphs, so it doesn't move the line away from its alignment
+		let advances = glyph_buffer.glyph_positions().iter().map(|glyph_position| glyph_position.x_advance as f64 * scale).collect::<Vec<_>>();
+		let line_length = advances.iter().sum::<f64>() + options.letter_spacing * advances.len().saturating_sub(1) as f64;
+		let mut distance = match options.alignment {
+			TextPathAlignment::Start => options.start_offset,
+			TextPathAlignment::Center => (path_length - line_length) / 2. + options.start_offset,
+			TextPathAlignment::End => path_length - line_length - options.start_offset,
+		};
+
+		for ((glyph_position, glyph_info), advance) in glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()).zip(advances) {
+			let center = distance + advance / 2.;
+			distance += advance + options.letter_spacing;
+			if !path.closed() && !(0. ..=path_length).contains(&center) {
+				continue;
+			}
+
+			let first_subpath = builder.other_subpaths.len();
+			builder.offset = DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * builder.scale;
+			buzz_face.outline_glyph(GlyphId(glyph_info.glyph_id as u16), &mut builder);
+			if !builder.current_subpath.is_empty() {
+				builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
+			}
+
+			// The glyph is moved so the center of its baseline is at the origin, then placed at its center along the path
+			let tangent = arc_length_table.tangent_at_distance(center);
+			let angle = if options.rotate_glyphs && tangent.is_finite() { tangent.y.atan2(tangent.x) } else { 0. };
+			let transform = DAffine2::from_angle_translation(angle, arc_length_table.evaluate_at_distance(center))
+				* DAffine2::from_translation(DVec2::new(-advance / 2., line_index as f64 * line_height - baseline));
+			for subpath in &mut builder.other_subpaths[first_subpath..] {
+				subpath.apply_transform(transform);
+			}
+		}
+
+		buffer = glyph_buffer.clear();
+	}
+	builder.other_subpaths
+}
+
 pub fn bounding_box(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> DVec2 {
 	let buzz_face = match buzz_face {
 		Some(face) => face,
@@ -0,0 +1,270 @@
+use crate::Color;
+
+use glam::DVec2;
+
+/// The elements of [VectorData](super::VectorData) which an attribute holds a value for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttributeDomain {
+	/// One value for each anchor, counting through the manipulator groups of each subpath in turn.
+	Point,
+	/// One value for each subpath.
+	Subpath,
+}
+
+/// A single value of an attribute.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttributeValue {
+	F64(f64),
+	DVec2(DVec2),
+	Color(Color),
+	String(String),
+}
+
+/// The column of values of an attribute, with one value for each element of its [AttributeDomain].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttributeValues {
+	F64(Vec<f64>),
+	DVec2(Vec<DVec2>),
+	Color(Vec<Color>),
+	String(Vec<String>),
+}
+
+impl core::hash::Hash for AttributeValues {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		core::mem::discriminant(self).hash(state);
+		self.len().hash(state);
+		match self {
+			Self::F64(values) => values.iter().for_each(|value| value.to_bits().hash(state)),
+			Self::DVec2(values) => values.iter().flat_map(|value| value.to_array()).for_each(|value| value.to_bits().hash(state)),
+			Self::Color(values) => values.hash(state),
+			Self::String(values) => values.hash(state),
+		}
+	}
+}
+
+impl AttributeValues {
+	/// Get the number of values in the column.
+	pub fn len(&self) -> usize {
+		match self {
+			Self::F64(values) => values.len(),
+			Self::DVec2(values) => values.len(),
+			Self::Color(values) => values.len(),
+			Self::String(values) => values.len(),
+		}
+	}
+
+	/// Check if the column has no values.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Get the value at the given index, if there is one.
+	pub fn get(&self, index: usize) -> Option<AttributeValue> {
+		match self {
+			Self::F64(values) => values.get(index).copied().map(AttributeValue::F64),
+			Self::DVec2(values) => values.get(index).copied().map(AttributeValue::DVec2),
+			Self::Color(values) => values.get(index).copied().map(AttributeValue::Color),
+			Self::String(values) => values.get(index).cloned().map(AttributeValue::String),
+		}
+	}
+
+	/// Get the values if they are numbers.
+	pub fn as_f64(&self) -> Option<&[f64]> {
+		if let Self::F64(values) = self {
+			Some(values)
+		} else {
+			None
+		}
+	}
+
+	/// Get the values if they are vectors.
+	pub fn as_dvec2(&self) -> Option<&[DVec2]> {
+		if let Self::DVec2(values) = self {
+			Some(values)
+		} else {
+			None
+		}
+	}
+
+	/// Get the values if they are colors.
+	pub fn as_color(&self) -> Option<&[Color]> {
+		if let Self::Color(values) = self {
+			Some(values)
+		} else {
+			None
+		}
+	}
+
+	/// Get the values if they are strings.
+	pub fn as_string(&self) -> Option<&[String]> {
+		if let Self::String(values) = self {
+			Some(values)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the column with all of its values repeated the given number of times, one copy after another.
+	pub fn repeat(&self, times: usize) -> Self {
+		fn repeat<T: Clone>(values: &[T], times: usize) -> Vec<T> {
+			(0..times).flat_map(|_| values.iter().cloned()).collect()
+		}
+		match self {
+			Self::F64(values) => Self::F64(repeat(values, times)),
+			Self::DVec2(values) => Self::DVec2(repeat(values, times)),
+			Self::Color(values) => Self::Color(repeat(values, times)),
+			Self::String(values) => Self::String(repeat(values, times)),
+		}
+	}
+
+	/// Returns a new column with a value for each of the `samples`, each made by blending the values at two indices by a factor from `0` to `1`.
+	/// Strings can't be blended, so the nearer of the two is used. Returns `None` if any of the indices are out of range.
+	pub fn interpolate(&self, samples: &[(usize, usize, f64)]) -> Option<Self> {
+		fn blend<T: Clone>(values: &[T], samples: &[(usize, usize, f64)], lerp: impl Fn(&T, &T, f64) -> T) -> Option<Vec<T>> {
+			samples.iter().map(|&(a, b, t)| Some(lerp(values.get(a)?, values.get(b)?, t.clamp(0., 1.)))).collect()
+		}
+		Some(match self {
+			Self::F64(values) => Self::F64(blend(values, samples, |a, b, t| a + (b - a) * t)?),
+			Self::DVec2(values) => Self::DVec2(blend(values, samples, |a, b, t| a.lerp(*b, t))?),
+			Self::Color(values) => Self::Color(blend(values, samples, |a, b, t| a.lerp(*b, t as f32))?),
+			Self::String(values) => Self::String(blend(values, samples, |a, b, t| if t < 0.5 { a.clone() } else { b.clone() })?),
+		})
+	}
+}
+
+/// Named columns of values attached to the points and subpaths of [VectorData](super::VectorData), such as a width or color for each anchor.
+///
+/// The values are matched up with the points or subpaths by their order, so a column only applies while it has exactly one value for each of them.
+/// Nodes which repeat or resample the subpaths update the columns to match, while those which rebuild the subpaths in other ways leave them unused.
+#[derive(Debug, Clone, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorAttributes {
+	point: Vec<(String, AttributeValues)>,
+	subpath: Vec<(String, AttributeValues)>,
+}
+
+impl VectorAttributes {
+	/// Construct a set of attributes with no columns.
+	pub const fn new() -> Self {
+		Self {
+			point: Vec::new(),
+			subpath: Vec::new(),
+		}
+	}
+
+	fn columns(&self, domain: AttributeDomain) -> &Vec<(String, AttributeValues)> {
+		match domain {
+			AttributeDomain::Point => &self.point,
+			AttributeDomain::Subpath => &self.subpath,
+		}
+	}
+
+	fn columns_mut(&mut self, domain: AttributeDomain) -> &mut Vec<(String, AttributeValues)> {
+		match domain {
+			AttributeDomain::Point => &mut self.point,
+			AttributeDomain::Subpath => &mut self.subpath,
+		}
+	}
+
+	/// Get the column with the given name, whether or not its length matches the number of elements in the domain.
+	pub fn get(&self, domain: AttributeDomain, name: &str) -> Option<&AttributeValues> {
+		self.columns(domain).iter().find(|(column_name, _)| column_name == name).map(|(_, values)| values)
+	}
+
+	/// Add a column with the given name, replacing any existing column of that name.
+	pub fn insert(&mut self, domain: AttributeDomain, name: impl Into<String>, values: AttributeValues) {
+		let name = name.into();
+		let columns = self.columns_mut(domain);
+		match columns.iter_mut().find(|(column_name, _)| *column_name == name) {
+			Some((_, column)) => *column = values,
+			None => columns.push((name, values)),
+		}
+	}
+
+	/// Remove the column with the given name, returning its values.
+	pub fn remove(&mut self, domain: AttributeDomain, name: &str) -> Option<AttributeValues> {
+		let columns = self.columns_mut(domain);
+		let index = columns.iter().position(|(column_name, _)| column_name == name)?;
+		Some(columns.remove(index).1)
+	}
+
+	/// Iterate over the names of the columns in the domain.
+	pub fn names(&self, domain: AttributeDomain) -> impl Iterator<Item = &str> {
+		self.columns(domain).iter().map(|(name, _)| name.as_str())
+	}
+
+	/// Repeat the values of every column the given number of times, to match the subpaths being repeated one copy after another.
+	pub fn repeat(&mut self, times: usize) {
+		for (_, values) in self.point.iter_mut().chain(self.subpath.iter_mut()) {
+			*values = values.repeat(times);
+		}
+	}
+
+	/// Replace the values of every column in the domain with values blended from the old ones, as described by [AttributeValues::interpolate].
+	/// Columns which don't have a value for every index referred to by the `samples` are removed.
+	pub fn interpolate(&mut self, domain: AttributeDomain, samples: &[(usize, usize, f64)]) {
+		let columns = core::mem::take(self.columns_mut(domain));
+		*self.columns_mut(domain) = columns.into_iter().filter_map(|(name, values)| Some((name, values.interpolate(samples)?))).collect();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::vector::VectorData;
+
+	use bezier_rs::Subpath;
+
+	#[test]
+	fn repeat_and_interpolate_values() {
+		let values = AttributeValues::F64(vec![1., 2.]);
+		assert_eq!(values.repeat(3), AttributeValues::F64(vec![1., 2., 1., 2., 1., 2.]));
+		assert!(values.repeat(0).is_empty());
+
+		// Each sample blends the values at two indices, clamping the factor to the range between them
+		let samples = [(0, 1, 0.25), (1, 0, 0.5), (0, 1, 2.)];
+		assert_eq!(values.interpolate(&samples), Some(AttributeValues::F64(vec![1.25, 1.5, 2.])));
+		let vectors = AttributeValues::DVec2(vec![DVec2::ZERO, DVec2::new(4., 8.)]);
+		assert_eq!(vectors.interpolate(&[(0, 1, 0.75)]), Some(AttributeValues::DVec2(vec![DVec2::new(3., 6.)])));
+
+		// Strings take the nearer value, and an index out of range gives no column at all
+		let strings = AttributeValues::String(vec!["a".to_string(), "b".to_string()]);
+		assert_eq!(strings.interpolate(&[(0, 1, 0.4), (0, 1, 0.6)]), Some(AttributeValues::String(vec!["a".to_string(), "b".to_string()])));
+		assert_eq!(values.interpolate(&[(0, 2, 0.5)]), None);
+	}
+
+	#[test]
+	fn columns_apply_only_with_one_value_per_element() {
+		let mut vector_data = VectorData::from_subpaths(vec![
+			Subpath::from_anchors([DVec2::ZERO, DVec2::X, DVec2::Y], false),
+			Subpath::from_anchors([DVec2::ONE, DVec2::splat(2.)], false),
+		]);
+		assert_eq!(vector_data.domain_len(AttributeDomain::Point), 5);
+		assert_eq!(vector_data.domain_len(AttributeDomain::Subpath), 2);
+
+		vector_data.attributes.insert(AttributeDomain::Point, "width", AttributeValues::F64(vec![1.; 5]));
+		vector_data.attributes.insert(AttributeDomain::Subpath, "width", AttributeValues::F64(vec![1.; 3]));
+		assert!(vector_data.attribute(AttributeDomain::Point, "width").is_some());
+		assert!(vector_data.attribute(AttributeDomain::Subpath, "width").is_none());
+		assert_eq!(vector_data.attributes.get(AttributeDomain::Subpath, "width").map(AttributeValues::len), Some(3));
+
+		// Inserting a column of the same name replaces it, and removing it leaves the other domain alone
+		vector_data.attributes.insert(AttributeDomain::Subpath, "width", AttributeValues::F64(vec![2.; 2]));
+		assert_eq!(vector_data.attributes.names(AttributeDomain::Subpath).count(), 1);
+		assert!(vector_data.attribute(AttributeDomain::Subpath, "width").is_some());
+		assert!(vector_data.attributes.remove(AttributeDomain::Point, "width").is_some());
+		assert!(vector_data.attribute(AttributeDomain::Point, "width").is_none());
+		assert!(vector_data.attribute(AttributeDomain::Subpath, "width").is_some());
+
+		// Interpolating drops the columns which can't supply every sample
+		let mut attributes = VectorAttributes::new();
+		attributes.insert(AttributeDomain::Point, "short", AttributeValues::F64(vec![0.]));
+		attributes.insert(AttributeDomain::Point, "long", AttributeValues::F64(vec![0., 1.]));
+		attributes.interpolate(AttributeDomain::Point, &[(0, 1, 0.5)]);
+		assert_eq!(attributes.names(AttributeDomain::Point).collect::<Vec<_>>(), ["long"]);
+		assert_eq!(attributes.get(AttributeDomain::Point, "long"), Some(&AttributeValues::F64(vec![0.5])));
+	}
+}
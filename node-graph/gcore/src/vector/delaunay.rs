@@ -0,0 +1,148 @@
+use glam::DVec2;
+
+/// Returns twice the signed area of the triangle, which is positive when its points wind counterclockwise with +y pointing up.
+fn orientation(a: DVec2, b: DVec2, c: DVec2) -> f64 {
+	(b - a).perp_dot(c - a)
+}
+
+/// Returns whether `point` lies strictly inside the circle passing through the corners of the triangle.
+fn in_circumcircle([a, b, c]: [DVec2; 3], point: DVec2) -> bool {
+	let [a, b, c] = [a - point, b - point, c - point];
+	let determinant = a.length_squared() * b.perp_dot(c) + b.length_squared() * c.perp_dot(a) + c.length_squared() * a.perp_dot(b);
+	determinant * orientation(a, b, c).signum() > 0.
+}
+
+/// Returns the Delaunay triangulation of the points, as triples of indices into `points` which wind counterclockwise with +y pointing up.
+/// No point lies inside the circle through the corners of any of the triangles, which makes them as close to equilateral as possible.
+/// Repeated points are only used once, and points which all lie on a line give no triangles.
+pub fn delaunay_triangles(points: &[DVec2]) -> Vec<[usize; 3]> {
+	let count = points.len();
+	if count < 3 {
+		return Vec::new();
+	}
+
+	// Points are added one at a time to a triangle large enough to contain them all, which is removed again at the end
+	let (min, max) = points.iter().fold((DVec2::INFINITY, DVec2::NEG_INFINITY), |(min, max), &point| (min.min(point), max.max(point)));
+	let (center, size) = ((min + max) / 2., (max - min).max_element().max(1.));
+	let mut vertices = points.to_vec();
+	vertices.extend([DVec2::new(-20., -10.), DVec2::new(20., -10.), DVec2::new(0., 20.)].map(|corner| center + corner * size));
+	let mut triangles = vec![[count, count + 1, count + 2]];
+
+	for (index, &point) in points.iter().enumerate() {
+		// The triangles whose circumcircles contain the new point leave a hole, which is filled by joining each of its edges to the point
+		let (bad, good): (Vec<_>, Vec<_>) = triangles.into_iter().partition(|triangle| in_circumcircle(triangle.map(|vertex| vertices[vertex]), point));
+		triangles = good;
+		let shared = |a: usize, b: usize, other: &[usize; 3]| other.contains(&a) && other.contains(&b);
+		for (triangle_index, triangle) in bad.iter().enumerate() {
+			for edge in 0..3 {
+				let (a, b) = (triangle[edge], triangle[(edge + 1) % 3]);
+				if !bad.iter().enumerate().any(|(other_index, other)| other_index != triangle_index && shared(a, b, other)) {
+					triangles.push([a, b, index]);
+				}
+			}
+		}
+	}
+
+	triangles
+		.into_iter()
+		.filter(|triangle| triangle.iter().all(|&vertex| vertex < count))
+		.filter_map(|[a, b, c]| {
+			let area = orientation(points[a], points[b], points[c]);
+			(area.abs() > f64::EPSILON).then_some(if area > 0. { [a, b, c] } else { [a, c, b] })
+		})
+		.collect()
+}
+
+/// Returns the part of the convex polygon on the side of the line through `point` facing away from `normal`, using the Sutherland–Hodgman algorithm.
+fn clip_polygon(polygon: &[DVec2], point: DVec2, normal: DVec2) -> Vec<DVec2> {
+	let side = |vertex: DVec2| (vertex - point).dot(normal);
+	let mut clipped = Vec::with_capacity(polygon.len() + 1);
+	for (index, &vertex) in polygon.iter().enumerate() {
+		let next = polygon[(index + 1) % polygon.len()];
+		let (vertex_side, next_side) = (side(vertex), side(next));
+		if vertex_side <= 0. {
+			clipped.push(vertex);
+		}
+		if (vertex_side < 0. && next_side > 0.) || (vertex_side > 0. && next_side < 0.) {
+			clipped.push(vertex.lerp(next, vertex_side / (vertex_side - next_side)));
+		}
+	}
+	clipped
+}
+
+/// Returns the Voronoi cell of each of the points, which is the region of the rectangle between the `bounds` closer to that point than to any other.
+/// Each cell is a convex polygon winding counterclockwise with +y pointing up, which may be empty if its point lies outside of the bounds or is repeated.
+pub fn voronoi_cells(points: &[DVec2], [min, max]: [DVec2; 2]) -> Vec<Vec<DVec2>> {
+	let rectangle = [min, DVec2::new(max.x, min.y), max, DVec2::new(min.x, max.y)];
+	points
+		.iter()
+		.enumerate()
+		.map(|(index, &point)| {
+			// Only the first of a repeated point gets a cell
+			if points[..index].contains(&point) {
+				return Vec::new();
+			}
+			let mut others = points.iter().copied().filter(|&other| other != point).collect::<Vec<_>>();
+			others.sort_by(|a, b| a.distance_squared(point).total_cmp(&b.distance_squared(point)));
+
+			// The cell is cut down by the line halfway to each of the other points in turn, nearest first, until the rest are too far away to reach it
+			let mut cell = rectangle.to_vec();
+			for other in others {
+				let radius = cell.iter().map(|vertex| vertex.distance(point)).fold(0., f64::max);
+				if cell.is_empty() || other.distance(point) > 2. * radius {
+					break;
+				}
+				cell = clip_polygon(&cell, (point + other) / 2., other - point);
+			}
+			cell
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn polygon_area(polygon: &[DVec2]) -> f64 {
+		(0..polygon.len()).map(|index| polygon[index].perp_dot(polygon[(index + 1) % polygon.len()])).sum::<f64>() / 2.
+	}
+
+	#[test]
+	fn delaunay_square() {
+		let points = [DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 10.), DVec2::new(0., 10.), DVec2::new(5., 4.)];
+		let triangles = delaunay_triangles(&points);
+		assert_eq!(triangles.len(), 4);
+		// Every triangle uses the center point, winds counterclockwise and has no other point inside its circumcircle
+		for triangle in &triangles {
+			assert!(triangle.contains(&4));
+			let corners = triangle.map(|index| points[index]);
+			assert!(orientation(corners[0], corners[1], corners[2]) > 0.);
+			assert!(points.iter().all(|&point| !in_circumcircle(corners, point)));
+		}
+		let area = triangles.iter().map(|triangle| polygon_area(&triangle.map(|index| points[index]))).sum::<f64>();
+		assert!((area - 100.).abs() < 1e-9);
+	}
+
+	#[test]
+	fn delaunay_degenerate_points() {
+		assert!(delaunay_triangles(&[DVec2::ZERO, DVec2::ONE]).is_empty());
+		assert!(delaunay_triangles(&[DVec2::ZERO, DVec2::ONE, DVec2::splat(2.), DVec2::splat(3.)]).is_empty());
+		assert_eq!(delaunay_triangles(&[DVec2::ZERO, DVec2::X, DVec2::Y, DVec2::Y]).len(), 1);
+	}
+
+	#[test]
+	fn voronoi_grid() {
+		let points = (0..3).flat_map(|y| (0..3).map(move |x| DVec2::new(x as f64, y as f64) * 10. + 5.)).collect::<Vec<_>>();
+		let cells = voronoi_cells(&points, [DVec2::ZERO, DVec2::splat(30.)]);
+		assert_eq!(cells.len(), 9);
+		for (cell, point) in cells.iter().zip(&points) {
+			assert!((polygon_area(cell) - 100.).abs() < 1e-9);
+			assert!(cell.iter().all(|vertex| vertex.distance(*point) < 7.1));
+		}
+
+		// A repeated point gives an empty cell, leaving the first copy with the whole area
+		let cells = voronoi_cells(&[DVec2::splat(5.), DVec2::splat(5.)], [DVec2::ZERO, DVec2::splat(10.)]);
+		assert!((polygon_area(&cells[0]) - 100.).abs() < 1e-9);
+		assert!(cells[1].is_empty());
+	}
+}
@@ -1,5 +1,10 @@
+mod attributes;
+pub use attributes::*;
+
 pub mod brush_stroke;
 pub mod consts;
+mod delaunay;
+pub use delaunay::*;
 pub mod generator_nodes;
 pub mod manipulator_group;
 pub mod manipulator_point;
@@ -10,6 +15,9 @@ pub use style::PathStyle;
 pub mod subpath;
 pub use subpath::Subpath;
 
+mod symmetry;
+pub use symmetry::*;
+
 mod vector_data;
 pub use vector_data::*;
 
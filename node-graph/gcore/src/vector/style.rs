@@ -190,6 +190,52 @@ impl Fill {
 		}
 	}
 
+	/// Find the fill a fraction `time` of the way from this fill to another, treating no fill as a fully transparent version of the other's color.
+	/// Gradients with the same number of stops are interpolated stop by stop, while any other pair of fills switches over halfway through.
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let transparent = |color: &Color| color.with_alpha(0.);
+		match (self, other) {
+			(Self::Solid(a), Self::Solid(b)) => Self::Solid(a.lerp(*b, time as f32)),
+			(Self::Solid(a), Self::None) => Self::Solid(a.lerp(transparent(a), time as f32)),
+			(Self::None, Self::Solid(b)) => Self::Solid(transparent(b).lerp(*b, time as f32)),
+			(Self::Gradient(a), Self::Gradient(b)) if a.positions.len() == b.positions.len() && a.gradient_type == b.gradient_type => {
+				let positions = a
+					.positions
+					.iter()
+					.zip(&b.positions)
+					.map(|((a_position, a_color), (b_position, b_color))| {
+						let color = match (a_color, b_color) {
+							(Some(a), Some(b)) => Some(a.lerp(*b, time as f32)),
+							_ => {
+								if time < 0.5 {
+									*a_color
+								} else {
+									*b_color
+								}
+							}
+						};
+						(a_position + (b_position - a_position) * time, color)
+					})
+					.collect();
+				let (a_transform, b_transform) = (a.transform.to_cols_array(), b.transform.to_cols_array());
+				Self::Gradient(Gradient {
+					start: a.start.lerp(b.start, time),
+					end: a.end.lerp(b.end, time),
+					transform: DAffine2::from_cols_array(&core::array::from_fn(|index| a_transform[index] + (b_transform[index] - a_transform[index]) * time)),
+					positions,
+					gradient_type: a.gradient_type,
+				})
+			}
+			_ => {
+				if time < 0.5 {
+					self.clone()
+				} else {
+					other.clone()
+				}
+			}
+		}
+	}
+
 	/// Check if the fill is not none
 	pub fn is_some(&self) -> bool {
 		*self != Self::None
@@ -251,6 +297,26 @@ impl Display for LineJoin {
 	}
 }
 
+/// Rule used to decide which regions enclosed by the subpaths of a shape are inside of it, as defined in SVG.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum FillRule {
+	/// A point is inside if the subpaths wind around it a nonzero number of times, counting those winding the other way negatively.
+	#[default]
+	NonZero,
+	/// A point is inside if the subpaths wind around it an odd number of times.
+	EvenOdd,
+}
+
+impl From<FillRule> for bezier_rs::FillRule {
+	fn from(fill_rule: FillRule) -> Self {
+		match fill_rule {
+			FillRule::NonZero => bezier_rs::FillRule::NonZero,
+			FillRule::EvenOdd => bezier_rs::FillRule::EvenOdd,
+		}
+	}
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, DynAny, specta::Type)]
 pub struct Stroke {
@@ -344,6 +410,20 @@ impl Stroke {
 		}
 	}
 
+	/// Find the stroke a fraction `time` of the way from this stroke to another, interpolating the color and weight.
+	/// A missing color is treated as a fully transparent version of the other's, and the remaining options switch over halfway through.
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let mut stroke = if time < 0.5 { self.clone() } else { other.clone() };
+		stroke.color = match (self.color, other.color) {
+			(Some(a), Some(b)) => Some(a.lerp(b, time as f32)),
+			(Some(a), None) => Some(a.lerp(a.with_alpha(0.), time as f32)),
+			(None, Some(b)) => Some(b.with_alpha(0.).lerp(b, time as f32)),
+			(None, None) => None,
+		};
+		stroke.weight = self.weight + (other.weight - self.weight) * time;
+		stroke
+	}
+
 	pub fn with_color(mut self, color: &Option<Color>) -> Option<Self> {
 		self.color = *color;
 
@@ -529,6 +609,35 @@ impl PathStyle {
 		self.stroke = None;
 	}
 
+	/// Find the style a fraction `time` of the way from this style to another, from `0` for this style to `1` for the other.
+	/// A missing stroke is treated as a stroke of no weight in the same color as the other's, so that it fades in or out.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{Fill, Stroke, PathStyle};
+	/// # use graphene_core::raster::color::Color;
+	/// let black = PathStyle::new(Some(Stroke::new(Some(Color::BLACK), 2.)), Fill::Solid(Color::BLACK));
+	/// let white = PathStyle::new(Some(Stroke::new(Some(Color::WHITE), 6.)), Fill::Solid(Color::WHITE));
+	///
+	/// let gray = black.lerp(&white, 0.5);
+	///
+	/// assert_eq!(gray.fill().color(), Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.));
+	/// assert_eq!(gray.stroke().unwrap().weight(), 4.);
+	/// ```
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let stroke = match (&self.stroke, &other.stroke) {
+			(Some(a), Some(b)) => Some(a.lerp(b, time)),
+			(Some(a), None) => Some(a.lerp(&a.clone().with_weight(0.), time)),
+			(None, Some(b)) => Some(b.clone().with_weight(0.).lerp(b, time)),
+			(None, None) => None,
+		};
+		Self {
+			stroke,
+			fill: self.fill.lerp(&other.fill, time),
+			opacity: self.opacity + (other.opacity - self.opacity) * time as f32,
+		}
+	}
+
 	pub fn render(&self, view_mode: ViewMode, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
 		let fill_attribute = match (view_mode, &self.fill) {
 			(ViewMode::Outline, _) => Fill::None.render(svg_defs, multiplied_transform, bounds, transformed_bounds, self.opacity),
@@ -0,0 +1,227 @@
+use super::{VectorAttributes, VectorData};
+use crate::uuid::ManipulatorGroupId;
+use crate::{GraphicElement, GraphicGroup};
+
+use bezier_rs::{Identifier, ManipulatorGroup, Subpath};
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+use serde::{Deserialize, Serialize};
+
+/// Distance within which an end of a subpath counts as lying on a mirror axis, so that it can be welded to its reflection.
+const WELD_TOLERANCE: f64 = 1e-3;
+
+/// The kind of symmetry the Symmetry node gives to its data.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum SymmetryType {
+	/// Reflected horizontally, across a vertical axis through the center.
+	#[default]
+	Horizontal,
+	/// Reflected vertically, across a horizontal axis through the center.
+	Vertical,
+	/// Reflected across both axes, giving four copies.
+	Both,
+	/// Turned about the center into evenly spaced copies.
+	Radial,
+}
+
+/// Returns the transform reflecting points across the line through `center` in the direction at `angle` radians from the x-axis.
+pub fn reflection(center: DVec2, angle: f64) -> DAffine2 {
+	DAffine2::from_translation(center) * DAffine2::from_angle(angle) * DAffine2::from_scale(DVec2::new(1., -1.)) * DAffine2::from_angle(-angle) * DAffine2::from_translation(-center)
+}
+
+/// Returns the reflections which are applied one after another to give the symmetry, each doubling the number of copies, with the axes turned by `angle` degrees.
+/// Radial symmetry has no reflections.
+fn reflections(symmetry_type: SymmetryType, center: DVec2, angle: f64) -> Vec<DAffine2> {
+	let angle = angle.to_radians();
+	let vertical_axis = reflection(center, angle + core::f64::consts::FRAC_PI_2);
+	let horizontal_axis = reflection(center, angle);
+	match symmetry_type {
+		SymmetryType::Horizontal => vec![vertical_axis],
+		SymmetryType::Vertical => vec![horizontal_axis],
+		SymmetryType::Both => vec![vertical_axis, horizontal_axis],
+		SymmetryType::Radial => Vec::new(),
+	}
+}
+
+/// Returns the transform of each copy making up the symmetry, starting with the identity for the original data.
+/// Radial symmetry turns `count` copies about the `center`, while the other types reflect across axes turned by `angle` degrees.
+pub fn symmetry_transforms(symmetry_type: SymmetryType, center: DVec2, angle: f64, count: u32) -> Vec<DAffine2> {
+	if symmetry_type == SymmetryType::Radial {
+		let count = count.max(1);
+		return (0..count)
+			.map(|index| DAffine2::from_translation(center) * DAffine2::from_angle(core::f64::consts::TAU * index as f64 / count as f64) * DAffine2::from_translation(-center))
+			.collect();
+	}
+
+	let mut transforms = vec![DAffine2::IDENTITY];
+	for reflection in reflections(symmetry_type, center, angle) {
+		let reflected = transforms.iter().map(|&transform| reflection * transform).collect::<Vec<_>>();
+		transforms.extend(reflected);
+	}
+	transforms
+}
+
+/// Returns the subpath joined to its reflection by the `reflection` at its ends which lie on the mirror axis, or `None` if neither end does or the subpath is closed.
+/// With one end on the axis, the result is an open subpath running out to the reflection of the other end. With both ends on the axis, the result is closed.
+/// Each welded anchor is moved onto the axis, keeping the handle of the subpath on one side and the reflected handle on the other.
+pub fn weld_reflection(subpath: &Subpath<ManipulatorGroupId>, reflection: DAffine2) -> Option<Subpath<ManipulatorGroupId>> {
+	if subpath.closed() || subpath.len() < 2 {
+		return None;
+	}
+
+	let on_axis = |point: DVec2| reflection.transform_point2(point).distance(point) < WELD_TOLERANCE;
+	let groups = subpath.manipulator_groups();
+	let (start_on_axis, end_on_axis) = (on_axis(groups[0].anchor), on_axis(groups[groups.len() - 1].anchor));
+
+	// The subpath is followed so that it ends on the axis, then carries on back along its reflection
+	let mut groups = match (start_on_axis, end_on_axis) {
+		(_, true) => groups.to_vec(),
+		(true, false) => subpath.reverse().manipulator_groups().to_vec(),
+		(false, false) => return None,
+	};
+	let mut reflected = groups.iter().rev().map(|group| reflect_group(group, reflection)).collect::<Vec<_>>();
+
+	let last = groups.last_mut().unwrap();
+	last.anchor = (last.anchor + reflection.transform_point2(last.anchor)) / 2.;
+	last.out_handle = reflected[0].out_handle;
+	reflected.remove(0);
+
+	let closed = start_on_axis && end_on_axis;
+	if closed {
+		let end = reflected.pop().unwrap();
+		let first = &mut groups[0];
+		first.anchor = (first.anchor + reflection.transform_point2(first.anchor)) / 2.;
+		first.in_handle = end.in_handle;
+	}
+	groups.extend(reflected);
+	Some(Subpath::new(groups, closed))
+}
+
+/// Returns the reflection of the manipulator group as it is followed in reverse, so its handles swap places.
+/// The reflection is given a new id, since it is welded into the same subpath as the manipulator group it reflects.
+fn reflect_group(group: &ManipulatorGroup<ManipulatorGroupId>, reflection: DAffine2) -> ManipulatorGroup<ManipulatorGroupId> {
+	ManipulatorGroup {
+		anchor: reflection.transform_point2(group.anchor),
+		in_handle: group.out_handle.map(|handle| reflection.transform_point2(handle)),
+		out_handle: group.in_handle.map(|handle| reflection.transform_point2(handle)),
+		id: ManipulatorGroupId::new(),
+	}
+}
+
+/// Data which can be made symmetric by the Symmetry node, with `center` and `angle` in the coordinates of the data before its own transform.
+pub trait Symmetric {
+	/// Returns the data combined with its reflected or turned copies. If `weld` is set, subpaths which end on a mirror axis are joined to their reflections where possible.
+	fn make_symmetric(self, symmetry_type: SymmetryType, center: DVec2, angle: f64, count: u32, weld: bool) -> Self;
+}
+
+impl Symmetric for VectorData {
+	fn make_symmetric(mut self, symmetry_type: SymmetryType, center: DVec2, angle: f64, count: u32, weld: bool) -> Self {
+		if symmetry_type == SymmetryType::Radial {
+			let transforms = symmetry_transforms(symmetry_type, center, angle, count);
+			self.subpaths = transforms
+				.iter()
+				.flat_map(|&transform| {
+					self.subpaths.iter().map(move |subpath| {
+						let mut subpath = subpath.clone();
+						subpath.apply_transform(transform);
+						subpath
+					})
+				})
+				.collect();
+			self.attributes.repeat(transforms.len());
+			return self;
+		}
+
+		// Each reflection is applied to the result of the previous one, so that the quarters of a shape welded across one axis are then welded across the other
+		for reflection in reflections(symmetry_type, center, angle) {
+			let mut originals = Vec::with_capacity(self.subpaths.len());
+			let mut reflected_subpaths = Vec::with_capacity(self.subpaths.len());
+			let mut welded_any = false;
+			for subpath in &self.subpaths {
+				match weld.then(|| weld_reflection(subpath, reflection)).flatten() {
+					Some(welded) => {
+						originals.push(welded);
+						welded_any = true;
+					}
+					None => {
+						let mut reflected = subpath.clone();
+						reflected.apply_transform(reflection);
+						originals.push(subpath.clone());
+						reflected_subpaths.push(reflected);
+					}
+				}
+			}
+			originals.extend(reflected_subpaths);
+			self.subpaths = originals;
+
+			// Welding merges anchors and subpaths, so the attributes no longer line up with them
+			if welded_any {
+				self.attributes = VectorAttributes::new();
+			} else {
+				self.attributes.repeat(2);
+			}
+		}
+		self
+	}
+}
+
+impl Symmetric for GraphicGroup {
+	fn make_symmetric(self, symmetry_type: SymmetryType, center: DVec2, angle: f64, count: u32, _weld: bool) -> Self {
+		let mut copies = GraphicGroup::EMPTY;
+		for transform in symmetry_transforms(symmetry_type, center, angle, count) {
+			let mut copy = self.clone();
+			copy.transform = self.transform * transform;
+			copies.push(GraphicElement {
+				graphic_element_data: copy.into(),
+				..Default::default()
+			});
+		}
+		copies
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn symmetry_transform_counts() {
+		assert_eq!(symmetry_transforms(SymmetryType::Horizontal, DVec2::ZERO, 0., 0).len(), 2);
+		assert_eq!(symmetry_transforms(SymmetryType::Both, DVec2::ZERO, 0., 0).len(), 4);
+		assert_eq!(symmetry_transforms(SymmetryType::Radial, DVec2::ZERO, 0., 6).len(), 6);
+
+		// The vertical axis through the center reflects points horizontally
+		let transforms = symmetry_transforms(SymmetryType::Horizontal, DVec2::new(10., 0.), 0., 0);
+		assert!(transforms[1].transform_point2(DVec2::new(0., 5.)).abs_diff_eq(DVec2::new(20., 5.), 1e-9));
+	}
+
+	#[test]
+	fn weld_half_shapes() {
+		// Half of a diamond, with both ends on the vertical axis, is welded into a closed diamond
+		let half = Subpath::from_anchors([DVec2::new(0., -10.), DVec2::new(-10., 0.), DVec2::new(0., 10.)], false);
+		let vector_data = VectorData::from_subpath(half).make_symmetric(SymmetryType::Horizontal, DVec2::ZERO, 0., 0, true);
+		assert_eq!(vector_data.subpaths.len(), 1);
+		assert!(vector_data.subpaths[0].closed());
+		assert_eq!(vector_data.subpaths[0].len(), 4);
+		assert!((vector_data.subpaths[0].area() - 200.).abs() < 1e-9);
+
+		// The reflected half is given its own ids, so every manipulator group of the welded subpath can be told apart
+		let ids = vector_data.subpaths[0].manipulator_groups().iter().map(|group| group.id).collect::<std::collections::HashSet<_>>();
+		assert_eq!(ids.len(), 4);
+
+		// A quarter with an end on each axis becomes a single closed shape when reflected across both
+		let quarter = Subpath::from_anchors([DVec2::new(10., 0.), DVec2::new(10., 10.), DVec2::new(0., 10.)], false);
+		let vector_data = VectorData::from_subpath(quarter).make_symmetric(SymmetryType::Both, DVec2::ZERO, 0., 0, true);
+		assert_eq!(vector_data.subpaths.len(), 1);
+		assert!(vector_data.subpaths[0].closed());
+		assert!((vector_data.subpaths[0].area() - 400.).abs() < 1e-9);
+
+		// Without welding, or away from the axis, the reflection is a separate subpath
+		let line = Subpath::from_anchors([DVec2::new(-5., 0.), DVec2::new(-10., 0.)], false);
+		let vector_data = VectorData::from_subpath(line.clone()).make_symmetric(SymmetryType::Horizontal, DVec2::ZERO, 0., 0, true);
+		assert_eq!(vector_data.subpaths.len(), 2);
+		let vector_data = VectorData::from_subpath(line).make_symmetric(SymmetryType::Horizontal, DVec2::new(-5., 0.), 0., 0, false);
+		assert_eq!(vector_data.subpaths.len(), 2);
+	}
+}
@@ -1,4 +1,5 @@
 use super::style::{PathStyle, Stroke};
+use super::{AttributeDomain, AttributeValues, VectorAttributes};
 use crate::uuid::ManipulatorGroupId;
 use crate::Color;
 
@@ -8,7 +9,7 @@ use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
 
 /// [VectorData] is passed between nodes.
-/// It contains a list of subpaths (that may be open or closed), a transform and some style information.
+/// It contains a list of subpaths (that may be open or closed), a transform, some style information and named attributes for the points and subpaths.
 #[derive(Clone, Debug, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VectorData {
@@ -16,6 +17,8 @@ pub struct VectorData {
 	pub transform: DAffine2,
 	pub style: PathStyle,
 	pub mirror_angle: Vec<ManipulatorGroupId>,
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub attributes: VectorAttributes,
 }
 
 impl core::hash::Hash for VectorData {
@@ -24,6 +27,7 @@ impl core::hash::Hash for VectorData {
 		self.transform.to_cols_array().iter().for_each(|x| x.to_bits().hash(state));
 		self.style.hash(state);
 		self.mirror_angle.hash(state);
+		self.attributes.hash(state);
 	}
 }
 
@@ -35,6 +39,7 @@ impl VectorData {
 			transform: DAffine2::IDENTITY,
 			style: PathStyle::new(Some(Stroke::new(Some(Color::BLACK), 0.)), super::style::Fill::None),
 			mirror_angle: Vec::new(),
+			attributes: VectorAttributes::new(),
 		}
 	}
 
@@ -57,6 +62,19 @@ impl VectorData {
 		super::VectorData { subpaths, ..Self::empty() }
 	}
 
+	/// Get the number of elements in the domain, which is the number of values each of its attributes needs.
+	pub fn domain_len(&self, domain: AttributeDomain) -> usize {
+		match domain {
+			AttributeDomain::Point => self.subpaths.iter().map(|subpath| subpath.len()).sum(),
+			AttributeDomain::Subpath => self.subpaths.len(),
+		}
+	}
+
+	/// Get the values of the named attribute, if it has exactly one value for each point or subpath.
+	pub fn attribute(&self, domain: AttributeDomain, name: &str) -> Option<&AttributeValues> {
+		self.attributes.get(domain, name).filter(|values| values.len() == self.domain_len(domain))
+	}
+
 	/// Compute the bounding boxes of the subpaths without any transform
 	pub fn bounding_box(&self) -> Option<[DVec2; 2]> {
 		self.bounding_box_with_transform(DAffine2::IDENTITY)
@@ -1,10 +1,15 @@
 use super::style::{Fill, FillType, Gradient, GradientType, Stroke};
-use super::VectorData;
-use crate::{Color, Node};
+use super::{AttributeDomain, AttributeValues, VectorData};
+use crate::graphic_element::renderer::GraphicElementRendered;
+use crate::raster::curve::Curve;
+use crate::uuid::ManipulatorGroupId;
+use crate::{Color, GraphicElement, GraphicGroup, Node};
 
-use bezier_rs::{Subpath, SubpathTValue};
-use glam::{DAffine2, DVec2};
+use bezier_rs::{Cap, Corner, Join, Subpath, TValue};
+use glam::{DAffine2, DVec2, DVec3};
 use num_traits::Zero;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
 #[derive(Debug, Clone, Copy)]
 pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions> {
@@ -75,6 +80,27 @@ fn set_vector_data_stroke(
 	vector_data
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct SetAttributeNode<Name, PerSubpath, Values> {
+	name: Name,
+	per_subpath: PerSubpath,
+	values: Values,
+}
+
+#[node_macro::node_fn(SetAttributeNode)]
+fn set_attribute(mut vector_data: VectorData, name: String, per_subpath: bool, values: Vec<f32>) -> VectorData {
+	let domain = if per_subpath { AttributeDomain::Subpath } else { AttributeDomain::Point };
+	if values.is_empty() {
+		vector_data.attributes.remove(domain, &name);
+		return vector_data;
+	}
+
+	// The values are repeated in turn until every point or subpath has one
+	let values = values.iter().cycle().take(vector_data.domain_len(domain)).map(|&value| value as f64).collect();
+	vector_data.attributes.insert(domain, name, AttributeValues::F64(values));
+	vector_data
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RepeatNode<Direction, Count> {
 	direction: Direction,
@@ -98,6 +124,7 @@ fn repeat_vector_data(mut vector_data: VectorData, direction: DVec2, count: u32)
 	}
 
 	vector_data.subpaths = new_subpaths;
+	vector_data.attributes.repeat(count as usize);
 	vector_data
 }
 
@@ -133,9 +160,335 @@ fn circular_repeat_vector_data(mut vector_data: VectorData, angle_offset: f32, r
 	}
 
 	vector_data.subpaths = new_subpaths;
+	vector_data.attributes.repeat(count as usize);
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CopyToPointsNode<Instance, AlignToTangent, RandomScaleMin, RandomScaleMax, RandomRotation, IndexScale, IndexRotation, Seed> {
+	instance: Instance,
+	align_to_tangent: AlignToTangent,
+	random_scale_min: RandomScaleMin,
+	random_scale_max: RandomScaleMax,
+	random_rotation: RandomRotation,
+	index_scale: IndexScale,
+	index_rotation: IndexRotation,
+	seed: Seed,
+}
+
+/// Returns a random number in the range `0..1`, using the 53 bits of precision of an `f64`.
+fn random_unit(rng: &mut ChaCha8Rng) -> f64 {
+	(rng.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+/// Returns the direction of the subpath at a manipulator group once placed by the transform, taken from the segment which starts at the point, or ends at it for the last point of an open subpath.
+/// Where that segment has no direction at the point, such as when it has zero length or a handle lying on the anchor, the segment on the other side of the point is tried, then the direction from the start to the end of either segment.
+/// Returns `None` if there is no direction to be found, such as for a lone point.
+fn tangent_at_group(subpath: &Subpath<ManipulatorGroupId>, group_index: usize, transform: DAffine2) -> Option<DVec2> {
+	let segments = subpath.len_segments();
+	let starting = (group_index < segments).then_some(group_index);
+	let ending = if group_index > 0 {
+		Some(group_index - 1)
+	} else {
+		subpath.closed().then(|| segments.checked_sub(1)).flatten()
+	};
+	let (starting, ending) = (starting.and_then(|index| subpath.get_segment(index)), ending.and_then(|index| subpath.get_segment(index)));
+
+	let tangents = [
+		starting.map(|bezier| bezier.tangent(TValue::Parametric(0.))),
+		ending.map(|bezier| bezier.tangent(TValue::Parametric(1.))),
+	];
+	let chords = [starting, ending].map(|bezier| bezier.map(|bezier| bezier.end() - bezier.start()));
+	tangents
+		.into_iter()
+		.chain(chords)
+		.flatten()
+		.find_map(|direction| transform.transform_vector2(direction).try_normalize())
+}
+
+#[node_macro::node_fn(CopyToPointsNode)]
+fn copy_to_points<Data: Into<GraphicGroup>>(
+	points: VectorData,
+	instance: Data,
+	align_to_tangent: bool,
+	random_scale_min: f64,
+	random_scale_max: f64,
+	random_rotation: f64,
+	index_scale: f64,
+	index_rotation: f64,
+	seed: u32,
+) -> GraphicGroup {
+	let instance: GraphicGroup = instance.into();
+	let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+
+	let mut instances = GraphicGroup::EMPTY;
+	let mut index = 0;
+	for subpath in &points.subpaths {
+		for (group_index, group) in subpath.manipulator_groups().iter().enumerate() {
+			// Both random values are drawn for every point, so that each instance keeps its variation when the others change
+			let (random_scale, random_angle) = (random_unit(&mut rng), random_unit(&mut rng));
+			// A negative scale would mirror the copy, so it shrinks away to nothing instead
+			let scale = (random_scale_min + (random_scale_max - random_scale_min) * random_scale + index_scale * index as f64).max(0.);
+			let mut angle = (random_rotation * (2. * random_angle - 1.) + index_rotation * index as f64).to_radians();
+
+			if align_to_tangent {
+				if let Some(tangent) = tangent_at_group(subpath, group_index, points.transform) {
+					angle += tangent.y.atan2(tangent.x);
+				}
+			}
+
+			let position = points.transform.transform_point2(group.anchor);
+			let mut copy = instance.clone();
+			copy.transform = DAffine2::from_scale_angle_translation(DVec2::splat(scale), angle, position) * copy.transform;
+			instances.push(GraphicElement {
+				graphic_element_data: copy.into(),
+				..Default::default()
+			});
+			index += 1;
+		}
+	}
+
+	instances
+}
+
+/// Returns a copy of the instance for the given step of a repeat, turned by `index * rotation` degrees and scaled by `scale` to the power of `index` about the `pivot`, then moved by the `transform`.
+/// Each step also loses `opacity_falloff` of the instance's full opacity, until it disappears.
+fn repeat_step(instance: &GraphicGroup, index: usize, pivot: DVec2, transform: DAffine2, rotation: f64, scale: f64, opacity_falloff: f64) -> GraphicElement {
+	let step = index as f64;
+	let mut copy = instance.clone();
+	let step_transform = DAffine2::from_scale_angle_translation(DVec2::splat(scale.powf(step)), (rotation * step).to_radians(), pivot) * DAffine2::from_translation(-pivot);
+	copy.transform = transform * step_transform * copy.transform;
+	copy.opacity *= (1. - opacity_falloff * step).clamp(0., 1.) as f32;
+	GraphicElement {
+		graphic_element_data: copy.into(),
+		..Default::default()
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GridRepeatNode<Columns, Rows, Spacing, Stagger, Rotation, Scale, OpacityFalloff> {
+	columns: Columns,
+	rows: Rows,
+	spacing: Spacing,
+	stagger: Stagger,
+	rotation: Rotation,
+	scale: Scale,
+	opacity_falloff: OpacityFalloff,
+}
+
+#[node_macro::node_fn(GridRepeatNode)]
+fn grid_repeat<Data: Into<GraphicGroup>>(instance: Data, columns: u32, rows: u32, spacing: DVec2, stagger: f64, rotation: f64, scale: f64, opacity_falloff: f64) -> GraphicGroup {
+	let instance: GraphicGroup = instance.into();
+	// Each copy turns and scales about the center of the instance, which stays in place for the first copy
+	let pivot = instance.bounding_box(DAffine2::IDENTITY).map_or(DVec2::ZERO, |[min, max]| (min + max) / 2.);
+
+	let mut instances = GraphicGroup::EMPTY;
+	for row in 0..rows {
+		// Every other row is shifted along by a fraction of the spacing between columns
+		let shift = if row % 2 == 1 { stagger * spacing.x } else { 0. };
+		for column in 0..columns {
+			let index = row as usize * columns as usize + column as usize;
+			let offset = DVec2::new(column as f64 * spacing.x + shift, row as f64 * spacing.y);
+			instances.push(repeat_step(&instance, index, pivot, DAffine2::from_translation(offset), rotation, scale, opacity_falloff));
+		}
+	}
+
+	instances
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatAlongPathNode<Instance, Count, AlignToPath, Rotation, Scale, OpacityFalloff> {
+	instance: Instance,
+	count: Count,
+	align_to_path: AlignToPath,
+	rotation: Rotation,
+	scale: Scale,
+	opacity_falloff: OpacityFalloff,
+}
+
+#[node_macro::node_fn(RepeatAlongPathNode)]
+fn repeat_along_path<Data: Into<GraphicGroup>>(path: VectorData, instance: Data, count: u32, align_to_path: bool, rotation: f64, scale: f64, opacity_falloff: f64) -> GraphicGroup {
+	let instance: GraphicGroup = instance.into();
+	let mut instances = GraphicGroup::EMPTY;
+	// The instances are spread over the subpaths of the path as if they were joined end to end, measuring their lengths in the coordinates of the document
+	let subpaths = path
+		.subpaths
+		.iter()
+		.filter(|subpath| subpath.len_segments() > 0)
+		.map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(path.transform);
+			subpath
+		})
+		.collect::<Vec<_>>();
+	let tables = subpaths.iter().map(|subpath| subpath.arc_length_table(None)).collect::<Vec<_>>();
+	let length = tables.iter().map(|table| table.length()).sum::<f64>();
+	if tables.is_empty() || count == 0 {
+		return instances;
+	}
+
+	// A path made only of closed subpaths has no ends, so the last instance stops one spacing short of coming back around to the first
+	let intervals = if subpaths.iter().all(|subpath| subpath.closed()) { count } else { count - 1 };
+	let spacing = if intervals == 0 { 0. } else { length / intervals as f64 };
+	let mut table_index = 0;
+	let mut table_start = 0.;
+	for index in 0..count as usize {
+		let distance = index as f64 * spacing;
+		while table_index + 1 < tables.len() && distance > table_start + tables[table_index].length() {
+			table_start += tables[table_index].length();
+			table_index += 1;
+		}
+		let frame = tables[table_index].frame_at_distance((distance - table_start).min(tables[table_index].length()));
+
+		// As with Copy to Points, the origin of the instance is placed on the path
+		let angle = if align_to_path { frame.tangent.y.atan2(frame.tangent.x) } else { 0. };
+		let transform = DAffine2::from_angle_translation(angle, frame.position);
+		instances.push(repeat_step(&instance, index, DVec2::ZERO, transform, rotation, scale, opacity_falloff));
+	}
+
+	instances
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetryNode<SymmetryType, Center, Angle, Count, Weld> {
+	symmetry_type: SymmetryType,
+	center: Center,
+	angle: Angle,
+	count: Count,
+	weld: Weld,
+}
+
+#[node_macro::node_fn(SymmetryNode)]
+fn symmetry<Data: super::Symmetric>(data: Data, symmetry_type: super::SymmetryType, center: DVec2, angle: f64, count: u32, weld: bool) -> Data {
+	data.make_symmetric(symmetry_type, center, angle, count, weld)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScatterPointsNode<AlongOutline, Spacing, Count, FillRule, Seed> {
+	along_outline: AlongOutline,
+	spacing: Spacing,
+	count: Count,
+	fill_rule: FillRule,
+	seed: Seed,
+}
+
+#[node_macro::node_fn(ScatterPointsNode)]
+fn scatter_points(mut vector_data: VectorData, along_outline: bool, spacing: f64, count: u32, fill_rule: super::style::FillRule, seed: u32) -> VectorData {
+	let transform = vector_data.transform;
+	let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+	let fill_rule: bezier_rs::FillRule = fill_rule.into();
+
+	// Points are scattered in the coordinates of the document, so the spacing between them is even under a non-uniform scale
+	let subpaths = vector_data
+		.subpaths
+		.iter()
+		.map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform);
+			subpath
+		})
+		.collect::<Vec<_>>();
+	let tables = subpaths
+		.iter()
+		.filter(|subpath| subpath.len_segments() > 0)
+		.map(|subpath| subpath.arc_length_table(None))
+		.collect::<Vec<_>>();
+	let length = tables.iter().map(|table| table.length()).sum::<f64>();
+	let [min, max] = vector_data.bounding_box_with_transform(transform).unwrap_or_default();
+
+	// Each candidate is a uniformly random point on the outline, or within the bounding box, which is rejected if it falls outside of the filled area
+	let candidate = |rng: &mut ChaCha8Rng| {
+		if along_outline {
+			let mut distance = random_unit(rng) * length;
+			for table in &tables {
+				if distance <= table.length() {
+					return Some(table.frame_at_distance(distance).position);
+				}
+				distance -= table.length();
+			}
+			None
+		} else {
+			let point = min + (max - min) * DVec2::new(random_unit(rng), random_unit(rng));
+			let winding = subpaths.iter().map(|subpath| subpath.winding(point)).sum();
+			fill_rule.is_inside(winding).then_some(point)
+		}
+	};
+
+	// A minimum spacing gives a Poisson-disk distribution, with candidates rejected if they fall too close to an earlier point.
+	// The grid cells are small enough to hold at most one point each, so only the nearby cells need to be checked.
+	let cell_size = spacing / core::f64::consts::SQRT_2;
+	let cell = |point: DVec2| ((point.x / cell_size).floor() as i64, (point.y / cell_size).floor() as i64);
+	let mut grid = std::collections::HashMap::new();
+	let mut points = Vec::new();
+	let attempts = count as usize * crate::consts::SCATTER_MAX_ATTEMPTS_PER_POINT;
+	for _ in 0..attempts {
+		if points.len() >= count as usize {
+			break;
+		}
+		let Some(point) = candidate(&mut rng) else { continue };
+		if spacing > 0. {
+			let (x, y) = cell(point);
+			let too_close = (x - 2..=x + 2).any(|x| (y - 2..=y + 2).any(|y| grid.get(&(x, y)).map_or(false, |other: &DVec2| other.distance(point) < spacing)));
+			if too_close {
+				continue;
+			}
+			grid.insert((x, y), point);
+		}
+		points.push(point);
+	}
+
+	// A transform which squashes the layer flat has no inverse, so the points are then left in the coordinates of the document
+	if transform.matrix2.determinant() == 0. {
+		vector_data.transform = DAffine2::IDENTITY;
+	}
+	let inverse = vector_data.transform.inverse();
+	vector_data.subpaths = points.into_iter().map(|point| Subpath::from_anchors([inverse.transform_point2(point)], false)).collect();
 	vector_data
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct DelaunayTriangulationNode;
+
+#[node_macro::node_fn(DelaunayTriangulationNode)]
+fn delaunay_triangulation(points: Vec<DVec2>) -> VectorData {
+	let triangles = super::delaunay_triangles(&points);
+	VectorData::from_subpaths(triangles.into_iter().map(|triangle| Subpath::from_anchors(triangle.map(|index| points[index]), true)).collect())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VoronoiCellsNode<Boundary> {
+	boundary: Boundary,
+}
+
+#[node_macro::node_fn(VoronoiCellsNode)]
+fn voronoi_cells(points: Vec<DVec2>, boundary: VectorData) -> VectorData {
+	// The points are taken to lie in the coordinates of the boundary before its transform, as they do when extracted from the same layer,
+	// so the cells are clipped there and then placed by the transform of the boundary
+	let bounds = boundary.bounding_box().or_else(|| {
+		let mut points = points.iter().copied();
+		let first = points.next()?;
+		Some(points.fold([first, first], |[min, max], point| [min.min(point), max.max(point)]))
+	});
+	let Some(bounds) = bounds else { return VectorData::empty() };
+
+	// The cells are clipped to the boundary shape, or to the bounding box of the points if there is none
+	let subpaths = super::voronoi_cells(&points, bounds)
+		.into_iter()
+		.filter(|cell| cell.len() >= 3)
+		.flat_map(|cell| {
+			let cell = Subpath::from_anchors(cell, true);
+			if boundary.subpaths.is_empty() {
+				vec![cell]
+			} else {
+				Subpath::boolean_operation(&[cell], &boundary.subpaths, bezier_rs::BooleanOperation::Intersection, bezier_rs::FillRule::NonZero)
+			}
+		})
+		.collect();
+	let mut cells = VectorData::from_subpaths(subpaths);
+	cells.transform = boundary.transform;
+	cells
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BoundingBoxNode;
 
@@ -155,25 +508,434 @@ pub struct ResamplePoints<Spacing> {
 
 #[node_macro::node_fn(ResamplePoints)]
 fn resample_points(mut vector_data: VectorData, spacing: f64) -> VectorData {
+	// Each new point takes its attributes from the original points at either end of the segment it lands on, blended by how far along the segment it is
+	let mut samples = Vec::new();
+	let mut first_point = 0;
 	for subpath in &mut vector_data.subpaths {
+		let point_count = subpath.len();
+		let unchanged = (first_point..first_point + point_count).map(|index| (index, index, 0.));
 		if subpath.is_empty() || spacing.is_zero() || !spacing.is_finite() {
+			samples.extend(unchanged);
+			first_point += point_count;
 			continue;
 		}
 
 		subpath.apply_transform(vector_data.transform);
-		let length = subpath.length(None);
+		let arc_length_table = subpath.arc_length_table(None);
+		let length = arc_length_table.length();
 		let rounded_count = (length / spacing).round();
 
 		if rounded_count >= 1. {
-			let new_anchors = (0..=rounded_count as usize).map(|c| subpath.evaluate(SubpathTValue::GlobalEuclidean(c as f64 / rounded_count)));
+			let distances = (0..=rounded_count as usize).map(|c| c as f64 * length / rounded_count).collect::<Vec<_>>();
+			let new_anchors = distances.iter().map(|&distance| arc_length_table.evaluate_at_distance(distance)).collect::<Vec<_>>();
+
+			let segment_lengths = subpath.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>();
+			for distance in distances {
+				let (mut segment, mut segment_start) = (0, 0.);
+				while segment + 1 < segment_lengths.len() && distance > segment_start + segment_lengths[segment] {
+					segment_start += segment_lengths[segment];
+					segment += 1;
+				}
+				let segment_length = segment_lengths.get(segment).copied().unwrap_or_default();
+				let t = if segment_length > 0. { (distance - segment_start) / segment_length } else { 0. };
+				samples.push((first_point + segment, first_point + (segment + 1) % point_count, t));
+			}
+
 			*subpath = Subpath::from_anchors(new_anchors, subpath.closed() && rounded_count as usize > 1);
+		} else {
+			samples.extend(unchanged);
 		}
 
 		subpath.apply_transform(vector_data.transform.inverse());
+		first_point += point_count;
+	}
+
+	vector_data.attributes.interpolate(AttributeDomain::Point, &samples);
+	vector_data
+}
+
+/// Returns the transform taking the layer into the coordinates of the document, where nodes measure lengths before mapping their result back.
+/// A transform which squashes the layer flat has no inverse to map back with, so the identity is returned to keep the layer in its own coordinates instead.
+fn invertible_transform(transform: DAffine2) -> DAffine2 {
+	if transform.matrix2.determinant() != 0. {
+		transform
+	} else {
+		DAffine2::IDENTITY
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DashNode<DashLengths, DashOffset> {
+	dash_lengths: DashLengths,
+	dash_offset: DashOffset,
+}
+
+#[node_macro::node_fn(DashNode)]
+fn dash_vector_data(mut vector_data: VectorData, dash_lengths: Vec<f32>, dash_offset: f32) -> VectorData {
+	let dash_lengths = dash_lengths.iter().map(|&length| length as f64).collect::<Vec<_>>();
+	let transform = invertible_transform(vector_data.transform);
+
+	// Measure the dashes in the coordinates of the document, in the same way as the dashes of a stroke
+	let mut new_subpaths = Vec::with_capacity(vector_data.subpaths.len());
+	for mut subpath in vector_data.subpaths {
+		subpath.apply_transform(transform);
+		for mut dash in subpath.dash(&dash_lengths, dash_offset as f64) {
+			dash.apply_transform(transform.inverse());
+			new_subpaths.push(dash);
+		}
+	}
+
+	vector_data.subpaths = new_subpaths;
+	vector_data
+}
+
+/// Returns the join and cap with which the stroke is drawn, as used by Bezier-rs to outline it.
+fn stroke_join_and_cap(stroke: &Stroke) -> (Join, Cap) {
+	let join = match stroke.line_join {
+		super::style::LineJoin::Miter => Join::Miter(Some(stroke.line_join_miter_limit)),
+		super::style::LineJoin::Bevel => Join::Bevel,
+		super::style::LineJoin::Round => Join::Round,
+	};
+	let cap = match stroke.line_cap {
+		super::style::LineCap::Butt => Cap::Butt,
+		super::style::LineCap::Round => Cap::Round,
+		super::style::LineCap::Square => Cap::Square,
+	};
+	(join, cap)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OutlineStrokeNode {}
+
+#[node_macro::node_fn(OutlineStrokeNode)]
+fn outline_stroke(mut vector_data: VectorData) -> VectorData {
+	let Some(stroke) = vector_data.style.stroke() else {
+		return vector_data;
+	};
+	let (join, cap) = stroke_join_and_cap(&stroke);
+	let dash_lengths = stroke.dash_lengths.iter().map(|&length| length as f64).collect::<Vec<_>>();
+	let transform = invertible_transform(vector_data.transform);
+
+	// Outline the stroke in the coordinates of the document, where it is drawn with its dashes
+	let dashes = vector_data
+		.subpaths
+		.iter()
+		.flat_map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform);
+			subpath.dash(&dash_lengths, stroke.dash_offset)
+		})
+		.collect::<Vec<_>>();
+	let mut outline = Subpath::outline_stroke(&dashes, stroke.weight, join, cap);
+	for subpath in &mut outline {
+		subpath.apply_transform(transform.inverse());
+	}
+
+	vector_data.subpaths = outline;
+	vector_data.style.clear_stroke();
+	vector_data.style.set_fill(stroke.color.map_or(Fill::None, Fill::Solid));
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VariableWidthStrokeNode<Profile, UseWidthAttribute> {
+	profile: Profile,
+	use_width_attribute: UseWidthAttribute,
+}
+
+#[node_macro::node_fn(VariableWidthStrokeNode)]
+fn variable_width_stroke(mut vector_data: VectorData, profile: Curve, use_width_attribute: bool) -> VectorData {
+	let Some(stroke) = vector_data.style.stroke() else {
+		return vector_data;
+	};
+	let (join, cap) = stroke_join_and_cap(&stroke);
+	let transform = invertible_transform(vector_data.transform);
+	// The weight of the stroke is scaled by the "width" point attribute where it is used and present, or else by the profile along the length of each subpath.
+	// Dashes are not drawn, since each of them would be given the whole profile.
+	let point_widths = if use_width_attribute {
+		vector_data.attribute(AttributeDomain::Point, "width").and_then(AttributeValues::as_f64)
+	} else {
+		None
+	};
+
+	// Outline the stroke in the coordinates of the document, where its weight is measured
+	let mut outline = Vec::new();
+	let mut first_point = 0;
+	for original in &vector_data.subpaths {
+		let mut subpath = original.clone();
+		subpath.apply_transform(transform);
+		let widths = match point_widths {
+			Some(point_widths) => point_widths[first_point..first_point + subpath.len()].iter().map(|width| width * stroke.weight).collect(),
+			None => {
+				// The width only changes linearly between anchors, so the subpath is first split finely enough to follow the shape of the profile
+				subpath = subpath.subdivide(subpath.length(None) / crate::consts::VARIABLE_WIDTH_STROKE_PROFILE_SAMPLES as f64);
+				profile_widths(&subpath, &profile, stroke.weight)
+			}
+		};
+		first_point += original.len();
+		outline.extend(subpath.outline_variable_stroke(&widths, join, cap));
+	}
+	for subpath in &mut outline {
+		subpath.apply_transform(transform.inverse());
+	}
+
+	vector_data.subpaths = outline;
+	vector_data.style.clear_stroke();
+	vector_data.style.set_fill(stroke.color.map_or(Fill::None, Fill::Solid));
+	vector_data
+}
+
+/// Returns the width at each manipulator group of the subpath, found from the height of the profile at the fraction of the length of the subpath up to that manipulator group.
+fn profile_widths(subpath: &Subpath<ManipulatorGroupId>, profile: &Curve, weight: f64) -> Vec<f64> {
+	let length = subpath.length(None);
+	let mut length_so_far = 0.;
+	let mut widths = vec![profile.evaluate(0.) * weight];
+	for bezier in subpath.iter().take(subpath.len().saturating_sub(1)) {
+		length_so_far += bezier.length(None);
+		widths.push(profile.evaluate(if length > 0. { length_so_far / length } else { 0. }) * weight);
+	}
+	widths
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RoundCornersNode<Radius, Chamfer, Selection> {
+	radius: Radius,
+	chamfer: Chamfer,
+	selection: Selection,
+}
+
+#[node_macro::node_fn(RoundCornersNode)]
+fn round_corners(mut vector_data: VectorData, radius: f64, chamfer: bool, selection: Vec<ManipulatorGroupId>) -> VectorData {
+	let corner = if chamfer { Corner::Chamfer } else { Corner::Round };
+	// An empty selection rounds every corner
+	let selection = (!selection.is_empty()).then_some(selection.as_slice());
+	let transform = invertible_transform(vector_data.transform);
+
+	// Measure the radius in the coordinates of the document, so the corners stay circular under a non-uniform scale
+	for subpath in &mut vector_data.subpaths {
+		subpath.apply_transform(transform);
+		*subpath = subpath.round_corners(radius, corner, selection);
+		subpath.apply_transform(transform.inverse());
+	}
+
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetPathNode<Distance, LineJoin, MiterLimit> {
+	distance: Distance,
+	line_join: LineJoin,
+	miter_limit: MiterLimit,
+}
+
+#[node_macro::node_fn(OffsetPathNode)]
+fn offset_path(mut vector_data: VectorData, distance: f64, line_join: super::style::LineJoin, miter_limit: f64) -> VectorData {
+	let join = match line_join {
+		super::style::LineJoin::Miter => Join::Miter(Some(miter_limit)),
+		super::style::LineJoin::Bevel => Join::Bevel,
+		super::style::LineJoin::Round => Join::Round,
+	};
+	let transform = invertible_transform(vector_data.transform);
+
+	// Measure the distance in the coordinates of the document, so the offset is even under a non-uniform scale
+	let (closed, open): (Vec<_>, Vec<_>) = vector_data
+		.subpaths
+		.iter()
+		.map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform);
+			subpath
+		})
+		.partition(|subpath| subpath.closed());
+
+	// The area filled by the closed subpaths is offset as a whole, so holes shrink as it grows and parts which come to overlap are merged
+	let mut subpaths = Subpath::offset_area(&closed, distance, join, bezier_rs::FillRule::NonZero);
+	subpaths.extend(open.iter().flat_map(|subpath| subpath.offset_path(distance, join)));
+	for subpath in &mut subpaths {
+		subpath.apply_transform(transform.inverse());
+	}
+	vector_data.subpaths = subpaths;
+
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RoughenNode<Size, Amount, Smooth, Time, Seed> {
+	size: Size,
+	amount: Amount,
+	smooth: Smooth,
+	time: Time,
+	seed: Seed,
+}
+
+/// Coherent noise in three dimensions following Ken Perlin's improved noise, with the gradients of its lattice shuffled by a seed.
+struct GradientNoise {
+	permutation: [usize; 512],
+}
+
+impl GradientNoise {
+	fn new(seed: u32) -> Self {
+		let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+		let mut table: [usize; 256] = core::array::from_fn(|index| index);
+		for index in (1..table.len()).rev() {
+			table.swap(index, (rng.next_u64() % (index as u64 + 1)) as usize);
+		}
+		Self {
+			permutation: core::array::from_fn(|index| table[index % 256]),
+		}
+	}
+
+	/// Returns the noise at the point, which lies roughly in the range `-1..1` and varies smoothly over distances of about one unit.
+	fn sample(&self, point: DVec3) -> f64 {
+		let cell = point.floor();
+		let local = point - cell;
+		let [x, y, z] = [cell.x, cell.y, cell.z].map(|coordinate| (coordinate as i64 & 255) as usize);
+		let fade = |t: f64| t * t * t * (t * (t * 6. - 15.) + 10.);
+		let (u, v, w) = (fade(local.x), fade(local.y), fade(local.z));
+		let gradient = |hash: usize, offset: DVec3| {
+			let hash = hash & 15;
+			let a = if hash < 8 { offset.x } else { offset.y };
+			let b = if hash < 4 {
+				offset.y
+			} else if hash == 12 || hash == 14 {
+				offset.x
+			} else {
+				offset.z
+			};
+			(if hash & 1 == 0 { a } else { -a }) + (if hash & 2 == 0 { b } else { -b })
+		};
+		let lerp = |t: f64, a: f64, b: f64| a + t * (b - a);
+
+		let p = &self.permutation;
+		let (a, b) = (p[x] + y, p[x + 1] + y);
+		let (aa, ab, ba, bb) = (p[a] + z, p[a + 1] + z, p[b] + z, p[b + 1] + z);
+		let corner = |hash: usize, dx: f64, dy: f64, dz: f64| gradient(p[hash], local - DVec3::new(dx, dy, dz));
+		lerp(
+			w,
+			lerp(v, lerp(u, corner(aa, 0., 0., 0.), corner(ba, 1., 0., 0.)), lerp(u, corner(ab, 0., 1., 0.), corner(bb, 1., 1., 0.))),
+			lerp(
+				v,
+				lerp(u, corner(aa + 1, 0., 0., 1.), corner(ba + 1, 1., 0., 1.)),
+				lerp(u, corner(ab + 1, 0., 1., 1.), corner(bb + 1, 1., 1., 1.)),
+			),
+		)
+	}
+}
+
+#[node_macro::node_fn(RoughenNode)]
+fn roughen(mut vector_data: VectorData, size: f64, amount: f64, smooth: bool, time: f64, seed: u32) -> VectorData {
+	let transform = invertible_transform(vector_data.transform);
+	// Pieces smaller than a unit would add an enormous number of anchors for no visible difference
+	let size = size.max(1.);
+
+	// Uniform noise jumps to new values at each whole unit of time, while smooth noise flows continuously through time
+	let mut rng = ChaCha8Rng::seed_from_u64(seed as u64 ^ ((time.floor() as i64 as u64) << 32));
+	let noise = GradientNoise::new(seed);
+	// Smooth noise changes over the distance of a few pieces, so neighbouring anchors move together
+	let feature_size = 4. * size;
+	let mut displacement = |point: DVec2| {
+		let offset = if smooth {
+			let point = (point / feature_size).extend(time);
+			DVec2::new(noise.sample(point), noise.sample(point + DVec3::new(31.7, 47.3, 0.)))
+		} else {
+			DVec2::new(random_unit(&mut rng), random_unit(&mut rng)) * 2. - 1.
+		};
+		offset * amount
+	};
+
+	// Measure the size of the pieces and the distance they are displaced in the coordinates of the document
+	for subpath in &mut vector_data.subpaths {
+		subpath.apply_transform(transform);
+		*subpath = subpath.subdivide(size);
+		for index in 0..subpath.len() {
+			// The handles move along with their anchor, so smooth joins stay smooth
+			let group = &mut subpath[index];
+			let offset = displacement(group.anchor);
+			group.anchor += offset;
+			group.in_handle = group.in_handle.map(|handle| handle + offset);
+			group.out_handle = group.out_handle.map(|handle| handle + offset);
+		}
+		subpath.apply_transform(transform.inverse());
 	}
+
 	vector_data
 }
 
+/// Returns the shape and style a fraction `time` of the way from the `source` to the `target`, keeping the transform of the `source`.
+/// Subpaths are paired up in order, with any left over on either side shrinking to or growing from the center of their bounding box.
+fn morph_vector_data(source: &VectorData, target: &VectorData, time: f64) -> VectorData {
+	let time = time.clamp(0., 1.);
+	// The target is brought into the coordinates of the source, so both are interpolated in the same space.
+	// A source transform which squashes it flat has no inverse, so both are then interpolated in the coordinates of the document instead.
+	let (transform, source_transform) = if source.transform.matrix2.determinant() != 0. {
+		(source.transform, DAffine2::IDENTITY)
+	} else {
+		(DAffine2::IDENTITY, source.transform)
+	};
+	let target_transform = transform.inverse() * target.transform;
+	let transformed = |subpath: &Subpath<ManipulatorGroupId>, transform: DAffine2| {
+		let mut subpath = subpath.clone();
+		subpath.apply_transform(transform);
+		subpath
+	};
+	let point = |subpath: &Subpath<ManipulatorGroupId>| {
+		let center = subpath.bounding_box().map_or(DVec2::ZERO, |[min, max]| (min + max) / 2.);
+		Subpath::from_anchors([center], false)
+	};
+
+	let count = source.subpaths.len().max(target.subpaths.len());
+	let subpaths = (0..count)
+		.map(|index| {
+			let source_subpath = source.subpaths.get(index).map(|subpath| transformed(subpath, source_transform));
+			let target_subpath = target.subpaths.get(index).map(|subpath| transformed(subpath, target_transform));
+			match (source_subpath, target_subpath) {
+				(Some(source), Some(target)) => source.morph(&target, time),
+				(Some(source), None) => source.morph(&point(&source), time),
+				(None, Some(target)) => point(&target).morph(&target, time),
+				(None, None) => unreachable!(),
+			}
+		})
+		.collect();
+
+	let mut result = VectorData::from_subpaths(subpaths);
+	result.transform = transform;
+	result.style = source.style.lerp(&target.style, time);
+	result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MorphNode<Target, Time> {
+	target: Target,
+	time: Time,
+}
+
+#[node_macro::node_fn(MorphNode)]
+fn morph(source: VectorData, target: VectorData, time: f64) -> VectorData {
+	morph_vector_data(&source, &target, time)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BlendStepsNode<Target, Steps> {
+	target: Target,
+	steps: Steps,
+}
+
+#[node_macro::node_fn(BlendStepsNode)]
+fn blend_steps(source: VectorData, target: VectorData, steps: u32) -> GraphicGroup {
+	let steps = steps.min(crate::consts::BLEND_STEPS_MAX);
+	let mut blend = GraphicGroup::EMPTY;
+	// The intermediate steps are spaced evenly between the source and the target, which are both included at the ends
+	let intervals = steps as f64 + 1.;
+	for index in 0..=steps + 1 {
+		blend.push(GraphicElement {
+			graphic_element_data: morph_vector_data(&source, &target, index as f64 / intervals).into(),
+			..Default::default()
+		});
+	}
+
+	blend
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct SplineFromPointsNode {}
 
@@ -185,3 +947,68 @@ fn spline_from_points(mut vector_data: VectorData) -> VectorData {
 
 	vector_data
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	#[test]
+	fn set_attribute_cycles_values() {
+		let vector_data = VectorData::from_subpaths(vec![
+			Subpath::from_anchors([DVec2::ZERO, DVec2::X, DVec2::Y], false),
+			Subpath::from_anchors([DVec2::ONE, DVec2::splat(2.)], false),
+		]);
+		let set = |per_subpath: bool, values: Vec<f32>| SetAttributeNode::new(ClonedNode::new("width".to_string()), ClonedNode::new(per_subpath), ClonedNode::new(values)).eval(vector_data.clone());
+
+		// The values are repeated until there is one for every point or subpath
+		let points = set(false, vec![1., 2.]);
+		assert_eq!(points.attribute(AttributeDomain::Point, "width"), Some(&AttributeValues::F64(vec![1., 2., 1., 2., 1.])));
+		let subpaths = set(true, vec![1., 2., 3.]);
+		assert_eq!(subpaths.attribute(AttributeDomain::Subpath, "width"), Some(&AttributeValues::F64(vec![1., 2.])));
+
+		// No values remove the attribute
+		let removed = SetAttributeNode::new(ClonedNode::new("width".to_string()), ClonedNode::new(false), ClonedNode::new(Vec::<f32>::new())).eval(points);
+		assert!(removed.attributes.get(AttributeDomain::Point, "width").is_none());
+	}
+
+	#[test]
+	fn resample_points_interpolates_attributes() {
+		let mut vector_data = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 10.)], false));
+		vector_data.attributes.insert(AttributeDomain::Point, "width", AttributeValues::F64(vec![0., 10., 20.]));
+		vector_data.attributes.insert(AttributeDomain::Subpath, "id", AttributeValues::F64(vec![7.]));
+		// The spacing is measured in the coordinates of the document, where the subpath is twice as long
+		vector_data.transform = DAffine2::from_scale(DVec2::splat(2.));
+
+		let resampled = ResamplePoints::new(ClonedNode::new(10.)).eval(vector_data);
+		let anchors = resampled.subpaths[0].anchors();
+		assert_eq!(anchors.len(), 5);
+		assert!(anchors[1].abs_diff_eq(DVec2::new(5., 0.), 1e-6) && anchors[3].abs_diff_eq(DVec2::new(10., 5.), 1e-6));
+
+		// Each new point blends the width of the original points at either end of the segment it lands on
+		let widths = resampled.attribute(AttributeDomain::Point, "width").and_then(AttributeValues::as_f64).unwrap();
+		assert_eq!(widths.len(), 5);
+		for (width, expected) in widths.iter().zip([0., 5., 10., 15., 20.]) {
+			assert!((width - expected).abs() < 1e-6);
+		}
+		assert_eq!(resampled.attribute(AttributeDomain::Subpath, "id"), Some(&AttributeValues::F64(vec![7.])));
+	}
+
+	#[test]
+	fn voronoi_cells_follow_boundary_transform() {
+		// Points extracted from a transformed layer are in its own coordinates, as is the boundary taken from the same layer
+		let mut boundary = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(100.)));
+		boundary.transform = DAffine2::from_scale_angle_translation(DVec2::new(2., 0.5), 0.3, DVec2::new(50., -20.));
+		let points = vec![DVec2::new(25., 50.), DVec2::new(75., 50.)];
+		let cells = VoronoiCellsNode::new(ClonedNode::new(boundary.clone())).eval(points.clone());
+
+		// The cells tile the boundary, each containing its own point, and are placed by the same transform
+		assert_eq!(cells.transform, boundary.transform);
+		assert_eq!(cells.subpaths.len(), 2);
+		let area = cells.subpaths.iter().map(|cell| cell.area()).sum::<f64>();
+		assert!((area - 100. * 100.).abs() < 1e-6);
+		for point in points {
+			assert_eq!(cells.subpaths.iter().filter(|cell| cell.winding(point) != 0).count(), 1);
+		}
+	}
+}
@@ -49,6 +49,12 @@ pub enum TaggedValue {
 	SelectiveColorChoice(graphene_core::raster::SelectiveColorChoice),
 	LineCap(graphene_core::vector::style::LineCap),
 	LineJoin(graphene_core::vector::style::LineJoin),
+	FillRule(graphene_core::vector::style::FillRule),
+	SymmetryType(graphene_core::vector::SymmetryType),
+	TextPathAlignment(graphene_core::text::TextPathAlignment),
+	ResampleFilter(graphene_core::raster::ResampleFilter),
+	CanvasAnchor(graphene_core::raster::CanvasAnchor),
+	StrokePosition(graphene_core::raster::StrokePosition),
 	FillType(graphene_core::vector::style::FillType),
 	GradientType(graphene_core::vector::style::GradientType),
 	GradientPositions(Vec<(f64, Option<graphene_core::Color>)>),
@@ -110,6 +116,12 @@ impl Hash for TaggedValue {
 			Self::SelectiveColorChoice(selective_color_choice) => selective_color_choice.hash(state),
 			Self::LineCap(line_cap) => line_cap.hash(state),
 			Self::LineJoin(line_join) => line_join.hash(state),
+			Self::FillRule(fill_rule) => fill_rule.hash(state),
+			Self::SymmetryType(symmetry_type) => symmetry_type.hash(state),
+			Self::TextPathAlignment(alignment) => alignment.hash(state),
+			Self::ResampleFilter(filter) => filter.hash(state),
+			Self::CanvasAnchor(anchor) => anchor.hash(state),
+			Self::StrokePosition(position) => position.hash(state),
 			Self::FillType(fill_type) => fill_type.hash(state),
 			Self::GradientType(gradient_type) => gradient_type.hash(state),
 			Self::GradientPositions(gradient_positions) => {
@@ -178,6 +190,12 @@ impl<'a> TaggedValue {
 			TaggedValue::SelectiveColorChoice(x) => Box::new(x),
 			TaggedValue::LineCap(x) => Box::new(x),
 			TaggedValue::LineJoin(x) => Box::new(x),
+			TaggedValue::FillRule(x) => Box::new(x),
+			TaggedValue::SymmetryType(x) => Box::new(x),
+			TaggedValue::TextPathAlignment(x) => Box::new(x),
+			TaggedValue::ResampleFilter(x) => Box::new(x),
+			TaggedValue::CanvasAnchor(x) => Box::new(x),
+			TaggedValue::StrokePosition(x) => Box::new(x),
 			TaggedValue::FillType(x) => Box::new(x),
 			TaggedValue::GradientType(x) => Box::new(x),
 			TaggedValue::GradientPositions(x) => Box::new(x),
@@ -247,6 +265,12 @@ impl<'a> TaggedValue {
 			TaggedValue::SelectiveColorChoice(_) => concrete!(graphene_core::raster::SelectiveColorChoice),
 			TaggedValue::LineCap(_) => concrete!(graphene_core::vector::style::LineCap),
 			TaggedValue::LineJoin(_) => concrete!(graphene_core::vector::style::LineJoin),
+			TaggedValue::FillRule(_) => concrete!(graphene_core::vector::style::FillRule),
+			TaggedValue::SymmetryType(_) => concrete!(graphene_core::vector::SymmetryType),
+			TaggedValue::TextPathAlignment(_) => concrete!(graphene_core::text::TextPathAlignment),
+			TaggedValue::ResampleFilter(_) => concrete!(graphene_core::raster::ResampleFilter),
+			TaggedValue::CanvasAnchor(_) => concrete!(graphene_core::raster::CanvasAnchor),
+			TaggedValue::StrokePosition(_) => concrete!(graphene_core::raster::StrokePosition),
 			TaggedValue::FillType(_) => concrete!(graphene_core::vector::style::FillType),
 			TaggedValue::GradientType(_) => concrete!(graphene_core::vector::style::GradientType),
 			TaggedValue::GradientPositions(_) => concrete!(Vec<(f64, Option<graphene_core::Color>)>),
@@ -305,6 +329,12 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<graphene_core::raster::SelectiveColorChoice>() => Ok(TaggedValue::SelectiveColorChoice(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineCap>() => Ok(TaggedValue::LineCap(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineJoin>() => Ok(TaggedValue::LineJoin(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::FillRule>() => Ok(TaggedValue::FillRule(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::SymmetryType>() => Ok(TaggedValue::SymmetryType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::TextPathAlignment>() => Ok(TaggedValue::TextPathAlignment(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::ResampleFilter>() => Ok(TaggedValue::ResampleFilter(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::CanvasAnchor>() => Ok(TaggedValue::CanvasAnchor(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::StrokePosition>() => Ok(TaggedValue::StrokePosition(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::FillType>() => Ok(TaggedValue::FillType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::GradientType>() => Ok(TaggedValue::GradientType(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<(f64, Option<graphene_core::Color>)>>() => Ok(TaggedValue::GradientPositions(*downcast(input).unwrap())),
@@ -0,0 +1,489 @@
+use glam::{DAffine2, DVec2, UVec2};
+use graphene_core::raster::{Image, ImageFrame};
+use graphene_core::transform::Footprint;
+use graphene_core::{Color, Node};
+
+/// Largest number of samples taken along the streak of each pixel by the motion, radial, and zoom blurs.
+const MAX_STREAK_SAMPLES: usize = 128;
+
+/// Largest distance in pixels reached by the kernels of the Gaussian and box blurs, and by which blurs grow the image on each side.
+/// Larger radii are limited to this, since the memory used grows with the margin and the time taken with the kernel size.
+const MAX_BLUR_RADIUS: u32 = 768;
+
+/// Returns the transform from pixel coordinates of the image to the space of the image frame, where the document units of the blur radii are measured.
+pub(crate) fn pixel_to_frame(image_frame: &ImageFrame<Color>) -> DAffine2 {
+	image_frame.transform * DAffine2::from_scale(1. / DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64))
+}
+
+/// Returns the length of one document unit, in pixels, along each axis of the image.
+fn pixels_per_unit(image_frame: &ImageFrame<Color>) -> DVec2 {
+	let axis_lengths = DVec2::new(image_frame.transform.matrix2.x_axis.length(), image_frame.transform.matrix2.y_axis.length());
+	DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64) / axis_lengths
+}
+
+/// Whether the image has no pixels or a degenerate transform, so that there is nothing to filter.
+fn is_degenerate(image_frame: &ImageFrame<Color>) -> bool {
+	image_frame.image.width == 0 || image_frame.image.height == 0 || image_frame.transform.matrix2.determinant() == 0.
+}
+
+/// Downscales the image to the number of pixels it covers in the viewport of the footprint, so that interactive previews of zoomed out images are filtered at screen resolution.
+/// The transform of the image frame is unchanged, so the blur radii in document units give the same result at any resolution. Images are never upscaled.
+fn downsample_to_footprint(image_frame: ImageFrame<Color>, footprint: &Footprint) -> ImageFrame<Color> {
+	let viewport_transform = footprint.transform * image_frame.transform;
+	let viewport_size = DVec2::new(viewport_transform.matrix2.x_axis.length(), viewport_transform.matrix2.y_axis.length())
+		.ceil()
+		.max(DVec2::ONE);
+	let image = &image_frame.image;
+	let size = UVec2::new(image.width, image.height);
+	let new_size = viewport_size.as_uvec2().min(size);
+	if new_size == size || !viewport_size.is_finite() {
+		return image_frame;
+	}
+
+	let data = bytemuck::cast_vec(image_frame.image.data);
+	let image_buffer = image::Rgba32FImage::from_raw(size.x, size.y, data).expect("Failed to convert internal ImageFrame into image-rs data type.");
+	let dynamic_image: image::DynamicImage = image_buffer.into();
+	let resized = dynamic_image.resize_exact(new_size.x, new_size.y, image::imageops::Triangle);
+	let image = Image {
+		width: new_size.x,
+		height: new_size.y,
+		data: bytemuck::cast_vec(resized.to_rgba32f().into_raw()),
+	};
+	ImageFrame {
+		image,
+		transform: image_frame.transform,
+	}
+}
+
+/// Grows the image by transparent pixels on each side, so that a blur can spread beyond its original bounds. The margin is limited to [MAX_BLUR_RADIUS].
+fn expand(image_frame: ImageFrame<Color>, margin: UVec2) -> ImageFrame<Color> {
+	let margin = margin.min(UVec2::splat(MAX_BLUR_RADIUS));
+	if margin == UVec2::ZERO {
+		return image_frame;
+	}
+
+	let old_size = UVec2::new(image_frame.image.width, image_frame.image.height);
+	let (Some(new_width), Some(new_height)) = (old_size.x.checked_add(2 * margin.x), old_size.y.checked_add(2 * margin.y)) else {
+		return image_frame;
+	};
+	let new_size = UVec2::new(new_width, new_height);
+	let mut image = Image::new(new_size.x, new_size.y, Color::TRANSPARENT);
+	let (old_width, new_width) = (old_size.x as usize, new_size.x as usize);
+	for y in 0..old_size.y as usize {
+		let old_start = y * old_width;
+		let new_start = (y + margin.y as usize) * new_width + margin.x as usize;
+		image.data[new_start..new_start + old_width].copy_from_slice(&image_frame.image.data[old_start..old_start + old_width]);
+	}
+
+	let transform = pixel_to_frame(&image_frame) * DAffine2::from_translation(-margin.as_dvec2()) * DAffine2::from_scale(new_size.as_dvec2());
+	ImageFrame { image, transform }
+}
+
+fn add_weighted(sum: &mut [f32; 4], color: Color, weight: f32) {
+	sum[0] += color.r() * weight;
+	sum[1] += color.g() * weight;
+	sum[2] += color.b() * weight;
+	sum[3] += color.a() * weight;
+}
+
+fn color_from_sum(sum: [f32; 4], total_weight: f32) -> Color {
+	if total_weight == 0. {
+		return Color::TRANSPARENT;
+	}
+	Color::from_rgbaf32_unchecked(sum[0] / total_weight, sum[1] / total_weight, sum[2] / total_weight, sum[3] / total_weight)
+}
+
+/// Convolves the image with a symmetric kernel along one axis, where `kernel[0]` weighs the center pixel and `kernel[i]` the pixels `i` away on either side.
+/// Pixels beyond the edges are transparent, unless `extend_edges` is set, in which case they repeat the nearest edge pixel.
+fn convolve_axis(image: &Image<Color>, kernel: &[f32], horizontal: bool, extend_edges: bool) -> Image<Color> {
+	let (width, height) = (image.width as i64, image.height as i64);
+	let (length, step) = if horizontal { (width, 1) } else { (height, width) };
+	let total_weight = kernel[0] + 2. * kernel[1..].iter().sum::<f32>();
+
+	let mut data = Vec::with_capacity(image.data.len());
+	for y in 0..height {
+		for x in 0..width {
+			let (position, start) = if horizontal { (x, y * width) } else { (y, x) };
+			let mut sum = [0.; 4];
+			let mut add_sample = |sample: i64, weight: f32| {
+				let sample = if extend_edges { sample.clamp(0, length - 1) } else { sample };
+				if (0..length).contains(&sample) {
+					add_weighted(&mut sum, image.data[(start + sample * step) as usize], weight);
+				}
+			};
+			add_sample(position, kernel[0]);
+			for (offset, &weight) in kernel.iter().enumerate().skip(1) {
+				add_sample(position - offset as i64, weight);
+				add_sample(position + offset as i64, weight);
+			}
+			data.push(color_from_sum(sum, total_weight));
+		}
+	}
+	Image {
+		width: image.width,
+		height: image.height,
+		data,
+	}
+}
+
+/// Returns half of the symmetric Gaussian kernel with the standard deviation `sigma` in pixels, reaching out three standard deviations.
+/// The standard deviation is limited so the kernel reaches no further than [MAX_BLUR_RADIUS].
+pub(crate) fn gaussian_kernel(sigma: f64) -> Vec<f32> {
+	let sigma = sigma.min(MAX_BLUR_RADIUS as f64 / 3.);
+	let radius = (3. * sigma).ceil() as usize;
+	(0..=radius).map(|offset| (-(offset as f64).powi(2) / (2. * sigma * sigma)).exp() as f32).collect()
+}
+
+/// Returns half of the symmetric box kernel with the `radius` in pixels, where the outermost pixel is weighted by the fraction of the radius reaching it.
+/// The radius is limited to [MAX_BLUR_RADIUS].
+fn box_kernel(radius: f64) -> Vec<f32> {
+	let radius = radius.min(MAX_BLUR_RADIUS as f64);
+	let whole = radius.floor() as usize;
+	let mut kernel = vec![1.; whole + 1];
+	let fraction = (radius - whole as f64) as f32;
+	if fraction > 0. {
+		kernel.push(fraction);
+	}
+	kernel
+}
+
+/// Convolves the image with the kernels along each axis in turn, skipping axes with no blur.
+pub(crate) fn separable_blur(image: &Image<Color>, horizontal_kernel: &[f32], vertical_kernel: &[f32], extend_edges: bool) -> Image<Color> {
+	let blurred = if horizontal_kernel.len() > 1 {
+		convolve_axis(image, horizontal_kernel, true, extend_edges)
+	} else {
+		image.clone()
+	};
+	if vertical_kernel.len() > 1 {
+		convolve_axis(&blurred, vertical_kernel, false, extend_edges)
+	} else {
+		blurred
+	}
+}
+
+/// Applies a Gaussian blur with the standard deviation `radius` in document units, growing the image so the blur can spread beyond its bounds.
+pub fn gaussian_blur(image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	if is_degenerate(&image_frame) || radius <= 0. {
+		return image_frame;
+	}
+
+	let sigma = radius * pixels_per_unit(&image_frame);
+	let (horizontal_kernel, vertical_kernel) = (gaussian_kernel(sigma.x), gaussian_kernel(sigma.y));
+	let margin = UVec2::new(horizontal_kernel.len() as u32 - 1, vertical_kernel.len() as u32 - 1);
+	let mut image_frame = expand(image_frame, margin);
+	image_frame.image = separable_blur(&image_frame.image, &horizontal_kernel, &vertical_kernel, false);
+	image_frame
+}
+
+/// Averages each pixel with those within the `radius` in document units along each axis, growing the image so the blur can spread beyond its bounds.
+pub fn box_blur(image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	if is_degenerate(&image_frame) || radius <= 0. {
+		return image_frame;
+	}
+
+	let radius = radius * pixels_per_unit(&image_frame);
+	let (horizontal_kernel, vertical_kernel) = (box_kernel(radius.x), box_kernel(radius.y));
+	let margin = UVec2::new(horizontal_kernel.len() as u32 - 1, vertical_kernel.len() as u32 - 1);
+	let mut image_frame = expand(image_frame, margin);
+	image_frame.image = separable_blur(&image_frame.image, &horizontal_kernel, &vertical_kernel, false);
+	image_frame
+}
+
+/// Samples the image with bilinear interpolation at a position in pixel coordinates, where pixel centers lie at half-integers and everything beyond the edges is transparent.
+pub(crate) fn sample_bilinear(image: &Image<Color>, position: DVec2) -> Color {
+	let position = position - 0.5;
+	let base = position.floor();
+	let fraction = (position - base).as_vec2();
+	let (x, y) = (base.x as i64, base.y as i64);
+
+	let pixel = |x: i64, y: i64| {
+		if x < 0 || y < 0 || x >= image.width as i64 || y >= image.height as i64 {
+			Color::TRANSPARENT
+		} else {
+			image.data[(y * image.width as i64 + x) as usize]
+		}
+	};
+	let top = pixel(x, y).lerp(pixel(x + 1, y), fraction.x);
+	let bottom = pixel(x, y + 1).lerp(pixel(x + 1, y + 1), fraction.x);
+	top.lerp(bottom, fraction.y)
+}
+
+/// Replaces each pixel with the average of the image sampled at the pixel moved by each of the `transforms`, which are given in pixel coordinates.
+fn streak_blur(image: &Image<Color>, transforms: &[DAffine2]) -> Image<Color> {
+	let mut data = Vec::with_capacity(image.data.len());
+	for y in 0..image.height {
+		for x in 0..image.width {
+			let position = DVec2::new(x as f64, y as f64) + 0.5;
+			let mut sum = [0.; 4];
+			for transform in transforms {
+				add_weighted(&mut sum, sample_bilinear(image, transform.transform_point2(position)), 1.);
+			}
+			data.push(color_from_sum(sum, transforms.len() as f32));
+		}
+	}
+	Image {
+		width: image.width,
+		height: image.height,
+		data,
+	}
+}
+
+/// Returns the number of samples needed to cover a streak of the given length in pixels without gaps.
+fn streak_samples(length: f64) -> usize {
+	(length.ceil() as usize + 1).clamp(2, MAX_STREAK_SAMPLES)
+}
+
+/// Smears the image along the direction at `angle` degrees by the `distance` in document units, centered on each pixel, growing the image so the blur can spread beyond its bounds.
+pub fn motion_blur(image_frame: ImageFrame<Color>, angle: f64, distance: f64) -> ImageFrame<Color> {
+	if is_degenerate(&image_frame) || distance <= 0. {
+		return image_frame;
+	}
+
+	let displacement = pixel_to_frame(&image_frame).inverse().transform_vector2(DVec2::from_angle(angle.to_radians()) * distance);
+	let margin = (displacement.abs() / 2.).ceil().as_uvec2();
+	let mut image_frame = expand(image_frame, margin);
+
+	let samples = streak_samples(displacement.length());
+	let transforms = (0..samples)
+		.map(|index| DAffine2::from_translation(displacement * (index as f64 / (samples - 1) as f64 - 0.5)))
+		.collect::<Vec<_>>();
+	image_frame.image = streak_blur(&image_frame.image, &transforms);
+	image_frame
+}
+
+/// Returns the center of the image offset by `center` in document units, and the greatest distance in pixels from it to a corner of the image.
+fn streak_center(image_frame: &ImageFrame<Color>, center: DVec2) -> (DVec2, f64) {
+	let center = image_frame.transform.transform_point2(DVec2::splat(0.5)) + center;
+	let center_in_pixels = pixel_to_frame(image_frame).inverse().transform_point2(center);
+	let size = DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64);
+	let farthest = [DVec2::ZERO, DVec2::new(size.x, 0.), DVec2::new(0., size.y), size]
+		.into_iter()
+		.map(|corner| corner.distance(center_in_pixels))
+		.fold(0., f64::max);
+	(center, farthest)
+}
+
+/// Spins the image about its center, offset by `center` in document units, blurring each pixel along the arc of `angle` degrees centered on it.
+pub fn radial_blur(image_frame: ImageFrame<Color>, center: DVec2, angle: f64) -> ImageFrame<Color> {
+	if is_degenerate(&image_frame) || angle <= 0. {
+		return image_frame;
+	}
+
+	let angle = angle.to_radians();
+	let (center, farthest) = streak_center(&image_frame, center);
+	let samples = streak_samples(farthest * angle);
+	let pixel_to_frame = pixel_to_frame(&image_frame);
+	let transforms = (0..samples)
+		.map(|index| {
+			let rotation = angle * (index as f64 / (samples - 1) as f64 - 0.5);
+			let spin = DAffine2::from_translation(center) * DAffine2::from_angle(rotation) * DAffine2::from_translation(-center);
+			pixel_to_frame.inverse() * spin * pixel_to_frame
+		})
+		.collect::<Vec<_>>();
+
+	let image = streak_blur(&image_frame.image, &transforms);
+	ImageFrame {
+		image,
+		transform: image_frame.transform,
+	}
+}
+
+/// Streaks the image outward from its center, offset by `center` in document units, blurring each pixel with those up to the `amount` percent of its distance closer to the center.
+pub fn zoom_blur(image_frame: ImageFrame<Color>, center: DVec2, amount: f64) -> ImageFrame<Color> {
+	let amount = amount.min(100.) / 100.;
+	if is_degenerate(&image_frame) || amount <= 0. {
+		return image_frame;
+	}
+
+	let (center, farthest) = streak_center(&image_frame, center);
+	let samples = streak_samples(farthest * amount);
+	let pixel_to_frame = pixel_to_frame(&image_frame);
+	let transforms = (0..samples)
+		.map(|index| {
+			let scale = 1. - amount * index as f64 / (samples - 1) as f64;
+			let zoom = DAffine2::from_translation(center) * DAffine2::from_scale(DVec2::splat(scale)) * DAffine2::from_translation(-center);
+			pixel_to_frame.inverse() * zoom * pixel_to_frame
+		})
+		.collect::<Vec<_>>();
+
+	let image = streak_blur(&image_frame.image, &transforms);
+	ImageFrame {
+		image,
+		transform: image_frame.transform,
+	}
+}
+
+/// Sharpens the image by adding the difference from its Gaussian blur with the standard deviation `radius` in document units, scaled by the `amount` percent.
+/// Pixels whose luminance differs from the blur by less than the `threshold` percent are left unchanged, to avoid sharpening noise in smooth areas.
+pub fn unsharp_mask(image_frame: ImageFrame<Color>, amount: f64, radius: f64, threshold: f64) -> ImageFrame<Color> {
+	if is_degenerate(&image_frame) || radius <= 0. || amount == 0. {
+		return image_frame;
+	}
+
+	let sigma = radius * pixels_per_unit(&image_frame);
+	let blurred = separable_blur(&image_frame.image, &gaussian_kernel(sigma.x), &gaussian_kernel(sigma.y), true);
+	let (amount, threshold) = (amount as f32 / 100., threshold as f32 / 100.);
+
+	let mut image_frame = image_frame;
+	for (pixel, blurred) in image_frame.image.data.iter_mut().zip(blurred.data) {
+		let difference = Color::from_rgbaf32_unchecked(pixel.r() - blurred.r(), pixel.g() - blurred.g(), pixel.b() - blurred.b(), pixel.a() - blurred.a());
+		if difference.luminance_srgb().abs() < threshold {
+			continue;
+		}
+
+		// The colors have associated alpha, so the color channels are kept within the sharpened alpha
+		let alpha = (pixel.a() + difference.a() * amount).clamp(0., 1.);
+		let channel = |value: f32, difference: f32| (value + difference * amount).clamp(0., alpha);
+		*pixel = Color::from_rgbaf32_unchecked(channel(pixel.r(), difference.r()), channel(pixel.g(), difference.g()), channel(pixel.b(), difference.b()), alpha);
+	}
+	image_frame
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GaussianBlurNode<ImageFrame, Radius> {
+	image_frame: ImageFrame,
+	radius: Radius,
+}
+
+#[node_macro::node_fn(GaussianBlurNode)]
+fn gaussian_blur_node(footprint: Footprint, image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	gaussian_blur(downsample_to_footprint(image_frame, &footprint), radius)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoxBlurNode<ImageFrame, Radius> {
+	image_frame: ImageFrame,
+	radius: Radius,
+}
+
+#[node_macro::node_fn(BoxBlurNode)]
+fn box_blur_node(footprint: Footprint, image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	box_blur(downsample_to_footprint(image_frame, &footprint), radius)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MotionBlurNode<ImageFrame, Angle, Distance> {
+	image_frame: ImageFrame,
+	angle: Angle,
+	distance: Distance,
+}
+
+#[node_macro::node_fn(MotionBlurNode)]
+fn motion_blur_node(footprint: Footprint, image_frame: ImageFrame<Color>, angle: f64, distance: f64) -> ImageFrame<Color> {
+	motion_blur(downsample_to_footprint(image_frame, &footprint), angle, distance)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RadialBlurNode<ImageFrame, Center, Angle> {
+	image_frame: ImageFrame,
+	center: Center,
+	angle: Angle,
+}
+
+#[node_macro::node_fn(RadialBlurNode)]
+fn radial_blur_node(footprint: Footprint, image_frame: ImageFrame<Color>, center: DVec2, angle: f64) -> ImageFrame<Color> {
+	radial_blur(downsample_to_footprint(image_frame, &footprint), center, angle)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZoomBlurNode<ImageFrame, Center, Amount> {
+	image_frame: ImageFrame,
+	center: Center,
+	amount: Amount,
+}
+
+#[node_macro::node_fn(ZoomBlurNode)]
+fn zoom_blur_node(footprint: Footprint, image_frame: ImageFrame<Color>, center: DVec2, amount: f64) -> ImageFrame<Color> {
+	zoom_blur(downsample_to_footprint(image_frame, &footprint), center, amount)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnsharpMaskNode<ImageFrame, Amount, Radius, Threshold> {
+	image_frame: ImageFrame,
+	amount: Amount,
+	radius: Radius,
+	threshold: Threshold,
+}
+
+#[node_macro::node_fn(UnsharpMaskNode)]
+fn unsharp_mask_node(footprint: Footprint, image_frame: ImageFrame<Color>, amount: f64, radius: f64, threshold: f64) -> ImageFrame<Color> {
+	unsharp_mask(downsample_to_footprint(image_frame, &footprint), amount, radius, threshold)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// A frame of `width` by `height` pixels which are one document unit across, with a single opaque white pixel at `(x, y)`.
+	fn single_pixel(width: u32, height: u32, x: u32, y: u32) -> ImageFrame<Color> {
+		let mut image = Image::new(width, height, Color::TRANSPARENT);
+		image.data[(y * width + x) as usize] = Color::WHITE;
+		ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::new(width as f64, height as f64)),
+		}
+	}
+
+	fn total_alpha(image_frame: &ImageFrame<Color>) -> f32 {
+		image_frame.image.data.iter().map(|color| color.a()).sum()
+	}
+
+	#[test]
+	fn blurs_spread_and_conserve() {
+		let blurred = gaussian_blur(single_pixel(5, 5, 2, 2), 1.);
+		// The image grows by three standard deviations on each side, keeping its pixels in place
+		assert_eq!((blurred.image.width, blurred.image.height), (11, 11));
+		assert!(blurred.transform.transform_point2(DVec2::ZERO).abs_diff_eq(DVec2::splat(-3.), 1e-9));
+		assert!((total_alpha(&blurred) - 1.).abs() < 1e-4);
+		let center = blurred.image.data[5 * 11 + 5];
+		assert!(center.a() > blurred.image.data[5 * 11 + 6].a());
+
+		let blurred = box_blur(single_pixel(5, 5, 2, 2), 1.);
+		assert_eq!(blurred.image.data[3 * 7 + 3].a(), 1. / 9.);
+		assert!((total_alpha(&blurred) - 1.).abs() < 1e-4);
+
+		// Radii are in document units, so doubling the size of the frame halves the blur in pixels
+		let mut frame = single_pixel(5, 5, 2, 2);
+		frame.transform = DAffine2::from_scale(DVec2::splat(10.));
+		assert_eq!(box_blur(frame, 2.).image.width, 7);
+	}
+
+	#[test]
+	fn blur_radius_is_limited() {
+		// Huge radii reach no further than the largest kernel, rather than growing the image without bound
+		let reach = MAX_BLUR_RADIUS as usize;
+		assert_eq!(gaussian_kernel(1e12).len(), reach + 1);
+		assert_eq!(box_kernel(f64::INFINITY).len(), reach + 1);
+
+		let expanded = expand(single_pixel(1, 1, 0, 0), UVec2::new(u32::MAX, 1));
+		assert_eq!((expanded.image.width as usize, expanded.image.height), (2 * reach + 1, 3));
+		assert_eq!(expanded.image.data[reach + 2 * reach + 1], Color::WHITE);
+	}
+
+	#[test]
+	fn motion_blur_follows_angle() {
+		let blurred = motion_blur(single_pixel(5, 5, 2, 2), 0., 4.);
+		assert_eq!((blurred.image.width, blurred.image.height), (9, 5));
+		assert!((total_alpha(&blurred) - 1.).abs() < 1e-4);
+		// The streak is horizontal, so the rows above and below stay transparent
+		assert!(blurred.image.data[..9].iter().all(|color| color.a() == 0.));
+		assert!(blurred.image.data[2 * 9 + 2].a() > 0.);
+		assert!(blurred.image.data[2 * 9 + 6].a() > 0.);
+	}
+
+	#[test]
+	fn unsharp_mask_sharpens_edges() {
+		let mut frame = ImageFrame {
+			image: Image::new(4, 1, Color::from_luminance(0.25)),
+			transform: DAffine2::from_scale(DVec2::new(4., 1.)),
+		};
+		frame.image.data[2] = Color::from_luminance(0.75);
+		frame.image.data[3] = Color::from_luminance(0.75);
+
+		let sharpened = unsharp_mask(frame.clone(), 100., 1., 0.);
+		assert!(sharpened.image.data[1].r() < 0.25);
+		assert!(sharpened.image.data[2].r() > 0.75);
+		// A threshold above every difference in luminance leaves the image unchanged
+		assert_eq!(unsharp_mask(frame.clone(), 100., 1., 100.).image.data, frame.image.data);
+	}
+}
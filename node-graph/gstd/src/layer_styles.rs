@@ -0,0 +1,613 @@
+use crate::filters::{gaussian_kernel, pixel_to_frame, sample_bilinear, separable_blur};
+
+use glam::{DAffine2, DVec2, UVec2};
+use graphene_core::raster::{BlendMode, Image, ImageFrame, StrokePosition};
+use graphene_core::transform::Footprint;
+use graphene_core::vector::style::Fill;
+use graphene_core::vector::{OutlineStrokeNode, VectorData};
+use graphene_core::{Color, GraphicElement, GraphicElementData, GraphicGroup, Node};
+
+use core::future::Future;
+
+/// Number of rows of samples taken across each pixel when rasterizing the coverage of vector shapes.
+const SUBSAMPLES: usize = 4;
+
+/// The settings shared by the shadow and glow layer style nodes, with lengths in document units.
+#[derive(Debug, Clone, Copy)]
+struct LayerStyle {
+	color: Color,
+	blend_mode: BlendMode,
+	/// In range 0..=100
+	opacity: f64,
+	/// Direction in degrees in which the effect is moved away from the layer.
+	angle: f64,
+	distance: f64,
+	/// Percentage of the size which is solid rather than faded.
+	spread: f64,
+	size: f64,
+}
+
+/// Where a layer style draws its effect relative to the layer it is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EffectPlacement {
+	/// The effect grows outward from the shape of the layer and is drawn beneath it, like a drop shadow, outer glow, or stroke.
+	Outside,
+	/// The effect grows inward from the edges of the layer and is drawn over it, clipped to its shape, like an inner shadow or inner glow.
+	Inside,
+}
+
+/// A part of the layer covering the viewport, either a vector shape flattened into polygons or an image, along with its opacity.
+enum Coverage<'a> {
+	Polygons(Vec<Vec<DVec2>>, f32),
+	Image(&'a ImageFrame<Color>, DAffine2, f32),
+}
+
+/// Flattens the subpaths of the vector data, placed in the viewport by the transform, into closed polygons.
+fn flatten(vector_data: &VectorData, transform: DAffine2) -> Vec<Vec<DVec2>> {
+	vector_data
+		.subpaths
+		.iter()
+		.map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform * vector_data.transform);
+			let mut polygon = Vec::new();
+			for bezier in subpath.iter() {
+				let steps = (bezier.length(None) / 2.).ceil().clamp(1., 64.) as usize;
+				let points = bezier.compute_lookup_table(Some(steps), None);
+				polygon.extend_from_slice(&points[..points.len() - 1]);
+			}
+			if polygon.is_empty() {
+				polygon.extend(subpath.manipulator_groups().first().map(|group| group.anchor));
+			}
+			polygon
+		})
+		.filter(|polygon| polygon.len() > 2)
+		.collect()
+}
+
+/// Gathers the visible parts of the graphic element data which give the layer its shape, placed in the viewport by the transform.
+/// Vector shapes contribute their fill and stroke, and images their alpha. Text and the backgrounds of artboards are not included.
+fn collect_coverage<'a>(data: &'a GraphicElementData, transform: DAffine2, opacity: f32, coverage: &mut Vec<Coverage<'a>>) {
+	match data {
+		GraphicElementData::VectorShape(vector_data) => {
+			if !matches!(vector_data.style.fill(), Fill::None) {
+				coverage.push(Coverage::Polygons(flatten(vector_data, transform), opacity));
+			}
+			if vector_data.style.stroke().map_or(false, |stroke| stroke.color.is_some() && stroke.weight > 0.) {
+				let outline = OutlineStrokeNode::new().eval((**vector_data).clone());
+				coverage.push(Coverage::Polygons(flatten(&outline, transform), opacity));
+			}
+		}
+		GraphicElementData::ImageFrame(image_frame) => {
+			if image_frame.image.width > 0 && image_frame.image.height > 0 {
+				coverage.push(Coverage::Image(image_frame, transform * pixel_to_frame(image_frame), opacity));
+			}
+		}
+		GraphicElementData::GraphicGroup(graphic_group) => collect_group_coverage(graphic_group, transform, opacity, coverage),
+		GraphicElementData::Artboard(artboard) => collect_group_coverage(&artboard.graphic_group, transform, opacity, coverage),
+		GraphicElementData::Text(_) => {}
+	}
+}
+
+fn collect_group_coverage<'a>(graphic_group: &'a GraphicGroup, transform: DAffine2, opacity: f32, coverage: &mut Vec<Coverage<'a>>) {
+	for element in graphic_group.iter().filter(|element| element.visible) {
+		collect_coverage(
+			&element.graphic_element_data,
+			transform * graphic_group.transform,
+			opacity * graphic_group.opacity * element.opacity,
+			coverage,
+		);
+	}
+}
+
+/// Returns the bounds in the viewport of the parts of the layer, or `None` if there are none.
+fn coverage_bounds(coverage: &[Coverage]) -> Option<[DVec2; 2]> {
+	let points = coverage.iter().flat_map(|coverage| match coverage {
+		Coverage::Polygons(polygons, _) => polygons.iter().flatten().copied().collect::<Vec<_>>(),
+		Coverage::Image(image_frame, pixel_to_viewport, _) => {
+			let size = DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64);
+			[DVec2::ZERO, DVec2::new(size.x, 0.), DVec2::new(0., size.y), size]
+				.map(|corner| pixel_to_viewport.transform_point2(corner))
+				.to_vec()
+		}
+	});
+	points.fold(None, |bounds, point| match bounds {
+		None => Some([point, point]),
+		Some([min, max]) => Some([min.min(point), max.max(point)]),
+	})
+}
+
+/// Adds the coverage of the polygons, filled with the nonzero rule, to the row-major `alpha` of a `size` pixel grid whose origin is at `origin` in the viewport.
+fn rasterize_polygons(polygons: &[Vec<DVec2>], opacity: f32, alpha: &mut [f32], size: UVec2, origin: DVec2) {
+	let mut coverage = vec![0_f32; alpha.len()];
+	let edges = polygons
+		.iter()
+		.flat_map(|polygon| polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(&start, &end)| (start - origin, end - origin)))
+		.filter(|(start, end)| start.y != end.y)
+		.collect::<Vec<_>>();
+
+	let mut crossings = Vec::new();
+	for row in 0..size.y as usize * SUBSAMPLES {
+		let y = (row as f64 + 0.5) / SUBSAMPLES as f64;
+		crossings.clear();
+		for &(start, end) in &edges {
+			let (top, bottom, winding) = if start.y < end.y { (start, end, 1) } else { (end, start, -1) };
+			if y >= top.y && y < bottom.y {
+				crossings.push((top.x + (y - top.y) / (bottom.y - top.y) * (bottom.x - top.x), winding));
+			}
+		}
+		crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+		let pixel_row = &mut coverage[row / SUBSAMPLES * size.x as usize..][..size.x as usize];
+		let mut winding = 0;
+		for pair in crossings.windows(2) {
+			winding += pair[0].1;
+			if winding == 0 {
+				continue;
+			}
+
+			// Add the fraction of each pixel which the span between the crossings covers
+			let (start, end) = (pair[0].0.clamp(0., size.x as f64), pair[1].0.clamp(0., size.x as f64));
+			let mut x = start;
+			while x < end {
+				let pixel = x.floor();
+				let span_end = end.min(pixel + 1.);
+				pixel_row[pixel as usize] += ((span_end - x) / SUBSAMPLES as f64) as f32;
+				x = span_end;
+			}
+		}
+	}
+
+	for (alpha, coverage) in alpha.iter_mut().zip(coverage) {
+		let coverage = coverage.min(1.) * opacity;
+		*alpha += coverage - *alpha * coverage;
+	}
+}
+
+/// Adds the alpha of the image, placed in the viewport by the transform of its pixels, to the row-major `alpha` of a `size` pixel grid whose origin is at `origin` in the viewport.
+fn rasterize_image(image_frame: &ImageFrame<Color>, pixel_to_viewport: DAffine2, opacity: f32, alpha: &mut [f32], size: UVec2, origin: DVec2) {
+	if pixel_to_viewport.matrix2.determinant() == 0. {
+		return;
+	}
+
+	let grid_to_pixel = pixel_to_viewport.inverse() * DAffine2::from_translation(origin);
+	for y in 0..size.y {
+		for x in 0..size.x {
+			let position = grid_to_pixel.transform_point2(DVec2::new(x as f64, y as f64) + 0.5);
+			let coverage = sample_bilinear(&image_frame.image, position).a() * opacity;
+			let alpha = &mut alpha[(y * size.x + x) as usize];
+			*alpha += coverage - *alpha * coverage;
+		}
+	}
+}
+
+/// The alpha of a layer rasterized at the resolution of the viewport, over a grid of `size` pixels whose origin is at `start` in the viewport.
+struct LayerAlpha {
+	alpha: Vec<f32>,
+	size: UVec2,
+	start: DVec2,
+}
+
+/// Rasterizes the alpha of the layer over its bounds and a margin around them, within reach of the viewport, or returns `None` if none of it can be seen.
+fn rasterize_layer(footprint: Footprint, data: &GraphicElementData, margin: DVec2) -> Option<LayerAlpha> {
+	if footprint.transform.matrix2.determinant() == 0. {
+		return None;
+	}
+
+	let mut coverage = Vec::new();
+	collect_coverage(data, footprint.transform, 1., &mut coverage);
+	let [start, end] = coverage_bounds(&coverage)?;
+	let start = (start - margin).max(-margin).floor();
+	let end = (end + margin).min(footprint.resolution.as_dvec2() + margin).ceil();
+	if start.x >= end.x || start.y >= end.y {
+		return None;
+	}
+	let size = (end - start).as_uvec2();
+
+	let mut alpha = vec![0.; (size.x * size.y) as usize];
+	for coverage in &coverage {
+		match coverage {
+			Coverage::Polygons(polygons, opacity) => rasterize_polygons(polygons, *opacity, &mut alpha, size, start),
+			Coverage::Image(image_frame, pixel_to_viewport, opacity) => rasterize_image(image_frame, *pixel_to_viewport, *opacity, &mut alpha, size, start),
+		}
+	}
+	Some(LayerAlpha { alpha, size, start })
+}
+
+/// Returns the layer with the effect, given as the color of each pixel of the rasterized layer, drawn beneath or over it as an image using the blend mode.
+fn compose_effect(footprint: Footprint, original: GraphicElement, layer: &LayerAlpha, effect: Vec<Color>, blend_mode: BlendMode, placement: EffectPlacement, name: &str) -> GraphicGroup {
+	let image = Image {
+		width: layer.size.x,
+		height: layer.size.y,
+		data: effect,
+	};
+	let transform = footprint.transform.inverse() * DAffine2::from_translation(layer.start) * DAffine2::from_scale(layer.size.as_dvec2());
+	let effect = GraphicElement {
+		name: name.to_string(),
+		blend_mode,
+		blend_image: true,
+		graphic_element_data: GraphicElementData::ImageFrame(ImageFrame { image, transform }),
+		..Default::default()
+	};
+
+	let mut result = GraphicGroup::EMPTY;
+	match placement {
+		EffectPlacement::Outside => result.extend([effect, original]),
+		EffectPlacement::Inside => result.extend([original, effect]),
+	}
+	result
+}
+
+/// Returns the layer with the effect of the layer style, rendered at the resolution of the footprint, drawn beneath or over it.
+/// The effect is made from the alpha of the layer, moved by the distance, faded by blurring across the size, and made solid across the spread.
+/// It is drawn as an image using the blend mode of the style, so that it blends with the content below the layer, or with the layer itself when drawn over it.
+fn apply_layer_style(footprint: Footprint, data: GraphicElementData, style: LayerStyle, placement: EffectPlacement, name: &str) -> GraphicGroup {
+	let original = GraphicElement {
+		graphic_element_data: data,
+		..Default::default()
+	};
+
+	let viewport_scale = footprint.transform.matrix2.determinant().abs().sqrt();
+	let offset = footprint.transform.transform_vector2(DVec2::from_angle(style.angle.to_radians()) * style.distance);
+	let size = (style.size * viewport_scale).max(0.);
+
+	// The effect is rasterized over the layer and the margin it can spread across, within reach of the viewport
+	let margin = DVec2::splat(size.ceil() + 1.) + offset.abs().ceil();
+	let Some(layer) = rasterize_layer(footprint, &original.graphic_element_data, margin) else {
+		let mut result = GraphicGroup::EMPTY;
+		result.push(original);
+		return result;
+	};
+	let grid_size = layer.size;
+
+	// The mask holds the effect in its alpha, moved by the offset, and inverted for effects inside the layer so they grow in from its edges
+	let layer_image = Image {
+		width: grid_size.x,
+		height: grid_size.y,
+		data: layer.alpha.iter().map(|&alpha| Color::from_rgbaf32_unchecked(0., 0., 0., alpha)).collect(),
+	};
+	let mut mask = Image::new(grid_size.x, grid_size.y, Color::TRANSPARENT);
+	for y in 0..grid_size.y {
+		for x in 0..grid_size.x {
+			let moved = sample_bilinear(&layer_image, DVec2::new(x as f64, y as f64) + 0.5 - offset).a();
+			let effect = if placement == EffectPlacement::Inside { 1. - moved } else { moved };
+			mask.data[(y * grid_size.x + x) as usize] = Color::from_rgbaf32_unchecked(0., 0., 0., effect);
+		}
+	}
+	if size > 0. {
+		let kernel = gaussian_kernel(size / 3.);
+		mask = separable_blur(&mask, &kernel, &kernel, placement == EffectPlacement::Inside);
+	}
+
+	// The effect is clipped to the layer when inside it, and knocked out of it when outside so it doesn't show through transparent parts
+	let solidity = 1. / (1. - style.spread.clamp(0., 100.) / 100.).max(1e-3) as f32;
+	let opacity = style.opacity.clamp(0., 100.) as f32 / 100.;
+	let effect = mask
+		.data
+		.iter()
+		.zip(&layer.alpha)
+		.map(|(mask, &alpha)| {
+			let clip = if placement == EffectPlacement::Inside { alpha } else { 1. - alpha };
+			style.color.apply_opacity((mask.a() * solidity).min(1.) * clip * opacity)
+		})
+		.collect();
+
+	compose_effect(footprint, original, &layer, effect, style.blend_mode, placement, name)
+}
+
+/// Returns the nearest pixel which is set to each pixel of a grid, as its index in the grid, or `None` if no pixels are set.
+/// This is the exact Euclidean distance transform of Felzenszwalb and Huttenlocher, applied to the columns and then the rows of the grid, keeping track of the pixels the distances are measured to.
+fn nearest_set_pixels(set: &[bool], size: UVec2) -> Vec<Option<usize>> {
+	let (width, height) = (size.x as usize, size.y as usize);
+
+	// The squared distance to the nearest set pixel in the same column, and the row it is in
+	let mut columns = vec![None; width * height];
+	let mut column = vec![0.; height];
+	for x in 0..width {
+		for (y, sample) in column.iter_mut().enumerate() {
+			*sample = if set[y * width + x] { 0. } else { f64::INFINITY };
+		}
+		for (y, nearest) in distance_transform_1d(&column).into_iter().enumerate() {
+			columns[y * width + x] = nearest;
+		}
+	}
+
+	let mut nearest = vec![None; width * height];
+	let mut row = vec![0.; width];
+	for y in 0..height {
+		let columns = &columns[y * width..(y + 1) * width];
+		for (sample, column) in row.iter_mut().zip(columns) {
+			*sample = column.map_or(f64::INFINITY, |(distance, _)| distance);
+		}
+		for (x, found) in distance_transform_1d(&row).into_iter().enumerate() {
+			nearest[y * width + x] = found.and_then(|(_, x)| columns[x].map(|(_, y)| y * width + x));
+		}
+	}
+	nearest
+}
+
+/// Returns the lower envelope of the parabolas rooted at each finite sample of a line, which is the squared distance transform of the samples,
+/// along with the index of the sample whose parabola is the lowest at each position, or `None` everywhere if no samples are finite.
+fn distance_transform_1d(samples: &[f64]) -> Vec<Option<(f64, usize)>> {
+	// The samples whose parabolas form the lower envelope, and the positions from which each of them is the lowest
+	let mut vertices: Vec<usize> = Vec::new();
+	let mut starts: Vec<f64> = Vec::new();
+	for (q, &sample) in samples.iter().enumerate().filter(|(_, sample)| sample.is_finite()) {
+		let mut start = f64::NEG_INFINITY;
+		while let (Some(&v), Some(&last_start)) = (vertices.last(), starts.last()) {
+			let (qf, vf) = (q as f64, v as f64);
+			start = ((sample + qf * qf) - (samples[v] + vf * vf)) / (2. * (qf - vf));
+			if start > last_start {
+				break;
+			}
+			vertices.pop();
+			starts.pop();
+			start = f64::NEG_INFINITY;
+		}
+		vertices.push(q);
+		starts.push(start);
+	}
+
+	if vertices.is_empty() {
+		return vec![None; samples.len()];
+	}
+	let mut k = 0;
+	(0..samples.len())
+		.map(|p| {
+			while k + 1 < starts.len() && starts[k + 1] <= p as f64 {
+				k += 1;
+			}
+			let v = vertices[k];
+			Some(((p as f64 - v as f64).powi(2) + samples[v], v))
+		})
+		.collect()
+}
+
+/// Returns the distance in pixels of each pixel of a grid from the nearest edge of the alpha, negative inside it and positive outside.
+/// Pixels partly covered by the alpha lie on its edges, where their coverage places the edge within them, and the others measure their distance to the nearest of those.
+fn signed_distance(alpha: &[f32], size: UVec2) -> Vec<f32> {
+	let nearest_covered = nearest_set_pixels(&alpha.iter().map(|&alpha| alpha > 0.).collect::<Vec<_>>(), size);
+	let nearest_uncovered = nearest_set_pixels(&alpha.iter().map(|&alpha| alpha < 1.).collect::<Vec<_>>(), size);
+	let position = |index: usize| DVec2::new((index % size.x as usize) as f64, (index / size.x as usize) as f64);
+
+	(0..alpha.len())
+		.map(|index| {
+			let inside = alpha[index] >= 1.;
+			let nearest = match alpha[index] {
+				alpha if alpha <= 0. => nearest_covered[index],
+				alpha if alpha >= 1. => nearest_uncovered[index],
+				_ => Some(index),
+			};
+			let Some(edge) = nearest else {
+				return if inside { f32::NEG_INFINITY } else { f32::INFINITY };
+			};
+
+			let distance = position(index).distance(position(edge)) as f32;
+			let edge_offset = 0.5 - alpha[edge];
+			if inside {
+				edge_offset - distance
+			} else {
+				edge_offset + distance
+			}
+		})
+		.collect()
+}
+
+/// Returns the layer with a stroke rendered at the resolution of the footprint, as a band of the size along the edges of the layer.
+/// The band is found by thresholding the distance of each pixel from the edges, antialiased across a pixel, so its width stays even around curves and corners.
+/// Strokes outside the layer are drawn beneath it and knocked out of it, while strokes inside or centered on its edges are drawn over it.
+fn apply_raster_stroke(footprint: Footprint, data: GraphicElementData, color: Color, blend_mode: BlendMode, opacity: f64, size: f64, position: StrokePosition) -> GraphicGroup {
+	let original = GraphicElement {
+		graphic_element_data: data,
+		..Default::default()
+	};
+
+	let viewport_scale = footprint.transform.matrix2.determinant().abs().sqrt();
+	let size = (size * viewport_scale).max(0.) as f32;
+	let Some(layer) = rasterize_layer(footprint, &original.graphic_element_data, DVec2::splat(size.ceil() as f64 + 1.)) else {
+		let mut result = GraphicGroup::EMPTY;
+		result.push(original);
+		return result;
+	};
+
+	let opacity = opacity.clamp(0., 100.) as f32 / 100.;
+	let effect = signed_distance(&layer.alpha, layer.size)
+		.into_iter()
+		.zip(&layer.alpha)
+		.map(|(distance, &alpha)| {
+			let coverage = match position {
+				StrokePosition::Inside => (distance + size + 0.5).clamp(0., 1.) * alpha,
+				StrokePosition::Center => (size / 2. - distance + 0.5).clamp(0., 1.) * (distance + size / 2. + 0.5).clamp(0., 1.),
+				StrokePosition::Outside => (size - distance + 0.5).clamp(0., 1.) * (1. - alpha),
+			};
+			color.apply_opacity(coverage * opacity)
+		})
+		.collect();
+
+	let placement = if position == StrokePosition::Outside { EffectPlacement::Outside } else { EffectPlacement::Inside };
+	compose_effect(footprint, original, &layer, effect, blend_mode, placement, "Stroke")
+}
+
+macro_rules! layer_style_node {
+	($node:ident, $function:ident, $placement:expr, $name:literal) => {
+		pub struct $node<Data, EffectColor, EffectBlendMode, Opacity, Angle, Distance, Spread, Size> {
+			data: Data,
+			color: EffectColor,
+			blend_mode: EffectBlendMode,
+			opacity: Opacity,
+			angle: Angle,
+			distance: Distance,
+			spread: Spread,
+			size: Size,
+		}
+
+		#[node_macro::node_fn($node)]
+		async fn $function<Data: Into<GraphicElementData>, Fut: Future<Output = Data>>(
+			footprint: Footprint,
+			data: impl Node<Footprint, Output = Fut>,
+			color: Color,
+			blend_mode: BlendMode,
+			opacity: f64,
+			angle: f64,
+			distance: f64,
+			spread: f64,
+			size: f64,
+		) -> GraphicGroup {
+			let data = self.data.eval(footprint).await;
+			let style = LayerStyle {
+				color,
+				blend_mode,
+				opacity,
+				angle,
+				distance,
+				spread,
+				size,
+			};
+			apply_layer_style(footprint, data.into(), style, $placement, $name)
+		}
+	};
+}
+
+layer_style_node!(DropShadowNode, drop_shadow, EffectPlacement::Outside, "Drop Shadow");
+layer_style_node!(InnerShadowNode, inner_shadow, EffectPlacement::Inside, "Inner Shadow");
+layer_style_node!(OuterGlowNode, outer_glow, EffectPlacement::Outside, "Outer Glow");
+layer_style_node!(InnerGlowNode, inner_glow, EffectPlacement::Inside, "Inner Glow");
+
+pub struct RasterStrokeNode<Data, StrokeColor, StrokeBlendMode, Opacity, Size, Position> {
+	data: Data,
+	color: StrokeColor,
+	blend_mode: StrokeBlendMode,
+	opacity: Opacity,
+	size: Size,
+	position: Position,
+}
+
+#[node_macro::node_fn(RasterStrokeNode)]
+async fn raster_stroke<Data: Into<GraphicElementData>, Fut: Future<Output = Data>>(
+	footprint: Footprint,
+	data: impl Node<Footprint, Output = Fut>,
+	color: Color,
+	blend_mode: BlendMode,
+	opacity: f64,
+	size: f64,
+	position: StrokePosition,
+) -> GraphicGroup {
+	let data = self.data.eval(footprint).await;
+	apply_raster_stroke(footprint, data.into(), color, blend_mode, opacity, size, position)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use bezier_rs::Subpath;
+
+	fn square() -> GraphicElementData {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::new(10., 10.), DVec2::new(30., 30.)));
+		vector_data.style.set_fill(Fill::Solid(Color::WHITE));
+		vector_data.into()
+	}
+
+	fn style(distance: f64, spread: f64, size: f64) -> LayerStyle {
+		LayerStyle {
+			color: Color::BLACK,
+			blend_mode: BlendMode::Multiply,
+			opacity: 100.,
+			angle: 0.,
+			distance,
+			spread,
+			size,
+		}
+	}
+
+	fn effect_image(group: &GraphicGroup, index: usize) -> &ImageFrame<Color> {
+		let GraphicElementData::ImageFrame(image_frame) = &group[index].graphic_element_data else {
+			panic!("The effect should be an image")
+		};
+		image_frame
+	}
+
+	/// Returns the alpha of the effect at a point in the document, which is also the viewport with the default footprint.
+	fn alpha_at(image_frame: &ImageFrame<Color>, point: DVec2) -> f32 {
+		let pixel = pixel_to_frame(image_frame).inverse().transform_point2(point).floor();
+		image_frame.image.data[(pixel.y as u32 * image_frame.image.width + pixel.x as u32) as usize].a()
+	}
+
+	#[test]
+	fn rasterizes_vector_coverage() {
+		let mut alpha = vec![0.; 16];
+		let triangle = vec![DVec2::new(0., 0.), DVec2::new(4., 0.), DVec2::new(0., 4.)];
+		rasterize_polygons(&[triangle], 1., &mut alpha, UVec2::new(4, 4), DVec2::ZERO);
+		assert!((alpha.iter().sum::<f32>() - 8.).abs() < 0.1);
+		assert_eq!(alpha[0], 1.);
+		assert_eq!(alpha[15], 0.);
+	}
+
+	#[test]
+	fn drop_shadow_beneath_layer() {
+		let group = apply_layer_style(Footprint::default(), square(), style(5., 100., 0.), EffectPlacement::Outside, "Drop Shadow");
+		assert_eq!(group.len(), 2);
+		assert_eq!(group[0].blend_mode, BlendMode::Multiply);
+		assert!(group[0].blend_image);
+		let shadow = effect_image(&group, 0);
+
+		// The shadow is moved to the right, and knocked out where the square covers it
+		assert_eq!(alpha_at(shadow, DVec2::new(32.5, 20.5)), 1.);
+		assert_eq!(alpha_at(shadow, DVec2::new(20.5, 20.5)), 0.);
+		assert_eq!(alpha_at(shadow, DVec2::new(7.5, 20.5)), 0.);
+	}
+
+	#[test]
+	fn inner_effects_clip_to_layer() {
+		let group = apply_layer_style(Footprint::default(), square(), style(0., 0., 4.), EffectPlacement::Inside, "Inner Glow");
+		let glow = effect_image(&group, 1);
+
+		// The glow fades in from the edges of the square, and never leaves it
+		let edge = alpha_at(glow, DVec2::new(10.5, 20.5));
+		let middle = alpha_at(glow, DVec2::new(20.5, 20.5));
+		assert!(edge > 0.2 && middle < 1e-3);
+		assert_eq!(alpha_at(glow, DVec2::new(8.5, 20.5)), 0.);
+	}
+
+	#[test]
+	fn raster_stroke_follows_edges() {
+		let stroke = |position| apply_raster_stroke(Footprint::default(), square(), Color::BLACK, BlendMode::Normal, 100., 4., position);
+
+		// An outside stroke is a band of the size around the square beneath it, rounded at its corners
+		let group = stroke(StrokePosition::Outside);
+		let outside = effect_image(&group, 0);
+		assert_eq!(alpha_at(outside, DVec2::new(30.5, 20.5)), 1.);
+		assert_eq!(alpha_at(outside, DVec2::new(33.5, 20.5)), 1.);
+		assert_eq!(alpha_at(outside, DVec2::new(34.5, 20.5)), 0.);
+		assert_eq!(alpha_at(outside, DVec2::new(29.5, 20.5)), 0.);
+		assert!(alpha_at(outside, DVec2::new(33.5, 33.5)) < 1e-3);
+
+		// An inside stroke is a band of the size within the square over it, sharp at its corners
+		let group = stroke(StrokePosition::Inside);
+		let inside = effect_image(&group, 1);
+		assert_eq!(alpha_at(inside, DVec2::new(10.5, 20.5)), 1.);
+		assert_eq!(alpha_at(inside, DVec2::new(13.5, 20.5)), 1.);
+		assert_eq!(alpha_at(inside, DVec2::new(14.5, 20.5)), 0.);
+		assert_eq!(alpha_at(inside, DVec2::new(13.5, 13.5)), 1.);
+		assert_eq!(alpha_at(inside, DVec2::new(9.5, 20.5)), 0.);
+
+		// A centered stroke straddles the edges of the square
+		let group = stroke(StrokePosition::Center);
+		let center = effect_image(&group, 1);
+		assert_eq!(alpha_at(center, DVec2::new(8.5, 20.5)), 1.);
+		assert_eq!(alpha_at(center, DVec2::new(11.5, 20.5)), 1.);
+		assert_eq!(alpha_at(center, DVec2::new(7.5, 20.5)), 0.);
+		assert_eq!(alpha_at(center, DVec2::new(12.5, 20.5)), 0.);
+	}
+
+	#[test]
+	fn raster_stroke_width_between_pixels() {
+		// The edge of the square falls within a column of pixels, whose coverage places it there, so the band keeps its width
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::new(10.3, 10.3), DVec2::new(30.3, 30.3)));
+		vector_data.style.set_fill(Fill::Solid(Color::WHITE));
+		let group = apply_raster_stroke(Footprint::default(), vector_data.into(), Color::BLACK, BlendMode::Normal, 100., 2.5, StrokePosition::Outside);
+		let stroke = effect_image(&group, 0);
+		let width: f32 = (28..36).map(|x| alpha_at(stroke, DVec2::new(x as f64 + 0.5, 20.5))).sum();
+		assert!((width - 2.5).abs() < 0.05);
+	}
+}
@@ -7,6 +7,10 @@ extern crate log;
 
 pub mod raster;
 
+pub mod filters;
+
+pub mod layer_styles;
+
 pub mod http;
 
 pub mod any;
@@ -1,8 +1,11 @@
 use dyn_any::{DynAny, StaticType};
-use glam::{DAffine2, DVec2, Vec2};
+use glam::{DAffine2, DVec2, IVec2, Vec2};
 use graph_craft::imaginate_input::{ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
 use graph_craft::proto::DynFuture;
-use graphene_core::raster::{Alpha, BlendMode, BlendNode, Image, ImageFrame, Linear, LinearChannel, Luminance, NoiseType, Pixel, RGBMut, Raster, RasterMut, RedGreenBlue, Sample};
+use graphene_core::consts::{IMAGE_PIXELS_MAX, IMAGE_SIZE_MAX};
+use graphene_core::raster::{
+	Alpha, BlendMode, BlendNode, CanvasAnchor, Image, ImageFrame, Linear, LinearChannel, Luminance, NoiseType, Pixel, RGBMut, Raster, RasterMut, RedGreenBlue, RelativeAbsolute, ResampleFilter, Sample,
+};
 use graphene_core::transform::{Footprint, Transform};
 
 use crate::wasm_application_io::WasmEditorApi;
@@ -420,6 +423,178 @@ fn extend_image_to_bounds_node(image: ImageFrame<Color>, bounds: DAffine2) -> Im
 	}
 }
 
+/// Returns the image resampled to the given size in pixels, interpolating with the filter.
+fn resample_image(image: Image<Color>, width: u32, height: u32, filter: ResampleFilter) -> Image<Color> {
+	if image.width == width && image.height == height {
+		return image;
+	}
+	if image.width == 0 || image.height == 0 || width == 0 || height == 0 {
+		return Image::new(width, height, Color::TRANSPARENT);
+	}
+
+	let filter = match filter {
+		ResampleFilter::Nearest => image::imageops::Nearest,
+		ResampleFilter::Bilinear => image::imageops::Triangle,
+		ResampleFilter::Bicubic => image::imageops::CatmullRom,
+		ResampleFilter::Lanczos => image::imageops::Lanczos3,
+	};
+	let image_buffer = image::Rgba32FImage::from_raw(image.width, image.height, bytemuck::cast_vec(image.data)).expect("Failed to convert internal ImageFrame into image-rs data type.");
+	let resized = image::imageops::resize(&image_buffer, width, height, filter);
+
+	// Bicubic and Lanczos filters overshoot at sharp edges, so each channel is kept within the premultiplied alpha
+	let data = resized
+		.pixels()
+		.map(|pixel| {
+			let [red, green, blue, alpha] = pixel.0;
+			let alpha = alpha.clamp(0., 1.);
+			Color::from_rgbaf32_unchecked(red.clamp(0., alpha), green.clamp(0., alpha), blue.clamp(0., alpha), alpha)
+		})
+		.collect();
+	Image { width, height, data }
+}
+
+/// Whether an image of the given size in pixels is too large to make, beyond [IMAGE_SIZE_MAX] on either side or [IMAGE_PIXELS_MAX] in total.
+fn exceeds_size_limits(size: DVec2) -> bool {
+	!(size.cmple(DVec2::splat(IMAGE_SIZE_MAX as f64)).all() && size.x * size.y <= IMAGE_PIXELS_MAX as f64)
+}
+
+/// Resamples the image to an absolute size in pixels or by a relative factor.
+/// Each pixel keeps its size in the document, so the image grows or shrinks away from its top left corner.
+/// Sizes beyond [IMAGE_SIZE_MAX] on either side or [IMAGE_PIXELS_MAX] in total give an empty image.
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeImageNode<Mode, Width, Height, Factor, Filter> {
+	mode: Mode,
+	width: Width,
+	height: Height,
+	factor: Factor,
+	filter: Filter,
+}
+
+#[node_macro::node_fn(ResizeImageNode)]
+fn resize_image_node(image_frame: ImageFrame<Color>, mode: RelativeAbsolute, width: u32, height: u32, factor: f64, filter: ResampleFilter) -> ImageFrame<Color> {
+	let old_size = DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64);
+	if old_size.x == 0. || old_size.y == 0. {
+		return image_frame;
+	}
+
+	let new_size = match mode {
+		RelativeAbsolute::Relative => (old_size * factor.max(0.)).round(),
+		RelativeAbsolute::Absolute => DVec2::new(width as f64, height as f64),
+	};
+	if new_size.x == 0. || new_size.y == 0. || exceeds_size_limits(new_size) {
+		return ImageFrame::empty();
+	}
+
+	let image = resample_image(image_frame.image, new_size.x as u32, new_size.y as u32, filter);
+	let transform = image_frame.transform * DAffine2::from_scale(new_size / old_size);
+	ImageFrame { image, transform }
+}
+
+/// Returns the start and end, in pixels, of the smallest rectangle containing every pixel of the image which is not fully transparent.
+fn visible_bounds(image: &Image<Color>) -> Option<(IVec2, IVec2)> {
+	let mut bounds: Option<(IVec2, IVec2)> = None;
+	for (index, pixel) in image.data.iter().enumerate() {
+		if pixel.a() <= 0. {
+			continue;
+		}
+		let position = IVec2::new((index % image.width as usize) as i32, (index / image.width as usize) as i32);
+		bounds = Some(match bounds {
+			Some((start, end)) => (start.min(position), end.max(position + IVec2::ONE)),
+			None => (position, position + IVec2::ONE),
+		});
+	}
+	bounds
+}
+
+/// Returns the pixels of the image from `start` to `end`, which must lie within it.
+fn copy_region(image: &Image<Color>, start: IVec2, end: IVec2) -> Image<Color> {
+	let size = (end - start).as_uvec2();
+	let mut data = Vec::with_capacity((size.x * size.y) as usize);
+	for y in start.y..end.y {
+		let row = y as usize * image.width as usize;
+		data.extend_from_slice(&image.data[row + start.x as usize..row + end.x as usize]);
+	}
+	Image { width: size.x, height: size.y, data }
+}
+
+/// Crops the image to a rectangle given in pixels from its top left corner, or to the bounds of its visible content.
+/// The cropped image stays where it was in the document.
+#[derive(Debug, Clone, Copy)]
+pub struct CropImageNode<Offset, Size, ContentBounds> {
+	offset: Offset,
+	size: Size,
+	content_bounds: ContentBounds,
+}
+
+#[node_macro::node_fn(CropImageNode)]
+fn crop_image_node(image_frame: ImageFrame<Color>, offset: IVec2, size: IVec2, content_bounds: bool) -> ImageFrame<Color> {
+	let image = &image_frame.image;
+	let image_size = IVec2::new(image.width as i32, image.height as i32);
+
+	let (start, end) = if content_bounds {
+		match visible_bounds(image) {
+			Some(bounds) => bounds,
+			None => return ImageFrame::empty(),
+		}
+	} else {
+		(offset.clamp(IVec2::ZERO, image_size), offset.saturating_add(size.max(IVec2::ZERO)).clamp(IVec2::ZERO, image_size))
+	};
+	if end.x <= start.x || end.y <= start.y {
+		return ImageFrame::empty();
+	}
+	if start == IVec2::ZERO && end == image_size {
+		return image_frame;
+	}
+
+	let transform = image_frame.transform * DAffine2::from_scale(1. / image_size.as_dvec2()) * DAffine2::from_translation(start.as_dvec2()) * DAffine2::from_scale((end - start).as_dvec2());
+	ImageFrame {
+		image: copy_region(image, start, end),
+		transform,
+	}
+}
+
+/// Changes the size of the canvas in pixels without resampling, padding it with transparency or cutting off the image.
+/// The anchor is the side or corner of the canvas which the image stays attached to, and the image stays where it was in the document.
+/// Sizes beyond [IMAGE_SIZE_MAX] on either side or [IMAGE_PIXELS_MAX] in total give an empty image.
+#[derive(Debug, Clone, Copy)]
+pub struct CanvasSizeNode<Width, Height, Anchor> {
+	width: Width,
+	height: Height,
+	anchor: Anchor,
+}
+
+#[node_macro::node_fn(CanvasSizeNode)]
+fn canvas_size_node(image_frame: ImageFrame<Color>, width: u32, height: u32, anchor: CanvasAnchor) -> ImageFrame<Color> {
+	let image = &image_frame.image;
+	if image.width == 0 || image.height == 0 || (width, height) == (image.width, image.height) {
+		return image_frame;
+	}
+	if width == 0 || height == 0 || exceeds_size_limits(DVec2::new(width as f64, height as f64)) {
+		return ImageFrame::empty();
+	}
+	// Both sizes are within the limit on the new size or already allocated, so they fit in the pixel coordinates
+	let old_size = IVec2::new(image.width as i32, image.height as i32);
+	let new_size = IVec2::new(width as i32, height as i32);
+
+	// Where the top left corner of the image lands on the new canvas, which is negative where the canvas shrinks
+	let (anchor_x, anchor_y) = anchor.factor();
+	let offset = ((new_size - old_size).as_dvec2() * DVec2::new(anchor_x, anchor_y)).round().as_ivec2();
+
+	let mut new_image = Image::new(width, height, Color::TRANSPARENT);
+	let start = offset.max(IVec2::ZERO);
+	let end = (offset + old_size).min(new_size);
+	if end.x > start.x && end.y > start.y {
+		let region = copy_region(image, start - offset, end - offset);
+		for (y, row) in (start.y..end.y).zip(region.data.chunks_exact(region.width as usize)) {
+			let new_start = y as usize * width as usize + start.x as usize;
+			new_image.data[new_start..new_start + row.len()].copy_from_slice(row);
+		}
+	}
+
+	let transform = image_frame.transform * DAffine2::from_scale(1. / old_size.as_dvec2()) * DAffine2::from_translation(-offset.as_dvec2()) * DAffine2::from_scale(new_size.as_dvec2());
+	ImageFrame { image: new_image, transform }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct MergeBoundingBoxNode<Data> {
 	_data: PhantomData<Data>,
@@ -630,6 +805,83 @@ fn map_color(iter: usize, max_iter: usize) -> Color {
 
 #[cfg(test)]
 mod test {
+	use super::*;
+
+	/// A 4 by 2 pixel image covering 8 by 4 units, with a single opaque pixel in its second row.
+	fn test_image_frame() -> ImageFrame<Color> {
+		let mut image = Image::new(4, 2, Color::TRANSPARENT);
+		image.data[6] = Color::WHITE;
+		ImageFrame {
+			image,
+			transform: DAffine2::from_translation(DVec2::new(10., 20.)) * DAffine2::from_scale(DVec2::new(8., 4.)),
+		}
+	}
+
+	#[test]
+	fn resize_image() {
+		let resize = |mode: RelativeAbsolute, width: u32, height: u32, factor: f64| {
+			let resize = ResizeImageNode::new(
+				CopiedNode::new(mode),
+				CopiedNode::new(width),
+				CopiedNode::new(height),
+				CopiedNode::new(factor),
+				CopiedNode::new(ResampleFilter::Nearest),
+			);
+			resize.eval(test_image_frame())
+		};
+
+		// Each pixel keeps its size in the document, so the image grows away from its top left corner
+		let relative = resize(RelativeAbsolute::Relative, 0, 0, 1.5);
+		assert_eq!((relative.image.width, relative.image.height), (6, 3));
+		assert!(relative.transform.transform_point2(DVec2::ZERO).abs_diff_eq(DVec2::new(10., 20.), 1e-9));
+		assert!(relative.transform.transform_point2(DVec2::ONE).abs_diff_eq(DVec2::new(22., 26.), 1e-9));
+
+		let absolute = resize(RelativeAbsolute::Absolute, 8, 4, 0.);
+		assert_eq!((absolute.image.width, absolute.image.height), (8, 4));
+		assert_eq!(absolute.image.data[2 * 8 + 4], Color::WHITE);
+		assert!(absolute.transform.transform_point2(DVec2::ZERO).abs_diff_eq(DVec2::new(10., 20.), 1e-9));
+		assert!(absolute.transform.transform_point2(DVec2::ONE).abs_diff_eq(DVec2::new(26., 28.), 1e-9));
+
+		// Sizes too large to allocate give an empty image
+		assert_eq!(resize(RelativeAbsolute::Relative, 0, 0, 1e9).image.width, 0);
+		assert_eq!(resize(RelativeAbsolute::Absolute, u32::MAX, u32::MAX, 0.).image.width, 0);
+		assert_eq!(resize(RelativeAbsolute::Absolute, IMAGE_SIZE_MAX, IMAGE_SIZE_MAX, 0.).image.width, 0);
+	}
+
+	#[test]
+	fn crop_image() {
+		let crop = CropImageNode::new(CopiedNode::new(IVec2::new(1, 1)), CopiedNode::new(IVec2::new(10, 10)), CopiedNode::new(false));
+		let cropped = crop.eval(test_image_frame());
+		assert_eq!((cropped.image.width, cropped.image.height), (3, 1));
+		assert!(cropped
+			.transform
+			.abs_diff_eq(DAffine2::from_translation(DVec2::new(12., 22.)) * DAffine2::from_scale(DVec2::new(6., 2.)), 1e-9));
+
+		let crop_to_content = CropImageNode::new(CopiedNode::new(IVec2::ZERO), CopiedNode::new(IVec2::ZERO), CopiedNode::new(true));
+		let cropped = crop_to_content.eval(test_image_frame());
+		assert_eq!(cropped.image.data, vec![Color::WHITE]);
+		assert!(cropped.transform.transform_point2(DVec2::ZERO).abs_diff_eq(DVec2::new(14., 22.), 1e-9));
+
+		// A size reaching past the largest coordinate is cut off at the edge of the image
+		let crop = CropImageNode::new(CopiedNode::new(IVec2::new(1, 1)), CopiedNode::new(IVec2::MAX), CopiedNode::new(false));
+		assert_eq!(crop.eval(test_image_frame()).image.width, 3);
+	}
+
+	#[test]
+	fn canvas_size() {
+		let extended = CanvasSizeNode::new(CopiedNode::new(6_u32), CopiedNode::new(4_u32), CopiedNode::new(CanvasAnchor::BottomRight)).eval(test_image_frame());
+		assert_eq!((extended.image.width, extended.image.height), (6, 4));
+		assert_eq!(extended.image.data[3 * 6 + 4], Color::WHITE);
+		assert!(extended.transform.transform_point2(DVec2::ZERO).abs_diff_eq(DVec2::new(6., 16.), 1e-9));
+		assert!(extended.transform.transform_point2(DVec2::ONE).abs_diff_eq(DVec2::new(18., 24.), 1e-9));
+
+		let shrunk = CanvasSizeNode::new(CopiedNode::new(2_u32), CopiedNode::new(2_u32), CopiedNode::new(CanvasAnchor::Center)).eval(test_image_frame());
+		assert_eq!(shrunk.image.data[3], Color::WHITE);
+		assert!(shrunk.transform.transform_point2(DVec2::ZERO).abs_diff_eq(DVec2::new(12., 20.), 1e-9));
+
+		let too_large = CanvasSizeNode::new(CopiedNode::new(u32::MAX), CopiedNode::new(1_u32), CopiedNode::new(CanvasAnchor::Center)).eval(test_image_frame());
+		assert_eq!((too_large.image.width, too_large.image.height), (0, 0));
+	}
 
 	#[test]
 	fn load_image() {
@@ -644,6 +644,9 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::structural::ConsNode<_, _>, input: Image<Color>, params: [&str]),
 		register_node!(graphene_std::raster::ImageFrameNode<_, _>, input: Image<Color>, params: [DAffine2]),
 		register_node!(graphene_std::raster::PixelNoiseNode<_, _, _>, input: u32, params: [u32, u32, NoiseType]),
+		register_node!(graphene_std::raster::ResizeImageNode<_, _, _, _, _>, input: ImageFrame<Color>, params: [RelativeAbsolute, u32, u32, f64, ResampleFilter]),
+		register_node!(graphene_std::raster::CropImageNode<_, _, _>, input: ImageFrame<Color>, params: [glam::IVec2, glam::IVec2, bool]),
+		register_node!(graphene_std::raster::CanvasSizeNode<_, _, _>, input: ImageFrame<Color>, params: [u32, u32, CanvasAnchor]),
 		#[cfg(feature = "quantization")]
 		register_node!(graphene_std::quantization::GenerateQuantizationNode<_, _>, input: ImageFrame<Color>, params: [u32, u32]),
 		register_node!(graphene_core::quantization::QuantizeNode<_>, input: Color, params: [QuantizationChannels]),
@@ -777,8 +780,20 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [DAffine2]),
 		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>]),
 		register_node!(graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f32, Vec<f32>, f32, graphene_core::vector::style::LineCap, graphene_core::vector::style::LineJoin, f32]),
+		register_node!(graphene_core::vector::SetAttributeNode<_, _, _>, input: VectorData, params: [String, bool, Vec<f32>]),
 		register_node!(graphene_core::vector::RepeatNode<_, _>, input: VectorData, params: [DVec2, u32]),
 		register_node!(graphene_core::vector::BoundingBoxNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::CopyToPointsNode<_, _, _, _, _, _, _, _>, input: VectorData, params: [VectorData, bool, f64, f64, f64, f64, f64, u32]),
+		register_node!(graphene_core::vector::CopyToPointsNode<_, _, _, _, _, _, _, _>, input: VectorData, params: [GraphicGroup, bool, f64, f64, f64, f64, f64, u32]),
+		register_node!(graphene_core::vector::GridRepeatNode<_, _, _, _, _, _, _>, input: VectorData, params: [u32, u32, DVec2, f64, f64, f64, f64]),
+		register_node!(graphene_core::vector::GridRepeatNode<_, _, _, _, _, _, _>, input: GraphicGroup, params: [u32, u32, DVec2, f64, f64, f64, f64]),
+		register_node!(graphene_core::vector::RepeatAlongPathNode<_, _, _, _, _, _>, input: VectorData, params: [VectorData, u32, bool, f64, f64, f64]),
+		register_node!(graphene_core::vector::RepeatAlongPathNode<_, _, _, _, _, _>, input: VectorData, params: [GraphicGroup, u32, bool, f64, f64, f64]),
+		register_node!(graphene_core::vector::SymmetryNode<_, _, _, _, _>, input: VectorData, params: [graphene_core::vector::SymmetryType, DVec2, f64, u32, bool]),
+		register_node!(graphene_core::vector::SymmetryNode<_, _, _, _, _>, input: GraphicGroup, params: [graphene_core::vector::SymmetryType, DVec2, f64, u32, bool]),
+		register_node!(graphene_core::vector::ScatterPointsNode<_, _, _, _, _>, input: VectorData, params: [bool, f64, u32, graphene_core::vector::style::FillRule, u32]),
+		register_node!(graphene_core::vector::DelaunayTriangulationNode, input: Vec<DVec2>, params: []),
+		register_node!(graphene_core::vector::VoronoiCellsNode<_>, input: Vec<DVec2>, params: [VectorData]),
 		register_node!(graphene_core::vector::CircularRepeatNode<_, _, _>, input: VectorData, params: [f32, f32, u32]),
 		vec![(
 			NodeIdentifier::new("graphene_core::transform::CullNode<_>"),
@@ -821,7 +836,36 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		)],
 		register_node!(graphene_std::raster::SampleNode<_>, input: Footprint, params: [ImageFrame<Color>]),
 		register_node!(graphene_std::raster::MandelbrotNode, input: Footprint, params: []),
+		register_node!(graphene_std::filters::GaussianBlurNode<_, _>, input: Footprint, params: [ImageFrame<Color>, f64]),
+		register_node!(graphene_std::filters::BoxBlurNode<_, _>, input: Footprint, params: [ImageFrame<Color>, f64]),
+		register_node!(graphene_std::filters::MotionBlurNode<_, _, _>, input: Footprint, params: [ImageFrame<Color>, f64, f64]),
+		register_node!(graphene_std::filters::RadialBlurNode<_, _, _>, input: Footprint, params: [ImageFrame<Color>, DVec2, f64]),
+		register_node!(graphene_std::filters::ZoomBlurNode<_, _, _>, input: Footprint, params: [ImageFrame<Color>, DVec2, f64]),
+		register_node!(graphene_std::filters::UnsharpMaskNode<_, _, _, _>, input: Footprint, params: [ImageFrame<Color>, f64, f64, f64]),
+		async_node!(graphene_std::layer_styles::DropShadowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => ImageFrame<Color>, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::DropShadowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::DropShadowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::InnerShadowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => ImageFrame<Color>, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::InnerShadowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::InnerShadowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::OuterGlowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => ImageFrame<Color>, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::OuterGlowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::OuterGlowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::InnerGlowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => ImageFrame<Color>, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::InnerGlowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::InnerGlowNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => Color, () => BlendMode, () => f64, () => f64, () => f64, () => f64, () => f64]),
+		async_node!(graphene_std::layer_styles::RasterStrokeNode<_, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => ImageFrame<Color>, () => Color, () => BlendMode, () => f64, () => f64, () => StrokePosition]),
+		async_node!(graphene_std::layer_styles::RasterStrokeNode<_, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => VectorData, () => Color, () => BlendMode, () => f64, () => f64, () => StrokePosition]),
+		async_node!(graphene_std::layer_styles::RasterStrokeNode<_, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => GraphicGroup, () => Color, () => BlendMode, () => f64, () => f64, () => StrokePosition]),
 		register_node!(graphene_core::vector::ResamplePoints<_>, input: VectorData, params: [f64]),
+		register_node!(graphene_core::vector::DashNode<_, _>, input: VectorData, params: [Vec<f32>, f32]),
+		register_node!(graphene_core::vector::OutlineStrokeNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::VariableWidthStrokeNode<_, _>, input: VectorData, params: [graphene_core::raster::curve::Curve, bool]),
+		register_node!(graphene_core::vector::RoundCornersNode<_, _, _>, input: VectorData, params: [f64, bool, Vec<graphene_core::uuid::ManipulatorGroupId>]),
+		register_node!(graphene_core::vector::OffsetPathNode<_, _, _>, input: VectorData, params: [f64, graphene_core::vector::style::LineJoin, f64]),
+		register_node!(graphene_core::vector::RoughenNode<_, _, _, _, _>, input: VectorData, params: [f64, f64, bool, f64, u32]),
+		register_node!(graphene_core::vector::MorphNode<_, _>, input: VectorData, params: [VectorData, f64]),
+		register_node!(graphene_core::vector::BlendStepsNode<_, _>, input: VectorData, params: [VectorData, u32]),
 		register_node!(graphene_core::vector::SplineFromPointsNode, input: VectorData, params: []),
 		register_node!(graphene_core::vector::generator_nodes::CircleGenerator<_>, input: (), params: [f32]),
 		register_node!(graphene_core::vector::generator_nodes::EllipseGenerator<_, _>, input: (), params: [f32, f32]),
@@ -835,7 +879,7 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 			input: Vec<graphene_core::vector::bezier_rs::Subpath<graphene_core::uuid::ManipulatorGroupId>>,
 			params: [Vec<graphene_core::uuid::ManipulatorGroupId>]
 		),
-		register_node!(graphene_core::text::TextGenerator<_, _, _>, input: WasmEditorApi, params: [String, graphene_core::text::Font, f64]),
+		register_node!(graphene_core::text::TextGenerator<_, _, _, _, _, _, _, _, _>, input: WasmEditorApi, params: [String, graphene_core::text::Font, f64, VectorData, f64, graphene_core::text::TextPathAlignment, f64, bool, bool]),
 		register_node!(graphene_std::brush::VectorPointsNode, input: VectorData, params: []),
 		register_node!(graphene_core::ExtractImageFrame, input: WasmEditorApi, params: []),
 		async_node!(graphene_core::ConstructLayerNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => graphene_core::GraphicElementData, () => String, () => BlendMode, () => f32,  () => bool, () => bool, () => bool, Footprint => GraphicGroup]),